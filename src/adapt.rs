@@ -0,0 +1,168 @@
+//! Chromatic adaptation transforms.
+//!
+//! The white point of a color is the white the viewer is adapted to. A chromatic adaptation transform
+//! predicts the color that will look the same under a different white point, by scaling the cone
+//! responses of the color by the ratio between the cone responses of the two whites.
+//!
+//! The transforms differ only in the cone response matrix they scale in, given by any
+//! [`ConeResponse`](../spaces/lms/trait.ConeResponse.html):
+//!
+//! * [`Bradford`](../spaces/lms/struct.Bradford.html), the default, and the transform used by ICC profiles
+//! * [`VonKries`](type.VonKries.html), which scales the Hunt-Pointer-Estevez cone fundamentals
+//! * [`Cat02`](../spaces/lms/struct.Cat02.html), from CIECAM02
+//! * [`Cat16`](../spaces/lms/struct.Cat16.html), from CAM16
+//! * [`XyzScaling`](struct.XyzScaling.html), which scales XYZ directly and is generally the least accurate
+//!
+//! Both white points must be defined for the same [`Observer`](../observer/trait.Observer.html).
+//!
+//! Any color can be adapted into a color of another white point with [`AdaptInto`](trait.AdaptInto.html):
+//!
+//! ```ignore
+//! use color::adapt::AdaptInto;
+//!
+//! let d50: Xyz<f32, D50> = Xyz::new(0.5, 0.4, 0.3).adapt_into();
+//! ```
+
+use num_traits::Float;
+
+use channels::*;
+use color::{Color, FloatColor, FromColor, ColorChannel, ColorWhitePoint};
+use white_point::{WhitePoint, WhitePointObserver};
+use math::{self, Matrix3, Vector3};
+
+use spaces::xyz::Xyz;
+
+pub use spaces::lms::{ConeResponse, HuntPointerEstevez, Bradford, Cat02, Cat16};
+
+/// The von Kries transform, which scales the Hunt-Pointer-Estevez cone fundamentals
+pub type VonKries = HuntPointerEstevez;
+
+/// Scaling of the XYZ tristimulus values directly, without a cone response matrix
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XyzScaling;
+
+impl ConeResponse for XyzScaling {
+    const MATRIX: [[f64; 3]; 3] = [
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+    ];
+}
+
+/// Computes the matrix adapting XYZ colors from one XYZ white to another, with the cone response `M`.
+pub fn matrix<M: ConeResponse, T: Float>(from: Vector3<T>, to: Vector3<T>) -> Matrix3<T> {
+    let m = math::matrix(&M::MATRIX);
+
+    let from = math::mul_vec(&m, from);
+    let to = math::mul_vec(&m, to);
+
+    let mut scale = [[T::zero(); 3]; 3];
+
+    for i in 0..3 {
+        scale[i][i] = to[i] / from[i];
+    }
+
+    math::mul(&math::invert(&m), &math::mul(&scale, &m))
+}
+
+/// Computes the matrix adapting XYZ colors from the white point `Wp` to the white point `To`,
+/// with the cone response `M`.
+pub fn white_point_matrix<M: ConeResponse, C: Channel, Wp, To>() -> Matrix3<FloatChannel<C>>
+where
+    Wp: WhitePoint<C>,
+    To: WhitePoint<C, Observer = WhitePointObserver<Wp, C>>,
+{
+    matrix::<M, _>(Wp::float_xyz(), To::float_xyz())
+}
+
+impl<C: Channel, Wp> Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Adapts the color to the white point `To` with the Bradford transform.
+    #[inline]
+    pub fn adapt<To>(self) -> Xyz<C, To>
+    where
+        To: WhitePoint<C, Observer = WhitePointObserver<Wp, C>>,
+    {
+        self.adapt_with::<Bradford, To>()
+    }
+
+    /// Adapts the color to the white point `To` with the cone response `M`.
+    pub fn adapt_with<M: ConeResponse, To>(self) -> Xyz<C, To>
+    where
+        To: WhitePoint<C, Observer = WhitePointObserver<Wp, C>>,
+    {
+        let xyz = self.into_float();
+
+        let [x, y, z] = math::mul_vec(&white_point_matrix::<M, C, Wp, To>(), [xyz.x, xyz.y, xyz.z]);
+
+        Xyz::with_wp(C::from_float(x), C::from_float(y), C::from_float(z))
+    }
+}
+
+/// Adapts a color of any color space and white point into `Self`, by way of CIE XYZ
+pub trait AdaptFrom<T>: Sized {
+    /// Adapts the color with the cone response `M`.
+    fn adapt_from_with<M: ConeResponse>(color: T) -> Self;
+
+    /// Adapts the color with the Bradford transform.
+    #[inline]
+    fn adapt_from(color: T) -> Self {
+        Self::adapt_from_with::<Bradford>(color)
+    }
+}
+
+// Adapts in floating point XYZ, so that integer colors are rounded once and XYZ above 1.0 does not saturate.
+impl<T, U> AdaptFrom<T> for U
+where
+    T: FloatColor,
+    U: FloatColor<Channel = ColorChannel<T>>,
+    U::Float: FromColor<FloatChannel<ColorChannel<T>>, ColorWhitePoint<U>>,
+    ColorWhitePoint<T>: WhitePoint<FloatChannel<ColorChannel<T>>>,
+    ColorWhitePoint<U>: WhitePoint<
+        FloatChannel<ColorChannel<T>>,
+        Observer = WhitePointObserver<ColorWhitePoint<T>, FloatChannel<ColorChannel<T>>>,
+    >,
+    Xyz<FloatChannel<ColorChannel<T>>, ColorWhitePoint<T>>: From<T::Float>,
+{
+    #[inline]
+    fn adapt_from_with<M: ConeResponse>(color: T) -> U {
+        let xyz = Xyz::from(color.into_float_color()).adapt_with::<M, ColorWhitePoint<U>>();
+
+        U::from_float_color(U::Float::from_xyz(xyz))
+    }
+}
+
+/// Adapts a color into any other color space and white point, the reciprocal of [`AdaptFrom`](trait.AdaptFrom.html)
+pub trait AdaptInto: Sized {
+    /// Adapts the color with the cone response `M`.
+    fn adapt_into_with<M: ConeResponse, U: AdaptFrom<Self>>(self) -> U;
+
+    /// Adapts the color with the Bradford transform.
+    #[inline]
+    fn adapt_into<U: AdaptFrom<Self>>(self) -> U {
+        self.adapt_into_with::<Bradford, U>()
+    }
+}
+
+impl<T: Color> AdaptInto for T {
+    #[inline]
+    fn adapt_into_with<M: ConeResponse, U: AdaptFrom<T>>(self) -> U {
+        U::adapt_from_with::<M>(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spaces::srgb::Srgb;
+    use white_point::{D50, D65};
+
+    #[test]
+    fn integer_white() {
+        let white: Srgb<u8, D50> = Srgb::<u8, D65>::new(255, 255, 255).adapt_into();
+
+        assert_eq!((white.r, white.g, white.b), (255, 255, 255));
+    }
+}
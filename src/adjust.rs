@@ -0,0 +1,601 @@
+//! Tonal adjustments of color channels, such as levels and curves.
+//!
+//! Adjustments map each channel independently, as a function from 0.0 to 1.0 onto itself, and can be applied
+//! to the same channel of every color in a buffer. They operate on whatever values the color stores, so
+//! adjusting gamma-encoded RGB gives the familiar results of image editors.
+//!
+//! For colors with alpha, alpha is the last channel, so use the per-channel functions to leave it untouched.
+//!
+//! [`Posterize`](struct.Posterize.html) quantizes channels to a number of levels, and
+//! [`posterize_oklch`](fn.posterize_oklch.html) quantizes in a perceptual space instead.
+//!
+//! [`Threshold`](struct.Threshold.html) reduces colors to black and white by their relative luminance,
+//! and [`otsu_threshold`](fn.otsu_threshold.html) chooses a cutoff for a buffer automatically.
+//!
+//! [`ToneAdjust`](trait.ToneAdjust.html) provides exposure, brightness and contrast for RGB colors, each applied
+//! to linear light or to encoded values as appropriate, regardless of how the color is stored, along with
+//! a hue-preserving brightening that gamut maps in Oklch instead of clipping.
+
+use std::cmp::Ordering;
+
+use num_traits::{Zero, One, Float, ToPrimitive};
+
+use channels::*;
+use color::{Color, FromColor, ColorChannel, ColorChannels, ColorWhitePoint};
+use convert::ConvertFrom;
+use limited::Limited;
+use luminance::RelativeLuminance;
+use white_point::WhitePoint;
+use gamma::{Encoding, TransferFunction, srgb_encode, srgb_decode};
+use math;
+
+use spaces::all::*;
+use spaces::generic_rgb::Rgb;
+use spaces::primaries::Primaries;
+
+/// A mapping of channel values, applied to each channel independently
+pub trait ChannelAdjustment<T> {
+    /// Maps a single channel value.
+    fn adjust(&self, value: T) -> T;
+}
+
+impl<T, F> ChannelAdjustment<T> for F where F: Fn(T) -> T {
+    #[inline]
+    fn adjust(&self, value: T) -> T {
+        self(value)
+    }
+}
+
+/// A levels adjustment, which remaps input black and white points, applies a midtone gamma,
+/// and then remaps to the output black and white points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Levels<T> {
+    /// The input value mapped to the output black point
+    pub in_black: T,
+    /// The input value mapped to the output white point
+    pub in_white: T,
+    /// The midtone gamma, where values above 1.0 brighten midtones
+    pub gamma: T,
+    /// The darkest output value
+    pub out_black: T,
+    /// The brightest output value
+    pub out_white: T,
+}
+
+impl<T: Float> Levels<T> {
+    /// Creates a levels adjustment with the full output range.
+    pub fn new(in_black: T, in_white: T, gamma: T) -> Levels<T> {
+        Levels {
+            in_black,
+            in_white,
+            gamma,
+            out_black: T::zero(),
+            out_white: T::one(),
+        }
+    }
+
+    /// Sets the output black and white points.
+    pub fn with_output(self, out_black: T, out_white: T) -> Levels<T> {
+        Levels { out_black, out_white, ..self }
+    }
+}
+
+impl<T: Float> Default for Levels<T> {
+    /// The identity adjustment
+    fn default() -> Levels<T> {
+        Levels::new(T::zero(), T::one(), T::one())
+    }
+}
+
+impl<T: Float> ChannelAdjustment<T> for Levels<T> {
+    fn adjust(&self, value: T) -> T {
+        let range = self.in_white - self.in_black;
+
+        let x = if range > T::zero() {
+            ((value - self.in_black) / range).max(T::zero()).min(T::one())
+        } else if value < self.in_black {
+            T::zero()
+        } else {
+            T::one()
+        };
+
+        x.powf(self.gamma.recip()) * (self.out_white - self.out_black) + self.out_black
+    }
+}
+
+/// A tone curve through control points, interpolated with a monotone cubic spline
+///
+/// The curve never overshoots its control points, so monotonic control points give a monotonic curve.
+/// Inputs outside the control points are mapped to the nearest end point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Curve<T> {
+    points: Vec<(T, T)>,
+    tangents: Vec<T>,
+}
+
+impl<T: Float> Curve<T> {
+    /// Creates a curve through the given `(input, output)` control points, in any order.
+    ///
+    /// Of several points with the same input, the last one is kept. A curve without points is the identity,
+    /// and a curve with a single point is constant.
+    pub fn new(points: &[(T, T)]) -> Curve<T> {
+        let mut points = points.to_vec();
+
+        points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        points.reverse();
+        points.dedup_by(|a, b| a.0 == b.0);
+        points.reverse();
+
+        let tangents = tangents(&points);
+
+        Curve { points, tangents }
+    }
+
+    /// The control points, sorted by input
+    #[inline]
+    pub fn points(&self) -> &[(T, T)] {
+        &self.points
+    }
+}
+
+/// Computes tangents of a monotone cubic Hermite spline with the Fritsch-Carlson method.
+fn tangents<T: Float>(points: &[(T, T)]) -> Vec<T> {
+    let n = points.len();
+
+    if n < 2 {
+        return vec![T::zero(); n];
+    }
+
+    let secants: Vec<T> = points.windows(2).map(|w| (w[1].1 - w[0].1) / (w[1].0 - w[0].0)).collect();
+
+    let mut m = vec![T::zero(); n];
+
+    m[0] = secants[0];
+    m[n - 1] = secants[n - 2];
+
+    for k in 1..n - 1 {
+        let (d0, d1) = (secants[k - 1], secants[k]);
+
+        m[k] = if d0 * d1 > T::zero() { (d0 + d1) / math::cast(2.0) } else { T::zero() };
+    }
+
+    for (k, &d) in secants.iter().enumerate() {
+        if d == T::zero() {
+            m[k] = T::zero();
+            m[k + 1] = T::zero();
+        } else {
+            let (a, b) = (m[k] / d, m[k + 1] / d);
+            let s = a * a + b * b;
+
+            if s > math::cast(9.0) {
+                let t = math::cast::<T>(3.0) / s.sqrt();
+
+                m[k] = t * a * d;
+                m[k + 1] = t * b * d;
+            }
+        }
+    }
+
+    m
+}
+
+impl<T: Float> ChannelAdjustment<T> for Curve<T> {
+    fn adjust(&self, value: T) -> T {
+        let points = &self.points;
+
+        match points.len() {
+            0 => return value,
+            1 => return points[0].1,
+            _ => {}
+        }
+
+        let (first, last) = (points[0], points[points.len() - 1]);
+
+        if value.is_nan() || value <= first.0 {
+            return first.1;
+        } else if value >= last.0 {
+            return last.1;
+        }
+
+        let k = points.iter().position(|p| p.0 > value).unwrap_or(points.len() - 1) - 1;
+
+        let ((x0, y0), (x1, y1)) = (points[k], points[k + 1]);
+        let h = x1 - x0;
+        let t = (value - x0) / h;
+
+        let (two, three): (T, T) = (math::cast(2.0), math::cast(3.0));
+
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let h00 = two * t3 - three * t2 + T::one();
+        let h10 = t3 - two * t2 + t;
+        let h01 = three * t2 - two * t3;
+        let h11 = t3 - t2;
+
+        h00 * y0 + h10 * h * self.tangents[k] + h01 * y1 + h11 * h * self.tangents[k + 1]
+    }
+}
+
+/// Quantizes channels to a number of evenly spaced levels, from 0.0 to 1.0 inclusive
+///
+/// Each channel is rounded to the nearest level, so levels are exact fractions of the full range of integer
+/// channels, rounded to the nearest integer. Posterizing twice is the same as posterizing once, and posterizing
+/// integer channels to at least as many levels as they have values leaves them unchanged.
+/// Fewer than two levels are treated as two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Posterize {
+    /// The number of levels
+    pub levels: u32,
+}
+
+impl Posterize {
+    #[inline]
+    pub fn new(levels: u32) -> Posterize {
+        Posterize { levels }
+    }
+}
+
+impl<T: Float> ChannelAdjustment<T> for Posterize {
+    fn adjust(&self, value: T) -> T {
+        let steps: T = math::cast(f64::from(self.levels.max(2) - 1));
+
+        let level = (value * steps).round().max(T::zero()).min(steps);
+
+        level / steps
+    }
+}
+
+/// Posterizes a color in Oklch, giving levels that are closer to perceptually even than those of encoded RGB.
+///
+/// Lightness is quantized from 0.0 to 1.0 and chroma from 0.0 to 0.4, each to `levels` levels, while hue is kept.
+/// The result is clamped to the valid range of the space. The color must have floating point channels.
+pub fn posterize_oklch<C>(color: C, levels: u32) -> C
+where
+    C: Color + Limited + ConvertFrom<Oklch<ColorChannel<C>, ColorWhitePoint<C>>>,
+    ColorChannel<C>: Float,
+    Oklch<ColorChannel<C>, ColorWhitePoint<C>>: ConvertFrom<C>,
+{
+    let p = Posterize::new(levels);
+
+    let mut lch = Oklch::convert_from(color);
+
+    let range: ColorChannel<C> = math::cast(0.4);
+
+    lch.l = p.adjust(lch.l);
+    lch.c = p.adjust(lch.c / range) * range;
+
+    C::convert_from(lch).clamp()
+}
+
+/// Applies an adjustment to every channel of a color.
+pub fn apply<C, A>(mut color: C, adjustment: &A) -> C
+where
+    C: Color,
+    ColorChannels<C>: ChannelSlice<ColorChannel<C>>,
+    A: ChannelAdjustment<FloatChannel<ColorChannel<C>>> + ?Sized,
+{
+    for c in color.channels_mut().as_mut_slice() {
+        *c = Channel::from_float(adjustment.adjust(c.into_float()));
+    }
+
+    color
+}
+
+/// Applies a separate adjustment to each channel of a color, in channel order.
+///
+/// Channels past the end of `adjustments` are left untouched.
+pub fn apply_per_channel<C, A>(mut color: C, adjustments: &[A]) -> C
+where
+    C: Color,
+    ColorChannels<C>: ChannelSlice<ColorChannel<C>>,
+    A: ChannelAdjustment<FloatChannel<ColorChannel<C>>>,
+{
+    for (c, adjustment) in color.channels_mut().as_mut_slice().iter_mut().zip(adjustments) {
+        *c = Channel::from_float(adjustment.adjust(c.into_float()));
+    }
+
+    color
+}
+
+/// Applies an adjustment to every channel of every color in a buffer.
+pub fn apply_slice<C, A>(colors: &mut [C], adjustment: &A)
+where
+    C: Color + Copy,
+    ColorChannels<C>: ChannelSlice<ColorChannel<C>>,
+    A: ChannelAdjustment<FloatChannel<ColorChannel<C>>> + ?Sized,
+{
+    for color in colors {
+        *color = apply(*color, adjustment);
+    }
+}
+
+/// Applies a separate adjustment to each channel of every color in a buffer.
+pub fn apply_per_channel_slice<C, A>(colors: &mut [C], adjustments: &[A])
+where
+    C: Color + Copy,
+    ColorChannels<C>: ChannelSlice<ColorChannel<C>>,
+    A: ChannelAdjustment<FloatChannel<ColorChannel<C>>>,
+{
+    for color in colors {
+        *color = apply_per_channel(*color, adjustments);
+    }
+}
+
+/// Exposure, brightness and contrast of RGB colors
+///
+/// Exposure scales linear light, while brightness and contrast operate on gamma-encoded values, which are
+/// closer to perceptually uniform. Linear sRGB is encoded with the sRGB transfer function for them, and
+/// `Rgb` uses its own transfer function, so a linear `Rgb` adjusts linear values. Channels are not clamped.
+pub trait ToneAdjust: Sized {
+    /// The type of the adjustment amounts
+    type Scalar: Float;
+
+    /// Multiplies linear light by 2 to the power of `stops`.
+    fn exposure(self, stops: Self::Scalar) -> Self;
+
+    /// Adds `offset` to each encoded channel.
+    fn brightness(self, offset: Self::Scalar) -> Self;
+
+    /// Scales the distance of each encoded channel from `pivot` by `factor`, where values above 1.0 increase contrast.
+    ///
+    /// A pivot of 0.5 is typical for encoded values.
+    fn contrast(self, factor: Self::Scalar, pivot: Self::Scalar) -> Self;
+
+    /// Multiplies luminance by 2 to the power of `stops` in Oklch, preserving hue.
+    ///
+    /// Scaling linear light scales the lightness and chroma of Oklch by the same factor, so in gamut this
+    /// matches [`exposure`](#tymethod.exposure). Where `exposure` would clip channels and skew the hue, chroma is
+    /// instead reduced at the new lightness until the color fits the gamut of the space.
+    fn brighten_preserving_hue(self, stops: Self::Scalar) -> Self;
+}
+
+macro_rules! impl_tone_adjust {
+    ($($name:ident $(< $($p:ident: $bound:path),* >)* {
+        linear: $decode:expr, $encode:expr;
+        encoded: $to_encoded:expr, $from_encoded:expr;
+    })*) => {
+        $(
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> ToneAdjust for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C>,
+                Self: Limited,
+                $name<FloatChannel<C>, Wp $($(, $p)*)*>: Limited,
+                Oklch<FloatChannel<C>, Wp>: ConvertFrom<$name<FloatChannel<C>, Wp $($(, $p)*)*>>,
+                $name<FloatChannel<C>, Wp $($(, $p)*)*>: ConvertFrom<Oklch<FloatChannel<C>, Wp>>,
+            {
+                type Scalar = FloatChannel<C>;
+
+                fn exposure(self, stops: FloatChannel<C>) -> Self {
+                    let gain = math::cast::<FloatChannel<C>>(2.0).powf(stops);
+
+                    let rgb = self.into_float();
+                    let f = |v| C::from_float($encode($decode(v) * gain));
+
+                    $name::with_wp(f(rgb.r), f(rgb.g), f(rgb.b))
+                }
+
+                fn brightness(self, offset: FloatChannel<C>) -> Self {
+                    let rgb = self.into_float();
+                    let f = |v| C::from_float($from_encoded($to_encoded(v) + offset));
+
+                    $name::with_wp(f(rgb.r), f(rgb.g), f(rgb.b))
+                }
+
+                fn contrast(self, factor: FloatChannel<C>, pivot: FloatChannel<C>) -> Self {
+                    let rgb = self.into_float();
+                    let f = |v| C::from_float($from_encoded(($to_encoded(v) - pivot) * factor + pivot));
+
+                    $name::with_wp(f(rgb.r), f(rgb.g), f(rgb.b))
+                }
+
+                fn brighten_preserving_hue(self, stops: FloatChannel<C>) -> Self {
+                    let gain = math::cast::<FloatChannel<C>>(2.0).powf(stops).cbrt();
+
+                    let mut lch = Oklch::convert_from(self.into_float());
+
+                    lch.l = lch.l * gain;
+                    lch.c = lch.c * gain;
+
+                    $name::from_float(gamut_map_oklch(lch)).clamp()
+                }
+            }
+        )*
+    }
+}
+
+/// Maps an Oklch color into the gamut of `C` by clamping lightness, then bisecting for the largest chroma
+/// of the same hue and lightness that fits.
+fn gamut_map_oklch<C, T, Wp>(mut lch: Oklch<T, Wp>) -> C
+where
+    T: Channel + Float,
+    C: ConvertFrom<Oklch<T, Wp>> + Limited,
+{
+    lch.l = lch.l.max(T::zero()).min(T::one());
+
+    let color = C::convert_from(lch);
+
+    if color.is_valid() {
+        return color;
+    }
+
+    let (mut lo, mut hi) = (T::zero(), lch.c);
+    let two: T = math::cast(2.0);
+
+    for _ in 0..24 {
+        let mid = (lo + hi) / two;
+
+        lch.c = mid;
+
+        if C::convert_from(lch).is_valid() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    lch.c = lo;
+
+    C::convert_from(lch)
+}
+
+impl_tone_adjust! {
+    Srgb {
+        linear: srgb_decode, srgb_encode;
+        encoded: |v| v, |v| v;
+    }
+    DisplayP3 {
+        linear: srgb_decode, srgb_encode;
+        encoded: |v| v, |v| v;
+    }
+    LinearSrgb {
+        linear: |v| v, |v| v;
+        encoded: srgb_encode, srgb_decode;
+    }
+    Rgb<P: Primaries, E: Encoding> {
+        linear: |v| E::default().decode(v), |v| E::default().encode(v);
+        encoded: |v| v, |v| v;
+    }
+}
+
+/// Binarization of colors into black and white by their relative luminance
+///
+/// With hysteresis, a buffer is processed in order like a Schmitt trigger: a color only turns white above
+/// `cutoff + hysteresis / 2` and only turns black below `cutoff - hysteresis / 2`, and otherwise keeps the
+/// result of the previous color. This suppresses speckles from noise around the cutoff along scanlines.
+///
+/// Luminance is computed by way of CIE XYZ in the channel type of the colors, so use floating point channels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Threshold<T> {
+    /// The relative luminance separating black from white
+    pub cutoff: T,
+    /// The width of the band around the cutoff where the previous result is kept
+    pub hysteresis: T,
+}
+
+impl<T: Channel + Float> Threshold<T> {
+    /// Creates a threshold without hysteresis.
+    #[inline]
+    pub fn new(cutoff: T) -> Threshold<T> {
+        Threshold { cutoff, hysteresis: T::zero() }
+    }
+
+    /// Sets the width of the hysteresis band.
+    #[inline]
+    pub fn with_hysteresis(self, hysteresis: T) -> Threshold<T> {
+        Threshold { hysteresis, ..self }
+    }
+
+    /// Classifies a relative luminance as white, given whether the previous luminance was white.
+    pub fn is_white(&self, luminance: T, previous: bool) -> bool {
+        let half = self.hysteresis.abs() / math::cast(2.0);
+
+        if previous {
+            luminance >= self.cutoff - half
+        } else {
+            luminance > self.cutoff + half
+        }
+    }
+
+    /// Reduces a single color to black or white, ignoring hysteresis.
+    pub fn apply<C>(&self, color: C) -> C
+    where
+        C: Color + FromColor<ColorChannel<C>, ColorWhitePoint<C>> + RelativeLuminance<Scalar = T>,
+        ColorChannel<C>: Channel<FloatChannel = T>,
+    {
+        let white = color.relative_luminance() > self.cutoff;
+
+        black_or_white(white)
+    }
+
+    /// Reduces every color of a buffer to black or white in order, starting from black for hysteresis.
+    pub fn apply_slice<C>(&self, colors: &mut [C])
+    where
+        C: Color + FromColor<ColorChannel<C>, ColorWhitePoint<C>> + RelativeLuminance<Scalar = T>,
+        ColorChannel<C>: Channel<FloatChannel = T>,
+    {
+        let mut white = false;
+
+        for color in colors {
+            white = self.is_white(color.relative_luminance(), white);
+
+            *color = black_or_white(white);
+        }
+    }
+}
+
+/// The black or white of the white point of a color
+fn black_or_white<C>(white: bool) -> C
+where
+    C: Color + FromColor<ColorChannel<C>, ColorWhitePoint<C>>,
+{
+    let xyz = if white {
+        ColorWhitePoint::<C>::get_xyz()
+    } else {
+        Xyz::with_wp(Zero::zero(), Zero::zero(), Zero::zero())
+    };
+
+    C::from_xyz(xyz)
+}
+
+/// The number of histogram bins used by [`otsu_threshold`](fn.otsu_threshold.html)
+const OTSU_BINS: usize = 256;
+
+/// Chooses a relative luminance cutoff for a buffer with Otsu's method, which maximizes the variance between
+/// the luminance of the resulting black and white classes.
+///
+/// Luminance is binned into a histogram of 256 bins from 0.0 to 1.0. Returns 0.5 for an empty buffer.
+pub fn otsu_threshold<C>(colors: &[C]) -> C::Scalar
+where
+    C: RelativeLuminance,
+    C::Scalar: Float,
+{
+    let bins: C::Scalar = math::cast(OTSU_BINS as f64);
+
+    let mut histogram = [0usize; OTSU_BINS];
+
+    for color in colors {
+        let l = color.relative_luminance().max(Zero::zero()).min(One::one());
+
+        let bin = (l * bins).floor().to_usize().unwrap_or(0).min(OTSU_BINS - 1);
+
+        histogram[bin] += 1;
+    }
+
+    if colors.is_empty() {
+        return math::cast(0.5);
+    }
+
+    let total = colors.len() as f64;
+    let sum: f64 = histogram.iter().enumerate().map(|(i, &n)| i as f64 * n as f64).sum();
+
+    let (mut weight_black, mut sum_black) = (0.0, 0.0);
+    // Any cutoff between two separated clusters gives the same variance, so take the middle of the gap
+    let (mut first, mut last, mut best_variance) = (0, 0, -1.0);
+
+    for (i, &n) in histogram.iter().enumerate() {
+        weight_black += n as f64;
+        sum_black += i as f64 * n as f64;
+
+        let weight_white = total - weight_black;
+
+        if weight_black == 0.0 || weight_white == 0.0 {
+            continue;
+        }
+
+        let mean_black = sum_black / weight_black;
+        let mean_white = (sum - sum_black) / weight_white;
+
+        let variance = weight_black * weight_white * (mean_black - mean_white).powi(2);
+
+        if variance > best_variance {
+            first = i;
+            last = i;
+            best_variance = variance;
+        } else if variance == best_variance {
+            last = i;
+        }
+    }
+
+    math::cast((first + last + 2) as f64 / (2 * OTSU_BINS) as f64)
+}
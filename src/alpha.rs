@@ -1,6 +1,14 @@
 use std::ops::{Deref, DerefMut};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::{mem, ptr};
 
-use color::{Color, ColorChannel, Components, ColorComponents};
+use num_traits::{Zero, One};
+
+use channels::*;
+use color::{Color, ColorChannel, ColorChannels, ColorWhitePoint, Components, ColorComponents, FromColor, FloatColor};
+use white_point::WhitePoint;
+
+use spaces::xyz::Xyz;
 
 #[repr(C)]
 pub struct Alpha<C: Color> {
@@ -8,10 +16,263 @@ pub struct Alpha<C: Color> {
     pub alpha: ColorChannel<C>,
 }
 
+/// A color with an alpha channel, where the color channels are premultiplied by alpha
+///
+/// Premultiplied colors can be composited and interpolated with plain arithmetic on all channels,
+/// without the dark fringes that result from interpolating transparent colors with straight alpha.
+#[repr(C)]
+pub struct PreAlpha<C: Color> {
+    color: C,
+    pub alpha: ColorChannel<C>,
+}
+
 impl<C: Color> Alpha<C> {
     pub fn from_color(color: C, alpha: ColorChannel<C>) -> Alpha<C> {
         Alpha { color, alpha }
     }
+
+    /// Creates a fully opaque color.
+    pub fn opaque(color: C) -> Alpha<C> {
+        Alpha::from_color(color, opaque::<ColorChannel<C>>())
+    }
+
+    /// Creates a fully transparent color, with the default color and zero alpha.
+    pub fn transparent() -> Alpha<C>
+    where
+        C: Default,
+    {
+        Alpha::from_color(C::default(), ColorChannel::<C>::zero())
+    }
+
+    /// The color without alpha.
+    #[inline]
+    pub fn color(&self) -> &C {
+        &self.color
+    }
+
+    /// The color without alpha.
+    #[inline]
+    pub fn color_mut(&mut self) -> &mut C {
+        &mut self.color
+    }
+
+    /// Splits into the color and alpha.
+    #[inline]
+    pub fn into_parts(self) -> (C, ColorChannel<C>) {
+        (self.color, self.alpha)
+    }
+
+    /// Transforms the color while keeping alpha, possibly into another color space.
+    pub fn map_color<U, F>(self, f: F) -> Alpha<U>
+    where
+        U: Color<Channel = ColorChannel<C>>,
+        F: FnOnce(C) -> U,
+    {
+        Alpha::from_color(f(self.color), self.alpha)
+    }
+
+    /// Transforms alpha while keeping the color.
+    pub fn map_alpha<F>(self, f: F) -> Alpha<C>
+    where
+        F: FnOnce(ColorChannel<C>) -> ColorChannel<C>,
+    {
+        Alpha::from_color(self.color, f(self.alpha))
+    }
+}
+
+impl<Ch: Channel, Wp, C> Alpha<C>
+where
+    C: FloatColor<Channel = Ch, WhitePoint = Wp>,
+    C::Float: FromColor<FloatChannel<Ch>, Wp>,
+    Wp: WhitePoint<FloatChannel<Ch>>,
+    Xyz<FloatChannel<Ch>, Wp>: From<C::Float>,
+{
+    /// Composites the color over an opaque background, flattening it into an opaque color.
+    ///
+    /// The colors are composited in linear light, by way of CIE XYZ with floating point channels, so the result
+    /// is correct even for gamma-encoded or perceptual color spaces and colors with integer channels. To composite
+    /// over a background that is not opaque, use [`Compose::over`](../compose/trait.Compose.html#method.over).
+    pub fn over(self, background: C) -> C {
+        let a = self.alpha.into_float();
+
+        let src = Xyz::from(self.color.into_float_color());
+        let dst = Xyz::from(background.into_float_color());
+
+        let mix = |s: FloatChannel<Ch>, d: FloatChannel<Ch>| s * a + d * (FloatChannel::<Ch>::one() - a);
+
+        C::from_float_color(C::Float::from_xyz(Xyz::with_wp(mix(src.x, dst.x), mix(src.y, dst.y), mix(src.z, dst.z))))
+    }
+}
+
+impl<C: Color> Alpha<C>
+where
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    /// Premultiplies the color channels by alpha.
+    #[inline]
+    pub fn premultiply(self) -> PreAlpha<C> {
+        self.into()
+    }
+
+    /// Linearly interpolates between two colors by way of premultiplied alpha,
+    /// where `t` of 0.0 is `self` and 1.0 is `other`.
+    pub fn mix(self, other: Alpha<C>, t: FloatChannel<ColorChannel<C>>) -> Alpha<C> {
+        self.premultiply().mix(other.premultiply(), t).into()
+    }
+}
+
+impl<C: Color> PreAlpha<C> {
+    /// Creates a color from channels that are already premultiplied by alpha.
+    pub fn from_premultiplied(color: C, alpha: ColorChannel<C>) -> PreAlpha<C> {
+        PreAlpha { color, alpha }
+    }
+
+    /// Splits into the premultiplied color and alpha.
+    #[inline]
+    pub fn into_parts(self) -> (C, ColorChannel<C>) {
+        (self.color, self.alpha)
+    }
+
+    /// The premultiplied color channels.
+    #[inline]
+    pub fn color(&self) -> &C {
+        &self.color
+    }
+
+    /// The premultiplied color channels.
+    #[inline]
+    pub fn color_mut(&mut self) -> &mut C {
+        &mut self.color
+    }
+}
+
+impl<C: Color> PreAlpha<C>
+where
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    /// Divides the color channels by alpha. Fully transparent colors become transparent black.
+    #[inline]
+    pub fn unpremultiply(self) -> Alpha<C> {
+        self.into()
+    }
+
+    /// Linearly interpolates between two colors, where `t` of 0.0 is `self` and 1.0 is `other`.
+    pub fn mix(mut self, other: PreAlpha<C>, t: FloatChannel<ColorChannel<C>>) -> PreAlpha<C> {
+        for (a, &b) in self.channels_mut().as_mut_slice().iter_mut().zip(other.channels().as_slice()) {
+            let (fa, fb) = (a.into_float(), b.into_float());
+
+            *a = Channel::from_float(fa + (fb - fa) * t);
+        }
+
+        self
+    }
+}
+
+impl<C: Color> From<Alpha<C>> for PreAlpha<C>
+where
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    fn from(color: Alpha<C>) -> PreAlpha<C> {
+        let Alpha { mut color, alpha } = color;
+
+        let a = alpha.into_float();
+
+        for c in color.channels_mut().as_mut_slice() {
+            *c = Channel::from_float(c.into_float() * a);
+        }
+
+        PreAlpha { color, alpha }
+    }
+}
+
+impl<C: Color> From<PreAlpha<C>> for Alpha<C>
+where
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    fn from(color: PreAlpha<C>) -> Alpha<C> {
+        let PreAlpha { mut color, alpha } = color;
+
+        let a = alpha.into_float();
+
+        for c in color.channels_mut().as_mut_slice() {
+            *c = if a > FloatChannel::<ColorChannel<C>>::zero() {
+                Channel::from_float(c.into_float() / a)
+            } else {
+                ColorChannel::<C>::zero()
+            };
+        }
+
+        Alpha { color, alpha }
+    }
+}
+
+impl<C: Color> Debug for PreAlpha<C>
+where
+    C: Debug,
+    ColorChannel<C>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("PreAlpha").field("color", &self.color).field("alpha", &self.alpha).finish()
+    }
+}
+
+impl<C: Color + Copy> Clone for PreAlpha<C> {
+    fn clone(&self) -> PreAlpha<C> {
+        *self
+    }
+}
+
+impl<C: Color + Copy> Copy for PreAlpha<C> {}
+
+impl<C: Color> PartialEq for PreAlpha<C>
+where
+    ColorChannels<C>: PartialEq,
+{
+    fn eq(&self, other: &PreAlpha<C>) -> bool {
+        self.color.channels() == other.color.channels() && self.alpha == other.alpha
+    }
+}
+
+impl<C: Color + Default> Default for PreAlpha<C> {
+    /// The default color with zero alpha, which is fully transparent if the default color is black
+    fn default() -> PreAlpha<C> {
+        PreAlpha::from_premultiplied(C::default(), ColorChannel::<C>::zero())
+    }
+}
+
+impl<C: Color> Color for PreAlpha<C>
+where
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    type Channel = ColorChannel<C>;
+    type Channels = <ColorChannels<C> as AlphaChannels<ColorChannel<C>>>::Channels;
+    type WhitePoint = ColorWhitePoint<C>;
+
+    fn from_channels(channels: Self::Channels) -> PreAlpha<C> {
+        debug_assert_eq!(mem::size_of::<Self::Channels>(), mem::size_of::<PreAlpha<C>>());
+
+        unsafe {
+            let color = ptr::read(&channels as *const Self::Channels as *const PreAlpha<C>);
+
+            mem::forget(channels);
+
+            color
+        }
+    }
+
+    fn channels(&self) -> &Self::Channels {
+        unsafe { &*(self as *const PreAlpha<C> as *const Self::Channels) }
+    }
+
+    fn channels_mut(&mut self) -> &mut Self::Channels {
+        unsafe { &mut *(self as *mut PreAlpha<C> as *mut Self::Channels) }
+    }
+}
+
+/// The alpha value of a fully opaque color, such as 1.0 for floating point channels or 255 for `u8` channels
+#[inline]
+fn opaque<C: Channel>() -> C {
+    C::from_float(FloatChannel::<C>::one())
 }
 
 impl<C: Color> Deref for Alpha<C>
@@ -34,6 +295,121 @@ where
     }
 }
 
-//impl<C: Color> Color for Alpha<C> {
-//
-//}
+impl<C: Color> Debug for Alpha<C>
+where
+    C: Debug,
+    ColorChannel<C>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("Alpha").field("color", &self.color).field("alpha", &self.alpha).finish()
+    }
+}
+
+impl<C: Color + Copy> Clone for Alpha<C> {
+    fn clone(&self) -> Alpha<C> {
+        *self
+    }
+}
+
+impl<C: Color + Copy> Copy for Alpha<C> {}
+
+impl<C: Color> PartialEq for Alpha<C>
+where
+    ColorChannels<C>: PartialEq,
+{
+    fn eq(&self, other: &Alpha<C>) -> bool {
+        self.color.channels() == other.color.channels() && self.alpha == other.alpha
+    }
+}
+
+impl<C: Color + Default> Default for Alpha<C> {
+    /// The default color, fully opaque
+    fn default() -> Alpha<C> {
+        Alpha::opaque(C::default())
+    }
+}
+
+impl<C: Color> Color for Alpha<C>
+where
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    type Channel = ColorChannel<C>;
+    type Channels = <ColorChannels<C> as AlphaChannels<ColorChannel<C>>>::Channels;
+    type WhitePoint = ColorWhitePoint<C>;
+
+    fn from_channels(channels: Self::Channels) -> Alpha<C> {
+        debug_assert_eq!(mem::size_of::<Self::Channels>(), mem::size_of::<Alpha<C>>());
+
+        // The color channels are followed by the alpha channel, as in the channel container
+        unsafe {
+            let color = ptr::read(&channels as *const Self::Channels as *const Alpha<C>);
+
+            mem::forget(channels);
+
+            color
+        }
+    }
+
+    fn channels(&self) -> &Self::Channels {
+        unsafe { &*(self as *const Alpha<C> as *const Self::Channels) }
+    }
+
+    fn channels_mut(&mut self) -> &mut Self::Channels {
+        unsafe { &mut *(self as *mut Alpha<C> as *mut Self::Channels) }
+    }
+}
+
+impl<C: Channel, Wp, T> FromColor<C, Wp> for Alpha<T>
+where
+    T: Color<Channel = C> + FromColor<C, Wp>,
+    Wp: WhitePoint<C>,
+{
+    /// Converts to a fully opaque color.
+    fn from_xyz(xyz: ::spaces::xyz::Xyz<C, Wp>) -> Alpha<T> {
+        Alpha::opaque(T::from_xyz(xyz))
+    }
+
+    /// Converts to a fully opaque color.
+    fn from_yxy(yxy: ::spaces::yxy::Yxy<C, Wp>) -> Alpha<T> {
+        Alpha::opaque(T::from_yxy(yxy))
+    }
+}
+
+impl<C: FloatColor> FloatColor for Alpha<C>
+where
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+    ColorChannels<C::Float>: AlphaChannels<ColorChannel<C::Float>>,
+{
+    type Float = Alpha<C::Float>;
+
+    #[inline]
+    fn into_float_color(self) -> Alpha<C::Float> {
+        Alpha::from_color(self.color.into_float_color(), self.alpha.into_float())
+    }
+
+    #[inline]
+    fn from_float_color(color: Alpha<C::Float>) -> Alpha<C> {
+        Alpha::from_color(C::from_float_color(color.color), Channel::from_float(color.alpha))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use spaces::srgb::Srgb;
+
+    #[test]
+    fn over_integer_channels() {
+        let white = Srgb::<u8>::new(255, 255, 255);
+
+        let half = Alpha::from_color(Srgb::<u8>::new(0, 0, 0), 128).over(white);
+        assert_eq!((half.r, half.g, half.b), (187, 187, 187));
+
+        let opaque = Alpha::from_color(Srgb::<u8>::new(200, 100, 50), 255).over(white);
+        assert_eq!((opaque.r, opaque.g, opaque.b), (200, 100, 50));
+
+        let clear = Alpha::from_color(Srgb::<u8>::new(200, 100, 50), 0).over(white);
+        assert_eq!((clear.r, clear.g, clear.b), (255, 255, 255));
+    }
+}
@@ -0,0 +1,323 @@
+//! ANSI escape sequences for printing colors to terminals.
+//!
+//! [`TrueColor`](struct.TrueColor.html) writes the 24-bit escape sequences `ESC[38;2;r;g;bm` for text and
+//! `ESC[48;2;r;g;bm` for the background with `Display`, so it can be formatted straight into output:
+//!
+//! ```ignore
+//! println!("{}warning{}", TrueColor::foreground(Oklch::new(0.7, 0.15, 80.0)), ansi::RESET);
+//! println!("{}", ansi::swatch(lab));
+//! ```
+//!
+//! Any color is converted to sRGB first, and clamped and rounded to 8 bits. Most terminals support 24-bit color,
+//! but some only support the palettes of 16 or 256 colors, for which [`Ansi16`](struct.Ansi16.html) and
+//! [`Ansi256`](struct.Ansi256.html) choose the palette color nearest to a color by Euclidean distance in Oklab.
+//! [`ansi_256_rgb`](fn.ansi_256_rgb.html) gives the color of any palette index.
+
+use std::fmt::{self, Display, Formatter};
+use std::sync::OnceLock;
+
+use channels::*;
+use color::{ColorChannel, ColorWhitePoint, FloatColor};
+use convert::ConvertFrom;
+use hex::to_byte;
+use palette::Palette;
+use white_point::{WhitePoint, D65};
+
+use spaces::srgb::Srgb;
+use spaces::oklab::Oklab;
+
+/// The escape sequence that resets colors and other attributes to the defaults of the terminal
+pub const RESET: &str = "\x1b[0m";
+
+/// The part of a character cell colored by an escape sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layer {
+    /// The text
+    Foreground,
+    /// The background behind the text
+    Background,
+}
+
+impl Layer {
+    /// The first parameter of the escape sequence selecting the layer for 24-bit and 256 colors
+    #[inline]
+    fn parameter(self) -> u8 {
+        match self {
+            Layer::Foreground => 38,
+            Layer::Background => 48,
+        }
+    }
+}
+
+/// A 24-bit color escape sequence, written with `Display`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TrueColor {
+    /// The 8-bit sRGB channels
+    pub rgb: [u8; 3],
+    /// The layer colored
+    pub layer: Layer,
+}
+
+/// Converts any color to 8-bit sRGB, clamping channels outside of the sRGB gamut.
+///
+/// The conversion is done with floating point channels, so colors with integer channels are only rounded once,
+/// and 8-bit sRGB colors are returned unchanged.
+pub fn to_srgb_bytes<C>(color: C) -> [u8; 3]
+where
+    C: FloatColor,
+    Srgb<FloatChannel<ColorChannel<C>>, ColorWhitePoint<C>>: ConvertFrom<C::Float>,
+    ColorWhitePoint<C>: WhitePoint<FloatChannel<ColorChannel<C>>>,
+{
+    srgb_bytes(&Srgb::convert_from(color.into_float_color()))
+}
+
+/// Clamps and rounds the channels of an sRGB color to bytes.
+#[inline]
+fn srgb_bytes<C: Channel, Wp>(color: &Srgb<C, Wp>) -> [u8; 3]
+where
+    Wp: WhitePoint<C>,
+{
+    [to_byte(color.r), to_byte(color.g), to_byte(color.b)]
+}
+
+impl TrueColor {
+    /// Creates an escape sequence from 8-bit sRGB channels.
+    #[inline]
+    pub fn new(rgb: [u8; 3], layer: Layer) -> TrueColor {
+        TrueColor { rgb, layer }
+    }
+
+    /// Creates an escape sequence from an sRGB color, which is exact for 8-bit channels.
+    #[inline]
+    pub fn from_srgb<C: Channel, Wp>(color: &Srgb<C, Wp>, layer: Layer) -> TrueColor
+    where
+        Wp: WhitePoint<C>,
+    {
+        TrueColor::new(srgb_bytes(color), layer)
+    }
+
+    /// Creates an escape sequence setting the text to any color, converted with
+    /// [`to_srgb_bytes`](fn.to_srgb_bytes.html).
+    #[inline]
+    pub fn foreground<C>(color: C) -> TrueColor
+    where
+        C: FloatColor,
+        Srgb<FloatChannel<ColorChannel<C>>, ColorWhitePoint<C>>: ConvertFrom<C::Float>,
+        ColorWhitePoint<C>: WhitePoint<FloatChannel<ColorChannel<C>>>,
+    {
+        TrueColor::new(to_srgb_bytes(color), Layer::Foreground)
+    }
+
+    /// Creates an escape sequence setting the background to any color, converted with
+    /// [`to_srgb_bytes`](fn.to_srgb_bytes.html).
+    #[inline]
+    pub fn background<C>(color: C) -> TrueColor
+    where
+        C: FloatColor,
+        Srgb<FloatChannel<ColorChannel<C>>, ColorWhitePoint<C>>: ConvertFrom<C::Float>,
+        ColorWhitePoint<C>: WhitePoint<FloatChannel<ColorChannel<C>>>,
+    {
+        TrueColor::new(to_srgb_bytes(color), Layer::Background)
+    }
+}
+
+impl Display for TrueColor {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let [r, g, b] = self.rgb;
+
+        write!(f, "\x1b[{};2;{};{};{}m", self.layer.parameter(), r, g, b)
+    }
+}
+
+/// A swatch of any color two cells wide, as a background color behind two spaces followed by a reset
+pub fn swatch<C>(color: C) -> String
+where
+    C: FloatColor,
+    Srgb<FloatChannel<ColorChannel<C>>, ColorWhitePoint<C>>: ConvertFrom<C::Float>,
+    ColorWhitePoint<C>: WhitePoint<FloatChannel<ColorChannel<C>>>,
+{
+    format!("{}  {}", TrueColor::background(color), RESET)
+}
+
+/// The 16 standard colors as displayed by xterm: black, red, green, yellow, blue, magenta, cyan and white,
+/// followed by their bright variants
+///
+/// Terminals let users change these colors, so they may be displayed differently.
+pub const ANSI_16: [[u8; 3]; 16] = [
+    [0x00, 0x00, 0x00], [0xcd, 0x00, 0x00], [0x00, 0xcd, 0x00], [0xcd, 0xcd, 0x00],
+    [0x00, 0x00, 0xee], [0xcd, 0x00, 0xcd], [0x00, 0xcd, 0xcd], [0xe5, 0xe5, 0xe5],
+    [0x7f, 0x7f, 0x7f], [0xff, 0x00, 0x00], [0x00, 0xff, 0x00], [0xff, 0xff, 0x00],
+    [0x5c, 0x5c, 0xff], [0xff, 0x00, 0xff], [0x00, 0xff, 0xff], [0xff, 0xff, 0xff],
+];
+
+/// The levels of each channel of the 6x6x6 color cube of the 256 color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The 8-bit sRGB color of an index of the xterm 256 color palette
+///
+/// Indices 0 to 15 are the standard colors of [`ANSI_16`](constant.ANSI_16.html), 16 to 231 are a 6x6x6
+/// color cube, and 232 to 255 are a ramp of grays from dark to light that excludes black and white.
+pub fn ansi_256_rgb(index: u8) -> [u8; 3] {
+    match index {
+        0..=15 => ANSI_16[index as usize],
+        16..=231 => {
+            let i = (index - 16) as usize;
+
+            [CUBE_LEVELS[i / 36], CUBE_LEVELS[i / 6 % 6], CUBE_LEVELS[i % 6]]
+        }
+        _ => {
+            let v = 8 + 10 * (index - 232);
+
+            [v, v, v]
+        }
+    }
+}
+
+/// Converts 8-bit sRGB to Oklab for comparisons.
+fn oklab([r, g, b]: [u8; 3]) -> Oklab<f64, D65> {
+    let f = |v: u8| v as f64 / 255.0;
+
+    Oklab::convert_from(Srgb::new(f(r), f(g), f(b)))
+}
+
+/// The 16 standard colors in Oklab
+fn palette_16() -> &'static Palette<Oklab<f64, D65>> {
+    static PALETTE: OnceLock<Palette<Oklab<f64, D65>>> = OnceLock::new();
+
+    PALETTE.get_or_init(|| Palette::new(ANSI_16.iter().map(|&rgb| oklab(rgb)).collect()))
+}
+
+/// The colors of the 256 color palette from index 16 on, in Oklab
+fn palette_256() -> &'static Palette<Oklab<f64, D65>> {
+    static PALETTE: OnceLock<Palette<Oklab<f64, D65>>> = OnceLock::new();
+
+    PALETTE.get_or_init(|| Palette::new((16..=255).map(|i| oklab(ansi_256_rgb(i))).collect()))
+}
+
+/// An escape sequence selecting one of the 16 standard colors, written with `Display`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ansi16 {
+    /// The index of the color in [`ANSI_16`](constant.ANSI_16.html), from 0 to 15
+    pub index: u8,
+    /// The layer colored
+    pub layer: Layer,
+}
+
+impl Ansi16 {
+    /// Creates an escape sequence for the standard color at `index`, which is taken modulo 16.
+    #[inline]
+    pub fn new(index: u8, layer: Layer) -> Ansi16 {
+        Ansi16 { index: index % 16, layer }
+    }
+
+    /// Creates an escape sequence for the standard color nearest to any color.
+    pub fn nearest<C>(color: C, layer: Layer) -> Ansi16
+    where
+        C: FloatColor,
+        Srgb<FloatChannel<ColorChannel<C>>, ColorWhitePoint<C>>: ConvertFrom<C::Float>,
+        ColorWhitePoint<C>: WhitePoint<FloatChannel<ColorChannel<C>>>,
+    {
+        Ansi16::new(nearest_ansi_16(to_srgb_bytes(color)), layer)
+    }
+
+    /// The 8-bit sRGB color of the escape sequence, as displayed by xterm
+    #[inline]
+    pub fn rgb(&self) -> [u8; 3] {
+        ANSI_16[self.index as usize]
+    }
+}
+
+impl Display for Ansi16 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // The bright colors have their own range of codes
+        let base = match (self.layer, self.index < 8) {
+            (Layer::Foreground, true) => 30,
+            (Layer::Background, true) => 40,
+            (Layer::Foreground, false) => 90 - 8,
+            (Layer::Background, false) => 100 - 8,
+        };
+
+        write!(f, "\x1b[{}m", base + self.index)
+    }
+}
+
+/// An escape sequence selecting a color of the xterm 256 color palette, written with `Display`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ansi256 {
+    /// The index of the color in the palette
+    pub index: u8,
+    /// The layer colored
+    pub layer: Layer,
+}
+
+impl Ansi256 {
+    /// Creates an escape sequence for the palette color at `index`.
+    #[inline]
+    pub fn new(index: u8, layer: Layer) -> Ansi256 {
+        Ansi256 { index, layer }
+    }
+
+    /// Creates an escape sequence for the palette color nearest to any color.
+    ///
+    /// Only the color cube and gray ramp are searched, since the first 16 colors vary between terminals.
+    pub fn nearest<C>(color: C, layer: Layer) -> Ansi256
+    where
+        C: FloatColor,
+        Srgb<FloatChannel<ColorChannel<C>>, ColorWhitePoint<C>>: ConvertFrom<C::Float>,
+        ColorWhitePoint<C>: WhitePoint<FloatChannel<ColorChannel<C>>>,
+    {
+        Ansi256::new(nearest_ansi_256(to_srgb_bytes(color)), layer)
+    }
+
+    /// The 8-bit sRGB color of the escape sequence, from [`ansi_256_rgb`](fn.ansi_256_rgb.html)
+    #[inline]
+    pub fn rgb(&self) -> [u8; 3] {
+        ansi_256_rgb(self.index)
+    }
+}
+
+impl Display for Ansi256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "\x1b[{};5;{}m", self.layer.parameter(), self.index)
+    }
+}
+
+/// The index of the standard color nearest to an 8-bit sRGB color, by Euclidean distance in Oklab
+pub fn nearest_ansi_16(rgb: [u8; 3]) -> u8 {
+    palette_16().nearest(&oklab(rgb)).unwrap_or(0) as u8
+}
+
+/// The index of the color of the 256 color palette nearest to an 8-bit sRGB color, by Euclidean distance in Oklab
+///
+/// Only the color cube and gray ramp from index 16 on are searched, since the first 16 colors vary between
+/// terminals and duplicate colors of the cube.
+pub fn nearest_ansi_256(rgb: [u8; 3]) -> u8 {
+    palette_256().nearest(&oklab(rgb)).map_or(16, |i| i as u8 + 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_srgb_is_unchanged() {
+        let color = Srgb::<u8>::new(200, 100, 50);
+
+        assert_eq!(to_srgb_bytes(color), [200, 100, 50]);
+        assert_eq!(TrueColor::foreground(color).to_string(), "\x1b[38;2;200;100;50m");
+        assert_eq!(Ansi256::nearest(color, Layer::Foreground).rgb(), [215, 95, 0]);
+    }
+
+    #[test]
+    fn palette_colors_are_nearest_to_themselves() {
+        for (i, &[r, g, b]) in ANSI_16.iter().enumerate() {
+            assert_eq!(Ansi16::nearest(Srgb::<u8>::new(r, g, b), Layer::Foreground).index, i as u8);
+        }
+
+        for i in 16..=255 {
+            let [r, g, b] = ansi_256_rgb(i);
+
+            assert_eq!(Ansi256::nearest(Srgb::<u8>::new(r, g, b), Layer::Background).rgb(), [r, g, b]);
+        }
+    }
+}
@@ -0,0 +1,430 @@
+//! Blend modes, which combine a source color with the backdrop color behind it.
+//!
+//! The blend modes follow the W3C *Compositing and Blending* specification. The source and backdrop are
+//! blended where they overlap, and composited with the source-over operator elsewhere,
+//! see [`compose`](../compose/index.html).
+//!
+//! Blend modes are defined on RGB channels from 0.0 to 1.0. Light only mixes linearly, so colors
+//! should be in linear light, such as [`LinearSrgb`](../spaces/linear_srgb/struct.LinearSrgb.html),
+//! for physically correct results, although blending gamma-encoded colors matches many image editors.
+
+use num_traits::{Zero, Float};
+
+use channels::*;
+use color::{Color, ColorChannel, ColorChannels};
+use convert::ConvertFrom;
+use alpha::{Alpha, PreAlpha};
+use math::{self, Vector3};
+
+/// A blend mode
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    /// The source color, without blending
+    Normal,
+    /// The product of the source and backdrop, which always darkens
+    Multiply,
+    /// The complement of the product of the complements, which always lightens
+    Screen,
+    /// Multiplies or screens depending on the backdrop, increasing contrast
+    Overlay,
+    /// The darker of the source and backdrop
+    Darken,
+    /// The lighter of the source and backdrop
+    Lighten,
+    /// Brightens the backdrop to reflect the source
+    ColorDodge,
+    /// Darkens the backdrop to reflect the source
+    ColorBurn,
+    /// Multiplies or screens depending on the source, as if shining a harsh spotlight
+    HardLight,
+    /// Darkens or lightens depending on the source, as if shining a diffuse spotlight
+    SoftLight,
+    /// The absolute difference of the source and backdrop
+    Difference,
+    /// Similar to `Difference`, but with lower contrast
+    Exclusion,
+    /// The hue of the source with the saturation and luminosity of the backdrop
+    Hue,
+    /// The saturation of the source with the hue and luminosity of the backdrop
+    Saturation,
+    /// The hue and saturation of the source with the luminosity of the backdrop
+    Color,
+    /// The luminosity of the source with the hue and saturation of the backdrop
+    Luminosity,
+}
+
+#[inline]
+fn multiply<T: Float>(b: T, s: T) -> T {
+    b * s
+}
+
+#[inline]
+fn screen<T: Float>(b: T, s: T) -> T {
+    b + s - b * s
+}
+
+#[inline]
+fn hard_light<T: Float>(b: T, s: T) -> T {
+    let two: T = math::cast(2.0);
+
+    if s <= math::cast(0.5) {
+        multiply(b, s * two)
+    } else {
+        screen(b, s * two - T::one())
+    }
+}
+
+fn soft_light<T: Float>(b: T, s: T) -> T {
+    let one = T::one();
+    let two: T = math::cast(2.0);
+
+    if s <= math::cast(0.5) {
+        b - (one - two * s) * b * (one - b)
+    } else {
+        let d = if b <= math::cast(0.25) {
+            ((b * math::cast(16.0) - math::cast(12.0)) * b + math::cast(4.0)) * b
+        } else {
+            b.sqrt()
+        };
+
+        b + (two * s - one) * (d - b)
+    }
+}
+
+/// The luminosity of a color, with the weights of the W3C specification
+#[inline]
+fn lum<T: Float>([r, g, b]: Vector3<T>) -> T {
+    r * math::cast(0.3) + g * math::cast(0.59) + b * math::cast(0.11)
+}
+
+/// Brings a color back into the range 0.0 to 1.0 while preserving its luminosity.
+fn clip_color<T: Float>(c: Vector3<T>) -> Vector3<T> {
+    let l = lum(c);
+
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+
+    let mut c = c;
+
+    if n < T::zero() {
+        for v in &mut c {
+            *v = l + (*v - l) * l / (l - n);
+        }
+    }
+
+    if x > T::one() {
+        for v in &mut c {
+            *v = l + (*v - l) * (T::one() - l) / (x - l);
+        }
+    }
+
+    c
+}
+
+/// Sets the luminosity of a color.
+fn set_lum<T: Float>(c: Vector3<T>, l: T) -> Vector3<T> {
+    let d = l - lum(c);
+
+    clip_color([c[0] + d, c[1] + d, c[2] + d])
+}
+
+/// The saturation of a color, the difference between its largest and smallest channels
+#[inline]
+fn sat<T: Float>(c: Vector3<T>) -> T {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+/// Sets the saturation of a color, scaling the channels so the smallest is zero and the largest is `s`.
+fn set_sat<T: Float>(c: Vector3<T>, s: T) -> Vector3<T> {
+    let min = c[0].min(c[1]).min(c[2]);
+    let range = sat(c);
+
+    if range > T::zero() {
+        [(c[0] - min) * s / range, (c[1] - min) * s / range, (c[2] - min) * s / range]
+    } else {
+        [T::zero(); 3]
+    }
+}
+
+impl BlendMode {
+    /// Returns `true` if the blend mode applies to each channel independently.
+    ///
+    /// The `Hue`, `Saturation`, `Color` and `Luminosity` modes are non-separable,
+    /// and depend on all channels at once.
+    pub fn is_separable(self) -> bool {
+        !matches!(self, BlendMode::Hue | BlendMode::Saturation | BlendMode::Color | BlendMode::Luminosity)
+    }
+
+    /// Blends a single channel of the source with the backdrop.
+    ///
+    /// Non-separable blend modes cannot be applied to a single channel, and return the source.
+    /// Use [`blend_rgb`](#method.blend_rgb) for those.
+    pub fn blend_channel<T: Float>(self, backdrop: T, source: T) -> T {
+        let (b, s) = (backdrop, source);
+        let (zero, one) = (T::zero(), T::one());
+
+        match self {
+            BlendMode::Normal => s,
+            BlendMode::Multiply => multiply(b, s),
+            BlendMode::Screen => screen(b, s),
+            BlendMode::Overlay => hard_light(s, b),
+            BlendMode::Darken => b.min(s),
+            BlendMode::Lighten => b.max(s),
+            BlendMode::ColorDodge => if b <= zero {
+                zero
+            } else if s >= one {
+                one
+            } else {
+                (b / (one - s)).min(one)
+            },
+            BlendMode::ColorBurn => if b >= one {
+                one
+            } else if s <= zero {
+                zero
+            } else {
+                one - ((one - b) / s).min(one)
+            },
+            BlendMode::HardLight => hard_light(b, s),
+            BlendMode::SoftLight => soft_light(b, s),
+            BlendMode::Difference => (b - s).abs(),
+            BlendMode::Exclusion => b + s - math::cast::<T>(2.0) * b * s,
+            BlendMode::Hue | BlendMode::Saturation | BlendMode::Color | BlendMode::Luminosity => s,
+        }
+    }
+
+    /// Blends the RGB channels of the source with the backdrop.
+    pub fn blend_rgb<T: Float>(self, backdrop: Vector3<T>, source: Vector3<T>) -> Vector3<T> {
+        let (b, s) = (backdrop, source);
+
+        match self {
+            BlendMode::Hue => return set_lum(set_sat(s, sat(b)), lum(b)),
+            BlendMode::Saturation => return set_lum(set_sat(b, sat(s)), lum(b)),
+            BlendMode::Color => return set_lum(s, lum(b)),
+            BlendMode::Luminosity => return set_lum(b, lum(s)),
+            _ => {}
+        }
+
+        [
+            self.blend_channel(backdrop[0], source[0]),
+            self.blend_channel(backdrop[1], source[1]),
+            self.blend_channel(backdrop[2], source[2]),
+        ]
+    }
+
+    /// Blends the source with the backdrop, both with straight alpha, and composites the result over the backdrop.
+    ///
+    /// Returns the premultiplied color and the alpha of the result.
+    #[inline]
+    pub fn blend_composite<T: Float>(self, backdrop: Vector3<T>, backdrop_alpha: T, source: Vector3<T>, source_alpha: T) -> (Vector3<T>, T) {
+        composite(&self, backdrop, backdrop_alpha, source, source_alpha)
+    }
+}
+
+/// An object-safe blend function, from the RGB channels of the backdrop and source to the blended channels
+///
+/// `GenericBlend` is implemented for [`BlendMode`](enum.BlendMode.html) and for closures, and can be boxed
+/// as [`BoxedGenericBlend`](type.BoxedGenericBlend.html) to select a blend function at runtime,
+/// such as from a document file.
+pub trait GenericBlend<T: Float> {
+    fn blend_rgb(&self, backdrop: Vector3<T>, source: Vector3<T>) -> Vector3<T>;
+}
+
+/// A blend function that can be shared between threads
+pub trait BlendSync<T: Float>: GenericBlend<T> + Send + Sync {}
+
+impl<T: Float, B: GenericBlend<T> + Send + Sync> BlendSync<T> for B {}
+
+/// A boxed blend function
+pub type BoxedGenericBlend<T> = Box<dyn GenericBlend<T>>;
+
+/// A boxed blend function that can be shared between threads
+pub type BoxedGenericBlendSync<T> = Box<dyn BlendSync<T>>;
+
+impl<T: Float> GenericBlend<T> for BlendMode {
+    #[inline]
+    fn blend_rgb(&self, backdrop: Vector3<T>, source: Vector3<T>) -> Vector3<T> {
+        BlendMode::blend_rgb(*self, backdrop, source)
+    }
+}
+
+impl<T: Float, F> GenericBlend<T> for F
+where
+    F: Fn(Vector3<T>, Vector3<T>) -> Vector3<T>,
+{
+    #[inline]
+    fn blend_rgb(&self, backdrop: Vector3<T>, source: Vector3<T>) -> Vector3<T> {
+        self(backdrop, source)
+    }
+}
+
+/// Blends the source with the backdrop using any blend function, both with straight alpha,
+/// and composites the result over the backdrop.
+///
+/// Returns the premultiplied color and the alpha of the result.
+pub fn composite<T: Float, B>(blend: &B, backdrop: Vector3<T>, backdrop_alpha: T, source: Vector3<T>, source_alpha: T) -> (Vector3<T>, T)
+where
+    B: GenericBlend<T> + ?Sized,
+{
+    let one = T::one();
+
+    let blended = blend.blend_rgb(backdrop, source);
+
+    let (ab, as_) = (backdrop_alpha, source_alpha);
+
+    let mut res = [T::zero(); 3];
+
+    for i in 0..3 {
+        res[i] = as_ * (one - ab) * source[i] + ab * (one - as_) * backdrop[i] + as_ * ab * blended[i];
+    }
+
+    (res, as_ + ab * (one - as_))
+}
+
+/// Blending of a source color with the backdrop behind it, where `self` is the source
+pub trait Blend: Sized {
+    /// The floating point type the blend functions operate on
+    type Float: Float;
+
+    /// Blends the source with the backdrop using any blend function,
+    /// and composites the result over the backdrop.
+    fn blend_with<B: GenericBlend<Self::Float> + ?Sized>(self, backdrop: Self, blend: &B) -> Self;
+
+    /// Blends the source with the backdrop using the given blend mode,
+    /// and composites the result over the backdrop.
+    #[inline]
+    fn blend(self, backdrop: Self, mode: BlendMode) -> Self {
+        self.blend_with(backdrop, &mode)
+    }
+}
+
+/// The first three channels of a color, divided by alpha if premultiplied.
+#[inline]
+fn rgb<T: Float>(channels: &[T], alpha: T) -> Vector3<T> {
+    [channels[0] / alpha, channels[1] / alpha, channels[2] / alpha]
+}
+
+impl<C: Color> Blend for PreAlpha<C>
+where
+    C: Color<Channels = TripleChannel<ColorChannel<C>>>,
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    type Float = FloatChannel<ColorChannel<C>>;
+
+    fn blend_with<B: GenericBlend<Self::Float> + ?Sized>(self, backdrop: PreAlpha<C>, blend: &B) -> PreAlpha<C> {
+        let straight = |c: &PreAlpha<C>| {
+            let alpha = c.alpha.into_float();
+            let channels = c.color().channels().as_slice();
+
+            let float = [channels[0].into_float(), channels[1].into_float(), channels[2].into_float()];
+
+            if alpha > FloatChannel::<ColorChannel<C>>::zero() { (rgb(&float, alpha), alpha) } else { (float, alpha) }
+        };
+
+        let (b, ab) = straight(&backdrop);
+        let (s, as_) = straight(&self);
+
+        let (res, alpha) = composite(blend, b, ab, s, as_);
+
+        let mut out = self;
+
+        for (c, &v) in out.color_mut().channels_mut().as_mut_slice().iter_mut().zip(&res) {
+            *c = Channel::from_float(v);
+        }
+
+        out.alpha = Channel::from_float(alpha);
+
+        out
+    }
+}
+
+impl<C: Color> Blend for Alpha<C>
+where
+    C: Color<Channels = TripleChannel<ColorChannel<C>>>,
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    type Float = FloatChannel<ColorChannel<C>>;
+
+    #[inline]
+    fn blend_with<B: GenericBlend<Self::Float> + ?Sized>(self, backdrop: Alpha<C>, blend: &B) -> Alpha<C> {
+        self.premultiply().blend_with(backdrop.premultiply(), blend).unpremultiply()
+    }
+}
+
+/// The three channels of a color as floating point values.
+#[inline]
+fn float_channels<C>(color: &C) -> Vector3<FloatChannel<ColorChannel<C>>>
+where
+    C: Color<Channels = TripleChannel<ColorChannel<C>>>,
+{
+    let c = color.channels().as_slice();
+
+    [c[0].into_float(), c[1].into_float(), c[2].into_float()]
+}
+
+/// Blending and interpolation of opaque colors in a chosen working space
+///
+/// The result of blending depends on the space the channels are blended in. Blending in linear light
+/// is physically correct, blending gamma-encoded sRGB matches many image editors, and interpolating
+/// in a perceptual space such as Oklab gives even gradients. The working space `S` is selected by the caller:
+///
+/// ```ignore
+/// let mixed = a.mix_in::<Oklab<f32>>(b, 0.5);
+/// let multiplied = a.blend_in::<LinearSrgb<f32>>(b, BlendMode::Multiply);
+/// ```
+pub trait BlendIn: Color + Sized {
+    /// Converts the source and backdrop into the space `S`, blends their channels with the given
+    /// blend mode, and converts the result back.
+    #[inline]
+    fn blend_in<S>(self, backdrop: Self, mode: BlendMode) -> Self
+    where
+        S: Color<Channels = TripleChannel<ColorChannel<S>>> + ConvertFrom<Self>,
+        Self: ConvertFrom<S>,
+    {
+        self.blend_in_with::<S, _>(backdrop, &mode)
+    }
+
+    /// Converts the source and backdrop into the space `S`, blends their channels with any
+    /// blend function, and converts the result back.
+    fn blend_in_with<S, B>(self, backdrop: Self, blend: &B) -> Self
+    where
+        S: Color<Channels = TripleChannel<ColorChannel<S>>> + ConvertFrom<Self>,
+        Self: ConvertFrom<S>,
+        B: GenericBlend<FloatChannel<ColorChannel<S>>> + ?Sized,
+    {
+        let mut source = S::convert_from(self);
+        let backdrop = S::convert_from(backdrop);
+
+        let blended = blend.blend_rgb(float_channels(&backdrop), float_channels(&source));
+
+        for (c, &v) in source.channels_mut().as_mut_slice().iter_mut().zip(&blended) {
+            *c = Channel::from_float(v);
+        }
+
+        Self::convert_from(source)
+    }
+
+    /// Converts both colors into the space `S`, linearly interpolates their channels, and converts the result back,
+    /// where `t` of 0.0 is `self` and 1.0 is `other`.
+    ///
+    /// Channels are interpolated independently, so hues of cylindrical spaces are not interpolated
+    /// along the shorter arc.
+    fn mix_in<S>(self, other: Self, t: FloatChannel<ColorChannel<S>>) -> Self
+    where
+        S: Color + ConvertFrom<Self>,
+        ColorChannels<S>: ChannelSlice<ColorChannel<S>>,
+        Self: ConvertFrom<S>,
+    {
+        let mut a = S::convert_from(self);
+        let b = S::convert_from(other);
+
+        for (x, &y) in a.channels_mut().as_mut_slice().iter_mut().zip(b.channels().as_slice()) {
+            let (fx, fy) = (x.into_float(), y.into_float());
+
+            *x = Channel::from_float(fx + (fy - fx) * t);
+        }
+
+        Self::convert_from(a)
+    }
+}
+
+impl<T: Color> BlendIn for T {}
@@ -8,15 +8,30 @@
 
 use num_traits::{NumCast, Num, Float};
 
+use std::ops::Add;
+
 use typenum::consts::{U1, U2, U3, U4};
-use numeric_array::NumericArray;
+use typenum::{Add1, B1};
+use generic_array::ArrayLength;
+use numeric_array::{NumericArray, NumericSequence};
 
 /// Defines shared behavior for all color channels.
-pub trait Channel: Num + Copy + NumCast {
+pub trait Channel: Num + Copy + NumCast + 'static {
     type FloatChannel: Channel + Float;
 
     fn into_float(self) -> Self::FloatChannel;
     fn from_float(channel: Self::FloatChannel) -> Self;
+
+    /// Converts a channel of a component ranging from `min` to `max` into floating point.
+    ///
+    /// Floating point channels already hold the value of the component, while the full range of integer
+    /// channels is spread over the range of the component, such as L* from 0 to 100 or a hue angle from
+    /// 0 to 360 degrees.
+    fn into_float_in(self, min: f64, max: f64) -> Self::FloatChannel;
+
+    /// Converts the value of a component ranging from `min` to `max` into a channel, the inverse of
+    /// `into_float_in`. Integer channels saturate for values outside of the range.
+    fn from_float_in(channel: Self::FloatChannel, min: f64, max: f64) -> Self;
 }
 
 pub type FloatChannel<C> = <C as Channel>::FloatChannel;
@@ -37,6 +52,38 @@ pub type DualChannel<C> = NumericArray<<C as ChannelAssertion>::Channel, U2>;
 pub type TripleChannel<C> = NumericArray<<C as ChannelAssertion>::Channel, U3>;
 pub type QuadChannel<C> = NumericArray<<C as ChannelAssertion>::Channel, U4>;
 
+/// Channel containers that can be viewed as a slice of channels
+pub trait ChannelSlice<C> {
+    fn as_slice(&self) -> &[C];
+    fn as_mut_slice(&mut self) -> &mut [C];
+}
+
+impl<C: Channel, N: ArrayLength<C>> ChannelSlice<C> for NumericArray<C, N> {
+    #[inline]
+    fn as_slice(&self) -> &[C] {
+        self.as_array().as_slice()
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [C] {
+        self.as_mut_array().as_mut_slice()
+    }
+}
+
+/// Channel containers that can be extended with an alpha channel
+pub trait AlphaChannels<C: Channel>: ChannelSlice<C> {
+    /// The container with one more channel, stored last
+    type Channels: NumericSequence<C> + ChannelSlice<C>;
+}
+
+impl<C: Channel, N> AlphaChannels<C> for NumericArray<C, N>
+where
+    N: ArrayLength<C> + Add<B1>,
+    Add1<N>: ArrayLength<C>,
+{
+    type Channels = NumericArray<C, Add1<N>>;
+}
+
 macro_rules! impl_channel {
     ($($t:ty as $f:ty),*) => {
         $(
@@ -45,12 +92,26 @@ macro_rules! impl_channel {
 
                 #[inline]
                 fn into_float(self) -> Self::FloatChannel {
-                    self as $f * <$t>::max_value() as $f
+                    self as $f / <$t>::max_value() as $f
                 }
 
                 #[inline]
                 fn from_float(f: Self::FloatChannel) -> Self {
-                    (f / <$t>::max_value() as $f) as $t
+                    (f * <$t>::max_value() as $f).round() as $t
+                }
+
+                #[inline]
+                fn into_float_in(self, min: f64, max: f64) -> Self::FloatChannel {
+                    let steps = <$t>::max_value() as $f - <$t>::min_value() as $f;
+
+                    min as $f + (self as $f - <$t>::min_value() as $f) / steps * (max - min) as $f
+                }
+
+                #[inline]
+                fn from_float_in(f: Self::FloatChannel, min: f64, max: f64) -> Self {
+                    let steps = <$t>::max_value() as $f - <$t>::min_value() as $f;
+
+                    ((f - min as $f) / (max - min) as $f * steps + <$t>::min_value() as $f).round() as $t
                 }
             }
         )*
@@ -69,6 +130,16 @@ impl Channel for f32 {
     fn from_float(f: f32) -> f32 {
         f
     }
+
+    #[inline(always)]
+    fn into_float_in(self, _min: f64, _max: f64) -> f32 {
+        self
+    }
+
+    #[inline(always)]
+    fn from_float_in(f: f32, _min: f64, _max: f64) -> f32 {
+        f
+    }
 }
 
 impl Channel for f64 {
@@ -83,6 +154,16 @@ impl Channel for f64 {
     fn from_float(f: f64) -> f64 {
         f
     }
+
+    #[inline(always)]
+    fn into_float_in(self, _min: f64, _max: f64) -> f64 {
+        self
+    }
+
+    #[inline(always)]
+    fn from_float_in(f: f64, _min: f64, _max: f64) -> f64 {
+        f
+    }
 }
 
 impl_channel! {
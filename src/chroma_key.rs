@@ -0,0 +1,114 @@
+//! Chroma keying, such as for green screens.
+//!
+//! A chroma key compares colors to a key color in a chroma plane, ignoring lightness so that shadows and
+//! highlights on the backdrop are keyed as well. Colors within the inner tolerance of the key are fully
+//! transparent, colors beyond the outer tolerance are fully opaque, and the matte ramps linearly in between.
+
+use num_traits::Float;
+
+use channels::*;
+use alpha::Alpha;
+use color::{Color, ColorWhitePoint};
+use convert::ConvertFrom;
+use math;
+
+use spaces::srgb::Srgb;
+use spaces::oklab::Oklab;
+use spaces::ycbcr::{YCbCrStandard, Bt709};
+
+/// The chroma plane colors are compared in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeySpace {
+    /// The Cb and Cr axes of BT.709 Y'CbCr computed from gamma-encoded sRGB, each from -0.5 to 0.5,
+    /// as used by video hardware
+    YCbCr,
+    /// The a and b axes of Oklab, each roughly from -0.4 to 0.4, which are closer to perceptually uniform
+    Oklab,
+}
+
+/// A chroma key matte generator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChromaKey<T> {
+    space: KeySpace,
+    key: [T; 2],
+    /// The chroma distance within which colors are fully transparent
+    pub inner: T,
+    /// The chroma distance beyond which colors are fully opaque
+    pub outer: T,
+}
+
+impl<T: Channel<FloatChannel = T> + Float> ChromaKey<T> {
+    /// Creates a chroma key for the key color, with tolerances given as distances in the chroma plane of `space`.
+    pub fn new<C>(key: C, space: KeySpace, inner: T, outer: T) -> ChromaKey<T>
+    where
+        C: Color<Channel = T>,
+        Srgb<T, ColorWhitePoint<C>>: ConvertFrom<C>,
+        Oklab<T, ColorWhitePoint<C>>: ConvertFrom<C>,
+    {
+        ChromaKey { space, key: chroma(key, space), inner, outer }
+    }
+
+    /// The chroma plane of the key
+    #[inline]
+    pub fn space(&self) -> KeySpace {
+        self.space
+    }
+
+    /// Computes the matte of a color, from 0.0 for colors matching the key to 1.0 for colors far from it.
+    pub fn matte<C>(&self, color: C) -> T
+    where
+        C: Color<Channel = T>,
+        Srgb<T, ColorWhitePoint<C>>: ConvertFrom<C>,
+        Oklab<T, ColorWhitePoint<C>>: ConvertFrom<C>,
+    {
+        let [x, y] = chroma(color, self.space);
+
+        let d = (x - self.key[0]).hypot(y - self.key[1]);
+
+        if d <= self.inner {
+            T::zero()
+        } else if d >= self.outer {
+            T::one()
+        } else {
+            (d - self.inner) / (self.outer - self.inner)
+        }
+    }
+
+    /// Keys a color, using its matte as alpha.
+    pub fn key<C>(&self, color: C) -> Alpha<C>
+    where
+        C: Color<Channel = T> + Copy,
+        Srgb<T, ColorWhitePoint<C>>: ConvertFrom<C>,
+        Oklab<T, ColorWhitePoint<C>>: ConvertFrom<C>,
+    {
+        Alpha::from_color(color, self.matte(color))
+    }
+}
+
+/// The coordinates of a color in the chroma plane of `space`
+fn chroma<C, T>(color: C, space: KeySpace) -> [T; 2]
+where
+    T: Channel<FloatChannel = T> + Float,
+    C: Color<Channel = T>,
+    Srgb<T, ColorWhitePoint<C>>: ConvertFrom<C>,
+    Oklab<T, ColorWhitePoint<C>>: ConvertFrom<C>,
+{
+    match space {
+        KeySpace::YCbCr => {
+            let rgb = Srgb::convert_from(color);
+
+            let (kr, kb): (T, T) = (math::cast(Bt709::KR), math::cast(Bt709::KB));
+            let kg = T::one() - kr - kb;
+
+            let y = kr * rgb.r + kg * rgb.g + kb * rgb.b;
+            let two: T = math::cast(2.0);
+
+            [(rgb.b - y) / (two * (T::one() - kb)), (rgb.r - y) / (two * (T::one() - kr))]
+        }
+        KeySpace::Oklab => {
+            let lab = Oklab::convert_from(color);
+
+            [lab.a, lab.b]
+        }
+    }
+}
@@ -47,12 +47,51 @@ pub trait FromColor<C: Channel, Wp> where Wp: WhitePoint<C> {
     fn from_yxy(yxy: ::spaces::yxy::Yxy<C, Wp>) -> Self;
 }
 
+/// Colors that convert to and from the same color with floating point channels
+///
+/// The conversion to floating point is exact, so computations in between lose no more precision
+/// than rounding back into the channel type once.
+pub trait FloatColor: Color {
+    /// The same color with the floating point type of its channels
+    type Float: Color<Channel = FloatChannel<Self::Channel>, WhitePoint = Self::WhitePoint>;
+
+    fn into_float_color(self) -> Self::Float;
+    fn from_float_color(color: Self::Float) -> Self;
+}
+
+/// The type of a color component, either the channel itself or a hue angle stored in it
+macro_rules! component_type {
+    ($t:ident) => { $t };
+    ($t:ident, $angle:ident) => { ::hue::$angle<$t> };
+}
+
+/// The channel of a color component
+macro_rules! component_channel {
+    ($c:ident) => { $c };
+    ($c:ident : $angle:ident) => { $c.0 };
+}
+
+/// Converts a color component into a floating point channel. Components with a range other than 0.0 to 1.0,
+/// including hue angles, spread the full range of integer channels over their range.
+macro_rules! component_into_float {
+    ($c:ident) => { Channel::into_float($c) };
+    ($c:ident : $angle:ident) => { $c.into_float().0 };
+    ($c:ident in $min:literal .. $max:literal) => { Channel::into_float_in($c, $min, $max) };
+}
+
+/// Converts a floating point channel back into a color component, the inverse of `component_into_float`.
+macro_rules! component_from_float {
+    ($c:ident) => { Channel::from_float($c) };
+    ($c:ident : $angle:ident) => { ::hue::$angle::from_float($c).0 };
+    ($c:ident in $min:literal .. $max:literal) => { Channel::from_float_in($c, $min, $max) };
+}
+
 macro_rules! declare_color_components {
     ($(
         $(#[$($attrs:tt)*])*
-        struct $name:ident : $channels:ident => $component:ident { $(
+        struct $name:ident $(< $($p:ident : $bound:path = $default:ty),* >)* : $channels:ident => $component:ident { $(
             $(#[$($component_attrs:tt)*])*
-            pub $c:ident,
+            pub $c:ident $(: $angle:ident)* $(in $min:literal .. $max:literal)*,
         )* }
     ),*) => {
         /// Color components
@@ -60,28 +99,47 @@ macro_rules! declare_color_components {
             $(
                 /// Color components
                 #[repr(C)]
-                #[derive(Debug, PartialEq, Eq, Hash)]
                 pub struct $component<C> {
                     $(
                         $(#[$($component_attrs)*])*
-                        pub $c: C,
+                        pub $c: component_type!(C $(, $angle)*),
                     )*
                 }
 
                 impl<C: Copy> Clone for $component<C> {
                     fn clone(&self) -> $component<C> {
-                        $component { ..*self }
+                        *self
                     }
                 }
 
                 impl<C: Copy> Copy for $component<C> {}
+
+                impl<C: ::std::fmt::Debug> ::std::fmt::Debug for $component<C> {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                        f.debug_struct(stringify!($component)) $(.field(stringify!($c), &self.$c))* .finish()
+                    }
+                }
+
+                impl<C: PartialEq> PartialEq for $component<C> {
+                    fn eq(&self, other: &$component<C>) -> bool {
+                        true $(&& self.$c == other.$c)*
+                    }
+                }
+
+                impl<C: Eq> Eq for $component<C> {}
+
+                impl<C: ::std::hash::Hash> ::std::hash::Hash for $component<C> {
+                    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                        $(self.$c.hash(state);)*
+                    }
+                }
             )*
         }
 
         $(
             use self::components::$component;
 
-            impl<C: Channel, Wp> Deref for $name<C, Wp> {
+            impl<C: Channel, Wp $($(, $p)*)*> Deref for $name<C, Wp $($(, $p)*)*> {
                 type Target = $component<C>;
 
                 #[inline]
@@ -90,25 +148,25 @@ macro_rules! declare_color_components {
                 }
             }
 
-            impl<C: Channel, Wp> DerefMut for $name<C, Wp> {
+            impl<C: Channel, Wp $($(, $p)*)*> DerefMut for $name<C, Wp $($(, $p)*)*> {
                 #[inline]
                 fn deref_mut(&mut self) -> &mut $component<C> {
                     self.as_components_mut()
                 }
             }
 
-            impl<C: Channel, Wp> From<$component<C>> for $name<C, Wp>
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> From<$component<C>> for $name<C, Wp $($(, $p)*)*>
             where
                 Wp: WhitePoint<C>
             {
-                fn from(components: $component<C>) -> $name<C, Wp> {
+                fn from(components: $component<C>) -> $name<C, Wp $($(, $p)*)*> {
                     let $component { $($c,)* } = components;
 
-                    $name::with_wp($($c,)*)
+                    $name::with_wp($(component_channel!($c $(: $angle)*),)*)
                 }
             }
 
-            impl<C: Channel, Wp> Components for $name<C, Wp> {
+            impl<C: Channel, Wp $($(, $p)*)*> Components for $name<C, Wp $($(, $p)*)*> {
                 type Components = $component<C>;
 
                 #[inline]
@@ -125,23 +183,26 @@ macro_rules! declare_color_components {
     }
 }
 
+/// Declares color types, with optional type parameters after the channel type and white point,
+/// such as the matrix standard of `YCbCr`, given with their bounds and defaults.
 macro_rules! declare_color_format {
     ($(
         $(#[$($attrs:tt)*])*
-        struct $name:ident : $channels:ident => $component:ident { $(
+        struct $name:ident $(< $($p:ident : $bound:path = $default:ty),* >)* : $channels:ident => $component:ident { $(
             $(#[$($component_attrs:tt)*])*
-            pub $c:ident,
+            pub $c:ident $(: $angle:ident)* $(in $min:literal .. $max:literal)*,
         )* }
     ),*) => {
         $(
             $(#[$($attrs)*])*
             #[repr(C)]
-            pub struct $name<C: Channel = f32, Wp = ::white_point::D65> {
+            pub struct $name<C: Channel = f32, Wp = ::white_point::D65 $($(, $p = $default)*)*> {
                 channels: $channels<C>,
-                white_point: PhantomData<Wp>
+                white_point: PhantomData<Wp>,
+                space: PhantomData<($($($p,)*)*)>,
             }
 
-            impl<C: Channel, Wp> Debug for $name<C, Wp>
+            impl<C: Channel, Wp $($(, $p)*)*> Debug for $name<C, Wp $($(, $p)*)*>
             where
                 C: Debug
             {
@@ -150,26 +211,26 @@ macro_rules! declare_color_format {
                 }
             }
 
-            impl<C: Channel, Wp> Clone for $name<C, Wp> {
-                fn clone(&self) -> $name<C, Wp> {
-                    $name { ..*self }
+            impl<C: Channel, Wp $($(, $p)*)*> Clone for $name<C, Wp $($(, $p)*)*> {
+                fn clone(&self) -> $name<C, Wp $($(, $p)*)*> {
+                    *self
                 }
             }
 
-            impl<C: Channel, Wp> Copy for $name<C, Wp> {}
+            impl<C: Channel, Wp $($(, $p)*)*> Copy for $name<C, Wp $($(, $p)*)*> {}
 
-            impl<C: Channel> $name<C, ::white_point::D65> {
+            impl<C: Channel $($(, $p: $bound)*)*> $name<C, ::white_point::D65 $($(, $p)*)*> {
                 #[inline(always)]
-                pub fn new($($c: C,)*) -> $name<C, ::white_point::D65> {
+                pub fn new($($c: C,)*) -> $name<C, ::white_point::D65 $($(, $p)*)*> {
                     $name::with_wp($($c,)*)
                 }
             }
 
             // This is just some cheating to allow direct creation internally without caring about the whitepoint,
             // such as when converting between `C` and `FloatChannel<C>`
-            impl<C: Channel, Wp> $name<C, Wp> {
+            impl<C: Channel, Wp $($(, $p)*)*> $name<C, Wp $($(, $p)*)*> {
                 #[inline]
-                fn raw($($c: C,)*) -> $name<C, Wp> {
+                pub(crate) fn raw($($c: C,)*) -> $name<C, Wp $($(, $p)*)*> {
                     use std::mem;
 
                     debug_assert_eq!(mem::size_of::<$channels<C>>(), mem::size_of_val(&[$($c,)*]));
@@ -177,39 +238,38 @@ macro_rules! declare_color_format {
                     $name {
                         channels: unsafe { mem::transmute_copy(&[$($c,)*]) },
                         white_point: PhantomData,
+                        space: PhantomData,
                     }
                 }
             }
 
-            impl<C: Channel, Wp> $name<C, Wp>
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> $name<C, Wp $($(, $p)*)*>
             where
                 Wp: WhitePoint<C>
             {
                 #[inline(always)]
-                pub fn with_wp($($c: C,)*) -> $name<C, Wp> {
+                pub fn with_wp($($c: C,)*) -> $name<C, Wp $($(, $p)*)*> {
                     Self::raw($($c,)*)
                 }
             }
 
-            impl<C: Channel, Wp> $name<C, Wp> {
+            impl<C: Channel, Wp $($(, $p)*)*> $name<C, Wp $($(, $p)*)*> {
                 #[inline]
-                pub fn into_float(self) -> $name<FloatChannel<C>, Wp> {
-                    $name {
-                        channels: self.channels.into_array().map(Channel::into_float).into(),
-                        white_point: PhantomData,
-                    }
+                pub fn into_float(self) -> $name<FloatChannel<C>, Wp $($(, $p)*)*> {
+                    let $component { $($c,)* } = *self.as_components();
+
+                    $name::raw($(component_into_float!($c $(: $angle)* $(in $min .. $max)*),)*)
                 }
 
                 #[inline]
-                pub fn from_float(fcolor: $name<FloatChannel<C>, Wp>) -> Self {
-                    $name {
-                        channels: fcolor.channels.into_array().map(Channel::from_float).into(),
-                        white_point: PhantomData
-                    }
+                pub fn from_float(fcolor: $name<FloatChannel<C>, Wp $($(, $p)*)*>) -> Self {
+                    let $component { $($c,)* } = *fcolor.as_components();
+
+                    $name::raw($(component_from_float!($c $(: $angle)* $(in $min .. $max)*),)*)
                 }
             }
 
-            impl<C: Channel, Wp> Color for $name<C, Wp>
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> Color for $name<C, Wp $($(, $p)*)*>
             where
                 Wp: WhitePoint<C>
             {
@@ -217,8 +277,8 @@ macro_rules! declare_color_format {
                 type Channels = $channels<C>;
                 type WhitePoint = Wp;
 
-                fn from_channels(channels: $channels<C>) -> $name<C, Wp> {
-                    $name { channels, white_point: PhantomData }
+                fn from_channels(channels: $channels<C>) -> $name<C, Wp $($(, $p)*)*> {
+                    $name { channels, white_point: PhantomData, space: PhantomData }
                 }
 
                 fn channels(&self) -> &$channels<C> {
@@ -230,13 +290,26 @@ macro_rules! declare_color_format {
                 }
             }
 
-            impl<C: Channel, Wp> FromColor<C, Wp> for $name<C, Wp>
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> FromColor<C, Wp> for $name<C, Wp $($(, $p)*)*>
             where
                 Wp: WhitePoint<C>
             {
                 fn from_xyz(xyz: ::spaces::xyz::Xyz<C, Wp>) -> Self { xyz.into() }
                 fn from_yxy(yxy: ::spaces::yxy::Yxy<C, Wp>) -> Self { yxy.into() }
             }
+
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> FloatColor for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>
+            {
+                type Float = $name<FloatChannel<C>, Wp $($(, $p)*)*>;
+
+                #[inline]
+                fn into_float_color(self) -> Self::Float { self.into_float() }
+
+                #[inline]
+                fn from_float_color(color: Self::Float) -> Self { $name::from_float(color) }
+            }
         )*
     }
 }
@@ -244,25 +317,25 @@ macro_rules! declare_color_format {
 macro_rules! declare_color_formats_with_components {
     ($(
         $(#[$($attrs:tt)*])*
-        struct $name:ident : $channels:ident => $component:ident { $(
+        struct $name:ident $(< $($p:ident : $bound:path = $default:ty),* >)* : $channels:ident => $component:ident { $(
             $(#[$($component_attrs:tt)*])*
-            pub $c:ident,
+            pub $c:ident $(: $angle:ident)* $(in $min:literal .. $max:literal)*,
         )* }
     ),*) => {
         $(
             declare_color_format! {
                 $(#[$($attrs)*])*
-                struct $name : $channels => $component { $(
+                struct $name $(< $($p : $bound = $default),* >)* : $channels => $component { $(
                     $(#[$($component_attrs)*])*
-                    pub $c,
+                    pub $c $(: $angle)* $(in $min .. $max)*,
                 )* }
             }
 
             declare_color_components! {
                 $(#[$($attrs)*])*
-                struct $name : $channels => $component { $(
+                struct $name $(< $($p : $bound = $default),* >)* : $channels => $component { $(
                     $(#[$($component_attrs)*])*
-                    pub $c,
+                    pub $c $(: $angle)* $(in $min .. $max)*,
                 )* }
             }
         )*
@@ -272,31 +345,31 @@ macro_rules! declare_color_formats_with_components {
 macro_rules! declare_color_formats_with_components_plus_alpha_specialization {
     ($(
         $(#[$($attrs:tt)*])*
-        struct $name:ident : $channels:ident => $component:ident { $(
+        struct $name:ident $(< $($p:ident : $bound:path = $default:ty),* >)* : $channels:ident => $component:ident { $(
             $(#[$($component_attrs:tt)*])*
-            pub $c:ident,
+            pub $c:ident $(: $angle:ident)* $(in $min:literal .. $max:literal)*,
         )* }
     ),*) => {
         $(
             declare_color_formats_with_components! {
                 $(#[$($attrs)*])*
-                struct $name : $channels => $component { $(
+                struct $name $(< $($p : $bound = $default),* >)* : $channels => $component { $(
                     $(#[$($component_attrs)*])*
-                    pub $c,
+                    pub $c $(: $angle)* $(in $min .. $max)*,
                 )* }
             }
 
-            impl<C: Channel> Alpha<$name<C>> {
-                pub fn new($($c: C,)* alpha: C) -> Alpha<$name<C>> {
+            impl<C: Channel $($(, $p: $bound)*)*> Alpha<$name<C, ::white_point::D65 $($(, $p)*)*>> {
+                pub fn new($($c: C,)* alpha: C) -> Alpha<$name<C, ::white_point::D65 $($(, $p)*)*>> {
                     Alpha::from_color($name::new($($c,)*), alpha)
                 }
             }
 
-            impl<C: Channel, Wp> Alpha<$name<C, Wp>>
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> Alpha<$name<C, Wp $($(, $p)*)*>>
             where
                 Wp: WhitePoint<C>
             {
-                pub fn with_wp($($c: C,)* alpha: C) -> Alpha<$name<C, Wp>> {
+                pub fn with_wp($($c: C,)* alpha: C) -> Alpha<$name<C, Wp $($(, $p)*)*>> {
                     Alpha::from_color($name::with_wp($($c,)*), alpha)
                 }
             }
@@ -304,6 +377,65 @@ macro_rules! declare_color_formats_with_components_plus_alpha_specialization {
     }
 }
 
+/// Implements component-wise arithmetic between colors, and with scalars, for spaces where it is meaningful,
+/// such as linear light. Spaces of perceptual coordinates, such as `Lab`, only get `Add` and `Sub` for deltas.
+///
+/// The arithmetic is done on floating point channels, so that integer channels neither overflow nor ignore the
+/// ranges of their components. Scalars added or subtracted are channel values, while scalars multiplied or
+/// divided by are plain factors, so `Xyz::<u8>::new(..) * 2` doubles the color.
+macro_rules! impl_color_arithmetic {
+    ($($name:ident),*) => {
+        $(
+            impl_color_arithmetic!(@op $name, Add, add, +, channel);
+            impl_color_arithmetic!(@op $name, Sub, sub, -, channel);
+            impl_color_arithmetic!(@op $name, Mul, mul, *, factor);
+            impl_color_arithmetic!(@op $name, Div, div, /, factor);
+        )*
+    };
+
+    (deltas: $($name:ident),*) => {
+        $(
+            impl_color_arithmetic!(@op $name, Add, add, +, channel);
+            impl_color_arithmetic!(@op $name, Sub, sub, -, channel);
+        )*
+    };
+
+    (@scalar channel, $rhs:ident) => { Channel::into_float($rhs) };
+    (@scalar factor, $rhs:ident) => { <FloatChannel<C> as ::num_traits::NumCast>::from($rhs).unwrap() };
+
+    (@op $name:ident, $op:ident, $f:ident, $sym:tt, $scalar:ident) => {
+        impl<C: Channel, Wp> ::std::ops::$op for $name<C, Wp> {
+            type Output = $name<C, Wp>;
+
+            #[inline]
+            fn $f(self, rhs: $name<C, Wp>) -> $name<C, Wp> {
+                let (a, b) = (self.into_float(), rhs.into_float());
+
+                $name::from_float($name {
+                    channels: a.channels.into_array().zip(b.channels.into_array(), |a, b| a $sym b).into(),
+                    white_point: PhantomData,
+                    space: PhantomData,
+                })
+            }
+        }
+
+        impl<C: Channel, Wp> ::std::ops::$op<C> for $name<C, Wp> {
+            type Output = $name<C, Wp>;
+
+            #[inline]
+            fn $f(self, rhs: C) -> $name<C, Wp> {
+                let (a, rhs): (_, FloatChannel<C>) = (self.into_float(), impl_color_arithmetic!(@scalar $scalar, rhs));
+
+                $name::from_float($name {
+                    channels: a.channels.into_array().map(|a| a $sym rhs).into(),
+                    white_point: PhantomData,
+                    space: PhantomData,
+                })
+            }
+        }
+    };
+}
+
 /*
 macro_rules! declare_color_newtype_formats {
     ($(
@@ -337,7 +469,7 @@ macro_rules! declare_color_newtype_formats {
 
             impl<C: Channel, Wp> Clone for $name<C, Wp> {
                 fn clone(&self) -> $name<C, Wp> {
-                    $name { ..*self }
+                    *self
                 }
             }
 
@@ -0,0 +1,205 @@
+//! Affine color matrix transforms, as with the SVG `feColorMatrix` filter primitive.
+//!
+//! A [`ColorMatrix`](struct.ColorMatrix.html) is a 4×5 matrix whose rows compute the red, green, blue and alpha
+//! channels of the result from the RGBA channels of the input and a constant offset. Any 3×3 matrix of RGB
+//! channels can be extended to one, and matrices compose by multiplication, so a chain of filters can be
+//! collapsed into a single matrix before being applied to a whole image.
+//!
+//! The presets follow the SVG filter effects specification, which applies them to linear RGB by default.
+//! Channels are not clamped after the transform.
+
+use std::ops::Mul;
+
+use num_traits::Float;
+
+use channels::*;
+use alpha::Alpha;
+use color::{Color, ColorChannel};
+use math::{self, Matrix3, Vector3};
+
+/// An affine transform of RGBA channels, stored as a 4×5 row-major matrix
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorMatrix<T> {
+    /// Rows for red, green, blue and alpha, with columns for red, green, blue, alpha and the offset
+    pub m: [[T; 5]; 4],
+}
+
+impl<T: Float> ColorMatrix<T> {
+    /// Creates a color matrix from its rows.
+    #[inline]
+    pub fn new(m: [[T; 5]; 4]) -> ColorMatrix<T> {
+        ColorMatrix { m }
+    }
+
+    /// The identity transform
+    pub fn identity() -> ColorMatrix<T> {
+        let mut m = [[T::zero(); 5]; 4];
+
+        for (i, row) in m.iter_mut().enumerate() {
+            row[i] = T::one();
+        }
+
+        ColorMatrix { m }
+    }
+
+    /// Extends a 3×3 matrix of RGB channels, passing alpha through unchanged.
+    pub fn from_rgb(rgb: &Matrix3<T>) -> ColorMatrix<T> {
+        let mut res = ColorMatrix::identity();
+
+        for (row, rgb) in res.m.iter_mut().zip(rgb) {
+            row[..3].copy_from_slice(rgb);
+        }
+
+        res
+    }
+
+    /// The `saturate` preset, where 0.0 gives grayscale and 1.0 gives the identity.
+    pub fn saturate(s: T) -> ColorMatrix<T> {
+        let c = |a: f64, b: f64| math::cast::<T>(a) + math::cast::<T>(b) * s;
+
+        ColorMatrix::from_rgb(&[
+            [c(0.213, 0.787), c(0.715, -0.715), c(0.072, -0.072)],
+            [c(0.213, -0.213), c(0.715, 0.285), c(0.072, -0.072)],
+            [c(0.213, -0.213), c(0.715, -0.715), c(0.072, 0.928)],
+        ])
+    }
+
+    /// The `hueRotate` preset, rotating hues by `degrees` while approximately preserving luminance.
+    pub fn hue_rotate(degrees: T) -> ColorMatrix<T> {
+        const BASE: Matrix3<f64> = [
+            [0.213, 0.715, 0.072],
+            [0.213, 0.715, 0.072],
+            [0.213, 0.715, 0.072],
+        ];
+
+        const COS: Matrix3<f64> = [
+            [0.787, -0.715, -0.072],
+            [-0.213, 0.285, -0.072],
+            [-0.213, -0.715, 0.928],
+        ];
+
+        const SIN: Matrix3<f64> = [
+            [-0.213, -0.715, 0.928],
+            [0.143, 0.140, -0.283],
+            [-0.787, 0.715, 0.072],
+        ];
+
+        let (sin, cos) = degrees.to_radians().sin_cos();
+
+        let mut m = [[T::zero(); 3]; 3];
+
+        for i in 0..3 {
+            for j in 0..3 {
+                m[i][j] = math::cast::<T>(BASE[i][j]) + cos * math::cast(COS[i][j]) + sin * math::cast(SIN[i][j]);
+            }
+        }
+
+        ColorMatrix::from_rgb(&m)
+    }
+
+    /// The `luminanceToAlpha` preset, which zeroes the color channels and replaces alpha with the luminance.
+    pub fn luminance_to_alpha() -> ColorMatrix<T> {
+        let mut m = [[T::zero(); 5]; 4];
+
+        m[3][0] = math::cast(0.2125);
+        m[3][1] = math::cast(0.7154);
+        m[3][2] = math::cast(0.0721);
+
+        ColorMatrix { m }
+    }
+
+    /// Composes two transforms, applying `self` first and then `next`.
+    #[inline]
+    pub fn then(self, next: ColorMatrix<T>) -> ColorMatrix<T> {
+        next * self
+    }
+
+    /// Transforms RGBA channels.
+    pub fn apply_rgba(&self, rgba: [T; 4]) -> [T; 4] {
+        let mut res = [T::zero(); 4];
+
+        for (r, row) in res.iter_mut().zip(&self.m) {
+            *r = row[0] * rgba[0] + row[1] * rgba[1] + row[2] * rgba[2] + row[3] * rgba[3] + row[4];
+        }
+
+        res
+    }
+
+    /// Transforms opaque RGB channels, ignoring the alpha row.
+    #[inline]
+    pub fn apply_rgb(&self, [r, g, b]: Vector3<T>) -> Vector3<T> {
+        let [r, g, b, _] = self.apply_rgba([r, g, b, T::one()]);
+
+        [r, g, b]
+    }
+
+    /// Transforms the channels of an opaque RGB color.
+    pub fn transform<C>(&self, mut color: C) -> C
+    where
+        C: Color<Channels = TripleChannel<ColorChannel<C>>>,
+        ColorChannel<C>: Channel<FloatChannel = T>,
+    {
+        let channels = color.channels_mut().as_mut_slice();
+
+        let res = self.apply_rgb([channels[0].into_float(), channels[1].into_float(), channels[2].into_float()]);
+
+        for (c, v) in channels.iter_mut().zip(&res) {
+            *c = Channel::from_float(*v);
+        }
+
+        color
+    }
+
+    /// Transforms the channels of an RGB color with straight alpha.
+    pub fn transform_alpha<C>(&self, color: Alpha<C>) -> Alpha<C>
+    where
+        C: Color<Channels = TripleChannel<ColorChannel<C>>>,
+        ColorChannel<C>: Channel<FloatChannel = T>,
+    {
+        let (mut color, alpha) = color.into_parts();
+
+        let res = {
+            let channels = color.channels_mut().as_mut_slice();
+
+            let res = self.apply_rgba([channels[0].into_float(), channels[1].into_float(), channels[2].into_float(), alpha.into_float()]);
+
+            for (c, v) in channels.iter_mut().zip(&res) {
+                *c = Channel::from_float(*v);
+            }
+
+            res
+        };
+
+        Alpha::from_color(color, Channel::from_float(res[3]))
+    }
+}
+
+impl<T: Float> Default for ColorMatrix<T> {
+    #[inline]
+    fn default() -> ColorMatrix<T> {
+        ColorMatrix::identity()
+    }
+}
+
+impl<T: Float> Mul for ColorMatrix<T> {
+    type Output = ColorMatrix<T>;
+
+    /// Composes two transforms, where `rhs` is applied first.
+    fn mul(self, rhs: ColorMatrix<T>) -> ColorMatrix<T> {
+        let mut m = [[T::zero(); 5]; 4];
+
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                let mut sum = if j == 4 { self.m[i][4] } else { T::zero() };
+
+                for k in 0..4 {
+                    sum = sum + self.m[i][k] * rhs.m[k][j];
+                }
+
+                *v = sum;
+            }
+        }
+
+        ColorMatrix { m }
+    }
+}
@@ -0,0 +1,143 @@
+//! Porter-Duff compositing operators.
+//!
+//! Each operator combines a source color with a destination color, weighting each by a factor that
+//! depends on the alpha of the other, as described by Porter and Duff in *Compositing Digital Images*.
+//! Compositing is done on premultiplied alpha, see [`PreAlpha`](../alpha/struct.PreAlpha.html).
+//!
+//! Light only mixes linearly, so colors should be in linear light, such as
+//! [`LinearSrgb`](../spaces/linear_srgb/struct.LinearSrgb.html), for physically correct results.
+
+use num_traits::{One, Float};
+
+use channels::*;
+use color::{Color, ColorChannel, ColorChannels};
+use alpha::{Alpha, PreAlpha};
+
+/// A Porter-Duff compositing operator
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PorterDuff {
+    /// Neither the source nor the destination, fully transparent
+    Clear,
+    /// The source only
+    Source,
+    /// The destination only
+    Destination,
+    /// The source placed over the destination
+    Over,
+    /// The destination placed over the source
+    DestinationOver,
+    /// The source where the destination is opaque
+    In,
+    /// The destination where the source is opaque
+    DestinationIn,
+    /// The source where the destination is transparent
+    Out,
+    /// The destination where the source is transparent
+    DestinationOut,
+    /// The source where the destination is opaque, over the destination
+    Atop,
+    /// The destination where the source is opaque, over the source
+    DestinationAtop,
+    /// The source where the destination is transparent, and the destination where the source is transparent
+    Xor,
+    /// The sum of the source and destination, with the alpha clamped to one
+    Plus,
+}
+
+impl PorterDuff {
+    /// Returns the weights of the source and the destination, for the given source and destination alpha.
+    pub fn factors<T: Float>(self, src_alpha: T, dst_alpha: T) -> (T, T) {
+        let (zero, one) = (T::zero(), T::one());
+
+        match self {
+            PorterDuff::Clear => (zero, zero),
+            PorterDuff::Source => (one, zero),
+            PorterDuff::Destination => (zero, one),
+            PorterDuff::Over => (one, one - src_alpha),
+            PorterDuff::DestinationOver => (one - dst_alpha, one),
+            PorterDuff::In => (dst_alpha, zero),
+            PorterDuff::DestinationIn => (zero, src_alpha),
+            PorterDuff::Out => (one - dst_alpha, zero),
+            PorterDuff::DestinationOut => (zero, one - src_alpha),
+            PorterDuff::Atop => (dst_alpha, one - src_alpha),
+            PorterDuff::DestinationAtop => (one - dst_alpha, src_alpha),
+            PorterDuff::Xor => (one - dst_alpha, one - src_alpha),
+            PorterDuff::Plus => (one, one),
+        }
+    }
+}
+
+/// Porter-Duff compositing of colors with alpha, where `self` is the source
+///
+/// `Alpha` also has an inherent [`over`](../alpha/struct.Alpha.html#method.over) method, which flattens
+/// a color onto an opaque background and takes precedence in method call syntax, so the `over` operator
+/// of this trait must be called as `Compose::over(src, dst)` on `Alpha`.
+pub trait Compose: Sized {
+    /// Composites the source with the destination using the given operator.
+    fn compose(self, dst: Self, op: PorterDuff) -> Self;
+
+    /// Places the source over the destination.
+    #[inline]
+    fn over(self, dst: Self) -> Self {
+        self.compose(dst, PorterDuff::Over)
+    }
+
+    /// Keeps the source where the destination is opaque.
+    #[inline]
+    fn inside(self, dst: Self) -> Self {
+        self.compose(dst, PorterDuff::In)
+    }
+
+    /// Keeps the source where the destination is transparent.
+    #[inline]
+    fn outside(self, dst: Self) -> Self {
+        self.compose(dst, PorterDuff::Out)
+    }
+
+    /// Places the source over the destination where the destination is opaque.
+    #[inline]
+    fn atop(self, dst: Self) -> Self {
+        self.compose(dst, PorterDuff::Atop)
+    }
+
+    /// Keeps the parts of the source and destination that do not overlap.
+    #[inline]
+    fn xor(self, dst: Self) -> Self {
+        self.compose(dst, PorterDuff::Xor)
+    }
+
+    /// Adds the source and destination.
+    #[inline]
+    fn plus(self, dst: Self) -> Self {
+        self.compose(dst, PorterDuff::Plus)
+    }
+}
+
+impl<C: Color> Compose for PreAlpha<C>
+where
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    fn compose(mut self, dst: PreAlpha<C>, op: PorterDuff) -> PreAlpha<C> {
+        let (fa, fb) = op.factors(self.alpha.into_float(), dst.alpha.into_float());
+
+        for (s, &d) in self.channels_mut().as_mut_slice().iter_mut().zip(dst.channels().as_slice()) {
+            *s = Channel::from_float(s.into_float() * fa + d.into_float() * fb);
+        }
+
+        if op == PorterDuff::Plus {
+            self.alpha = Channel::from_float(self.alpha.into_float().min(FloatChannel::<ColorChannel<C>>::one()));
+        }
+
+        self
+    }
+}
+
+impl<C: Color> Compose for Alpha<C>
+where
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    #[inline]
+    fn compose(self, dst: Alpha<C>, op: PorterDuff) -> Alpha<C> {
+        self.premultiply().compose(dst.premultiply(), op).unpremultiply()
+    }
+}
@@ -0,0 +1,132 @@
+//! Contrast between foreground and background colors, for accessibility checks.
+//!
+//! The WCAG 2.x contrast ratio is defined on gamma-encoded sRGB, so these functions take `Srgb` colors and
+//! compute luminance from their channels directly, which is exact for integer channels as well.
+//! Convert colors of other spaces to floating point sRGB first.
+//!
+//! [`apca_contrast`](fn.apca_contrast.html) computes the lightness contrast of APCA, the Accessible Perceptual
+//! Contrast Algorithm, which accounts for the polarity of text and background.
+
+use num_traits::{Zero, Float};
+
+use channels::*;
+use white_point::WhitePoint;
+use gamma::srgb_decode;
+use math;
+
+use spaces::srgb::Srgb;
+
+/// The size of text, which determines the contrast it requires
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextSize {
+    /// Text below the large size
+    Normal,
+    /// Text of at least 18 point, or at least 14 point and bold
+    Large,
+}
+
+/// The relative luminance of an sRGB color as defined by WCAG 2.x
+///
+/// WCAG linearizes channels below 0.03928 rather than the 0.04045 of the sRGB standard. No 8-bit or 16-bit
+/// value falls between the two, so the results are identical for integer channels.
+fn luminance<C: Channel, Wp>(color: &Srgb<C, Wp>) -> FloatChannel<C>
+where
+    Wp: WhitePoint<C>,
+{
+    let f = |v: C| srgb_decode(v.into_float());
+
+    f(color.r) * math::cast(0.2126) + f(color.g) * math::cast(0.7152) + f(color.b) * math::cast(0.0722)
+}
+
+/// The WCAG 2.x contrast ratio of two colors, from 1.0 for equal luminance up to 21.0 for black and white
+///
+/// The ratio is symmetric, so the order of foreground and background does not matter.
+pub fn contrast_ratio<C: Channel, Wp>(a: &Srgb<C, Wp>, b: &Srgb<C, Wp>) -> FloatChannel<C>
+where
+    Wp: WhitePoint<C>,
+{
+    let (la, lb) = (luminance(a), luminance(b));
+    let offset: FloatChannel<C> = math::cast(0.05);
+
+    (la.max(lb) + offset) / (la.min(lb) + offset)
+}
+
+/// Checks for the minimum contrast of WCAG 2.x success criterion 1.4.3, level AA:
+/// 4.5:1 for normal text and 3:1 for large text.
+///
+/// The ratio is not rounded, so a ratio of 4.499:1 fails for normal text.
+pub fn passes_aa<C: Channel, Wp>(a: &Srgb<C, Wp>, b: &Srgb<C, Wp>, size: TextSize) -> bool
+where
+    Wp: WhitePoint<C>,
+{
+    let required = match size {
+        TextSize::Normal => 4.5,
+        TextSize::Large => 3.0,
+    };
+
+    contrast_ratio(a, b) >= math::cast(required)
+}
+
+/// Checks for the enhanced contrast of WCAG 2.x success criterion 1.4.6, level AAA:
+/// 7:1 for normal text and 4.5:1 for large text.
+///
+/// The ratio is not rounded, so a ratio of 6.999:1 fails for normal text.
+pub fn passes_aaa<C: Channel, Wp>(a: &Srgb<C, Wp>, b: &Srgb<C, Wp>, size: TextSize) -> bool
+where
+    Wp: WhitePoint<C>,
+{
+    let required = match size {
+        TextSize::Normal => 7.0,
+        TextSize::Large => 4.5,
+    };
+
+    contrast_ratio(a, b) >= math::cast(required)
+}
+
+/// Screen luminance of an sRGB color as estimated by APCA, with a plain 2.4 gamma
+fn apca_luminance<C: Channel, Wp>(color: &Srgb<C, Wp>) -> FloatChannel<C>
+where
+    Wp: WhitePoint<C>,
+{
+    let f = |v: C| v.into_float().max(FloatChannel::<C>::zero()).powf(math::cast(2.4));
+
+    f(color.r) * math::cast(0.2126729) + f(color.g) * math::cast(0.7151522) + f(color.b) * math::cast(0.0721750)
+}
+
+/// The APCA lightness contrast Lc of text on a background, with the constants of APCA-W3 0.0.98G-4g
+///
+/// Lc is positive for dark text on a light background and negative for light text on a dark background, since
+/// the two polarities are perceived differently. It ranges from about 106 for black on white to about -108 for white
+/// on black, and is zero for contrasts too low to measure.
+pub fn apca_contrast<C: Channel, Wp>(text: &Srgb<C, Wp>, background: &Srgb<C, Wp>) -> FloatChannel<C>
+where
+    Wp: WhitePoint<C>,
+{
+    let c = |v: f64| -> FloatChannel<C> { math::cast(v) };
+
+    let zero = c(0.0);
+
+    // Soft clamp of near-black luminances, modeling flare
+    let soft_clamp = |y: FloatChannel<C>| {
+        if y > c(0.022) { y } else { y + (c(0.022) - y).powf(c(1.414)) }
+    };
+
+    let text = soft_clamp(apca_luminance(text));
+    let background = soft_clamp(apca_luminance(background));
+
+    if (background - text).abs() < c(0.0005) {
+        return zero;
+    }
+
+    let lc = if background > text {
+        let sapc = (background.powf(c(0.56)) - text.powf(c(0.57))) * c(1.14);
+
+        if sapc < c(0.1) { zero } else { sapc - c(0.027) }
+    } else {
+        let sapc = (background.powf(c(0.65)) - text.powf(c(0.62))) * c(1.14);
+
+        if sapc > c(-0.1) { zero } else { sapc + c(0.027) }
+    };
+
+    lc * c(100.0)
+}
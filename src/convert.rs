@@ -0,0 +1,94 @@
+//! Conversions between any two color spaces.
+//!
+//! Every color space can be converted to and from CIE XYZ, so instead of implementing `From` between
+//! each pair of spaces, [`ConvertFrom`](trait.ConvertFrom.html) routes any color through XYZ into any
+//! other space with the same channel type and white point:
+//!
+//! ```ignore
+//! use color::convert::IntoColor;
+//!
+//! let hsv = lab.into_color::<Hsv<_>>();
+//! ```
+//!
+//! The intermediate XYZ color uses the floating point type of the channels, so colors with integer
+//! channels are only rounded once, into the channel type of the result. Converting a color into its own
+//! space returns it unchanged. Where a direct `From` conversion exists, such as between sRGB and HSV,
+//! it will usually be faster.
+
+use std::any::Any;
+
+use channels::FloatChannel;
+use color::{Color, FromColor, FloatColor, ColorChannel, ColorWhitePoint};
+use white_point::WhitePoint;
+
+use spaces::xyz::Xyz;
+
+/// Converts a color of any color space into `Self`, by way of CIE XYZ
+pub trait ConvertFrom<T>: Sized {
+    fn convert_from(color: T) -> Self;
+}
+
+impl<T, U> ConvertFrom<T> for U
+where
+    T: FloatColor + 'static,
+    U: FloatColor<Channel = ColorChannel<T>, WhitePoint = ColorWhitePoint<T>> + 'static,
+    U::Float: FromColor<FloatChannel<ColorChannel<T>>, ColorWhitePoint<T>>,
+    ColorWhitePoint<T>: WhitePoint<FloatChannel<ColorChannel<T>>>,
+    Xyz<FloatChannel<ColorChannel<T>>, ColorWhitePoint<T>>: From<T::Float>,
+{
+    fn convert_from(color: T) -> U {
+        let mut color = Some(color);
+
+        // Converting a color into its own space is the identity
+        if let Some(same) = (&mut color as &mut dyn Any).downcast_mut::<Option<U>>() {
+            if let Some(same) = same.take() {
+                return same;
+            }
+        }
+
+        match color {
+            Some(color) => U::from_float_color(U::Float::from_xyz(Xyz::from(color.into_float_color()))),
+            None => unreachable!(),
+        }
+    }
+}
+
+/// Converts a color into any other color space, the reciprocal of [`ConvertFrom`](trait.ConvertFrom.html)
+pub trait IntoColor: Sized {
+    fn into_color<U: ConvertFrom<Self>>(self) -> U;
+}
+
+impl<T: Color> IntoColor for T {
+    #[inline]
+    fn into_color<U: ConvertFrom<T>>(self) -> U {
+        U::convert_from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use convert::ConvertFrom;
+    use spaces::srgb::Srgb;
+    use spaces::display_p3::DisplayP3;
+
+    #[test]
+    fn identity() {
+        let rgb = Srgb::<u8>::new(200, 100, 50);
+        let same = Srgb::<u8>::convert_from(rgb);
+        assert_eq!((same.r, same.g, same.b), (200, 100, 50));
+
+        let rgb = Srgb::new(0.1f32, 0.2, 0.3);
+        let same = Srgb::<f32>::convert_from(rgb);
+        assert_eq!((same.r, same.g, same.b), (0.1, 0.2, 0.3));
+    }
+
+    #[test]
+    fn integer_channels_round_once() {
+        for &(r, g, b) in &[(200, 100, 50), (255, 255, 255), (0, 128, 255), (17, 34, 51), (240, 16, 128)] {
+            let p3 = DisplayP3::<u8>::convert_from(Srgb::<u8>::new(r, g, b));
+            let expected = DisplayP3::<u8>::from_float(DisplayP3::convert_from(Srgb::<u8>::new(r, g, b).into_float()));
+
+            assert_eq!((p3.r, p3.g, p3.b), (expected.r, expected.g, expected.b));
+        }
+    }
+}
@@ -0,0 +1,1060 @@
+//! Parsing of CSS Color Module Level 4 colors.
+//!
+//! [`CssColor`](enum.CssColor.html) parses the color functions of CSS into the color space they are written in:
+//!
+//! * hex colors, such as `#ff8800`
+//! * [named colors](../named/index.html), such as `rebeccapurple`, and `transparent`
+//! * `rgb()` and `rgba()`, `hsl()` and `hsla()`, and `hwb()`
+//! * `lab()` and `lch()`, which CSS defines relative to D50
+//! * `oklab()` and `oklch()`
+//! * `color()` with the `srgb`, `srgb-linear`, `display-p3`, `xyz`, `xyz-d65` and `xyz-d50` color spaces
+//!
+//! Both the legacy comma-separated syntax and the modern space-separated syntax with `/` before alpha are
+//! accepted, as are percentages in place of numbers, hue angles in any CSS unit, and the `none` keyword,
+//! which is treated as zero. Function names and units are case-insensitive. Values are clamped to the
+//! ranges CSS clamps them to, so out of gamut colors in wide gamut spaces are preserved. Invalid colors fail with
+//! a [`ParseColorError`](../parse/struct.ParseColorError.html) locating the mistake.
+//!
+//! ```ignore
+//! let color: CssColor = "oklch(70% 0.15 250 / 50%)".parse()?;
+//! let srgb = color.to_srgba();
+//! ```
+//!
+//! Colors serialize back to CSS with [`to_css_string`](enum.CssColor.html#method.to_css_string) or `Display`,
+//! in the function of their color space, such as `oklch(62% 0.2 30deg)` or `color(display-p3 1 0 0)`.
+//! The spaces CSS can express, such as `Oklch`, `DisplayP3` and `Srgb<u8>`, have a `to_css_string` of their own.
+//! [`CssFormat`](struct.CssFormat.html) selects the legacy comma syntax and the number of decimals.
+//!
+//! [`CssGradient`](struct.CssGradient.html) parses `linear-gradient()` and `conic-gradient()` into a
+//! [`Gradient`](../gradient/struct.Gradient.html) of their color stops, and serializes them back.
+
+use std::str::FromStr;
+use std::fmt::{self, Display, Formatter};
+
+use num_traits::{Float, ToPrimitive};
+
+use channels::*;
+use alpha::Alpha;
+use adapt::AdaptFrom;
+use convert::ConvertFrom;
+use parse::{ParseColorError, ParseErrorKind};
+use named;
+use gradient::{Gradient, GradientStop};
+use white_point::{D50, D65};
+use math;
+
+use spaces::all::*;
+
+/// A color parsed from CSS, in the color space it was written in
+#[derive(Debug, Clone, Copy)]
+pub enum CssColor<T: Channel = f32> {
+    /// Hex colors, named colors, `rgb()`, `rgba()` and `color(srgb ...)`
+    Srgb(Alpha<Srgb<T, D65>>),
+    /// `color(srgb-linear ...)`
+    LinearSrgb(Alpha<LinearSrgb<T, D65>>),
+    /// `color(display-p3 ...)`
+    DisplayP3(Alpha<DisplayP3<T, D65>>),
+    /// `hsl()` and `hsla()`
+    Hsl(Alpha<Hsl<T, D65>>),
+    /// `hwb()`
+    Hwb(Alpha<Hwb<T, D65>>),
+    /// `lab()`
+    Lab(Alpha<Lab<T, D50>>),
+    /// `lch()`
+    Lch(Alpha<Lch<T, D50>>),
+    /// `oklab()`
+    Oklab(Alpha<Oklab<T, D65>>),
+    /// `oklch()`
+    Oklch(Alpha<Oklch<T, D65>>),
+    /// `color(xyz ...)` and `color(xyz-d65 ...)`
+    XyzD65(Alpha<Xyz<T, D65>>),
+    /// `color(xyz-d50 ...)`
+    XyzD50(Alpha<Xyz<T, D50>>),
+}
+
+/// Options for serializing a [`CssColor`](enum.CssColor.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CssFormat {
+    /// Writes `rgb()`, `rgba()`, `hsl()` and `hsla()` colors with the legacy comma-separated syntax.
+    /// Other functions have no legacy syntax and are unaffected.
+    pub legacy: bool,
+    /// The maximum number of decimals of each value. Trailing zeros are omitted.
+    pub precision: usize,
+}
+
+impl Default for CssFormat {
+    /// The modern syntax with up to 4 decimals
+    #[inline]
+    fn default() -> CssFormat {
+        CssFormat { legacy: false, precision: 4 }
+    }
+}
+
+impl CssFormat {
+    /// The legacy comma-separated syntax with up to 4 decimals
+    #[inline]
+    pub fn legacy() -> CssFormat {
+        CssFormat { legacy: true, ..CssFormat::default() }
+    }
+
+    /// Sets the maximum number of decimals.
+    #[inline]
+    pub fn with_precision(self, precision: usize) -> CssFormat {
+        CssFormat { precision, ..self }
+    }
+}
+
+/// Writes a number rounded to `precision` decimals, without trailing zeros or a negative zero.
+fn write_number(out: &mut String, value: f64, precision: usize, unit: &str) {
+    let mut number = format!("{:.*}", precision, value);
+
+    if number.contains('.') {
+        let len = number.trim_end_matches('0').trim_end_matches('.').len();
+
+        number.truncate(len);
+    }
+
+    if number == "-0" {
+        number.remove(0);
+    }
+
+    out.push_str(&number);
+    out.push_str(unit);
+}
+
+/// Writes a color function of three values with their units and the alpha, if not opaque. The name of the color
+/// space follows the opening parenthesis for `color()`.
+fn write_function(out: &mut String, name: &str, space: Option<&str>, values: [(f64, &str); 3], alpha: f64, format: &CssFormat) {
+    let legacy = format.legacy && (name == "rgb" || name == "hsl");
+
+    out.push_str(name);
+
+    if legacy && alpha < 1.0 {
+        out.push('a');
+    }
+
+    out.push('(');
+
+    if let Some(space) = space {
+        out.push_str(space);
+        out.push(' ');
+    }
+
+    for (i, &(value, unit)) in values.iter().enumerate() {
+        if i > 0 {
+            out.push_str(if legacy { ", " } else { " " });
+        }
+
+        write_number(out, value, format.precision, unit);
+    }
+
+    if alpha < 1.0 {
+        out.push_str(if legacy { ", " } else { " / " });
+
+        write_number(out, alpha, format.precision, "");
+    }
+
+    out.push(')');
+}
+
+/// An argument of a color function
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    Number(f64),
+    Percentage(f64),
+    /// An angle, in degrees
+    Angle(f64),
+    None,
+}
+
+/// An argument of a color function with its byte offset in the input
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Argument {
+    value: Value,
+    offset: usize,
+}
+
+/// The byte offset of a slice of the input within it
+#[inline]
+fn offset_of(input: &str, part: &str) -> usize {
+    part.as_ptr() as usize - input.as_ptr() as usize
+}
+
+#[inline]
+fn error(kind: ParseErrorKind, offset: usize, expected: &'static str) -> ParseColorError {
+    ParseColorError::new(kind, offset).expected(expected)
+}
+
+impl Argument {
+    fn parse(input: &str, token: &str) -> Result<Argument, ParseColorError> {
+        let offset = offset_of(input, token);
+
+        let invalid = || error(ParseErrorKind::InvalidValue, offset, "a number, percentage, angle or `none`");
+
+        if token.eq_ignore_ascii_case("none") {
+            return Ok(Argument { value: Value::None, offset });
+        }
+
+        let split = token.len() - token.bytes().rev().take_while(|b| b.is_ascii_alphabetic() || *b == b'%').count();
+        let (number, unit) = token.split_at(split);
+
+        let number: f64 = number.parse().map_err(|_| invalid())?;
+
+        if !number.is_finite() {
+            return Err(invalid());
+        }
+
+        let unit = unit.to_ascii_lowercase();
+
+        let value = match unit.as_str() {
+            "" => Value::Number(number),
+            "%" => Value::Percentage(number),
+            "deg" => Value::Angle(number),
+            "rad" => Value::Angle(number.to_degrees()),
+            "grad" => Value::Angle(number * 0.9),
+            "turn" => Value::Angle(number * 360.0),
+            _ => return Err(invalid()),
+        };
+
+        Ok(Argument { value, offset })
+    }
+
+    /// A number, or a percentage where 100% is `full`
+    fn number(self, full: f64) -> Result<f64, ParseColorError> {
+        match self.value {
+            Value::Number(n) => Ok(n),
+            Value::Percentage(p) => Ok(p / 100.0 * full),
+            Value::None => Ok(0.0),
+            Value::Angle(_) => Err(error(ParseErrorKind::InvalidValue, self.offset, "a number or percentage")),
+        }
+    }
+
+    /// A hue in degrees, where numbers are degrees
+    fn hue(self) -> Result<f64, ParseColorError> {
+        match self.value {
+            Value::Number(deg) | Value::Angle(deg) => Ok(deg),
+            Value::None => Ok(0.0),
+            Value::Percentage(_) => Err(error(ParseErrorKind::InvalidValue, self.offset, "a number or angle")),
+        }
+    }
+}
+
+/// Splits the arguments of a color function into three channels and an optional alpha, in either syntax.
+fn arguments(input: &str, args: &str) -> Result<([Argument; 3], Option<Argument>), ParseColorError> {
+    let end = offset_of(input, args) + args.len();
+
+    let (channels, alpha): (Vec<&str>, Option<&str>) = if args.contains(',') {
+        // Legacy syntax, where every argument is separated by a comma
+        let mut tokens: Vec<&str> = args.split(',').map(str::trim).collect();
+
+        for token in &tokens {
+            if token.is_empty() {
+                return Err(error(ParseErrorKind::InvalidSyntax, offset_of(input, token), "a value"));
+            }
+
+            if let Some(i) = token.find(|c: char| c.is_whitespace() || c == '/') {
+                return Err(error(ParseErrorKind::InvalidSyntax, offset_of(input, token) + i, "`,`"));
+            }
+        }
+
+        if tokens.len() > 4 {
+            return Err(error(ParseErrorKind::ArgumentCount, offset_of(input, tokens[4]), "`)`"));
+        }
+
+        let alpha = if tokens.len() == 4 { tokens.pop() } else { None };
+
+        (tokens, alpha)
+    } else {
+        let mut parts = args.splitn(2, '/');
+
+        let channels: Vec<&str> = parts.next().unwrap_or("").split_whitespace().collect();
+
+        if channels.len() > 3 {
+            return Err(error(ParseErrorKind::ArgumentCount, offset_of(input, channels[3]), "`/` or `)`"));
+        }
+
+        let alpha = match parts.next() {
+            Some(alpha) => {
+                let alpha = alpha.trim();
+
+                if alpha.is_empty() {
+                    return Err(error(ParseErrorKind::InvalidSyntax, end, "alpha"));
+                }
+
+                if let Some(i) = alpha.find(char::is_whitespace) {
+                    return Err(error(ParseErrorKind::InvalidSyntax, offset_of(input, alpha) + i, "`)`"));
+                }
+
+                Some(alpha)
+            }
+            None => None,
+        };
+
+        (channels, alpha)
+    };
+
+    if channels.len() < 3 {
+        // Point at the `/` before alpha, or at the end
+        let offset = if alpha.is_some() { offset_of(input, args) + args.find('/').unwrap_or(0) } else { end };
+
+        return Err(error(ParseErrorKind::ArgumentCount, offset, "a value"));
+    }
+
+    let alpha = match alpha {
+        Some(alpha) => Some(Argument::parse(input, alpha)?),
+        None => None,
+    };
+
+    let channels = [
+        Argument::parse(input, channels[0])?,
+        Argument::parse(input, channels[1])?,
+        Argument::parse(input, channels[2])?,
+    ];
+
+    Ok((channels, alpha))
+}
+
+#[inline]
+fn clamp(value: f64, min: f64, max: f64) -> f64 {
+    value.max(min).min(max)
+}
+
+/// Parses an optional alpha argument, which is opaque if missing.
+fn alpha(alpha: Option<Argument>) -> Result<f64, ParseColorError> {
+    match alpha {
+        Some(alpha) => Ok(clamp(alpha.number(1.0)?, 0.0, 1.0)),
+        None => Ok(1.0),
+    }
+}
+
+impl<T: Channel<FloatChannel = T> + Float> CssColor<T> {
+    /// Parses a hex color, named color or color function, ignoring surrounding whitespace.
+    pub fn parse(input: &str) -> Result<CssColor<T>, ParseColorError> {
+        let s = input.trim();
+        let start = offset_of(input, s);
+
+        if s.is_empty() {
+            return Err(error(ParseErrorKind::Empty, input.len(), "a color"));
+        }
+
+        if s.starts_with('#') {
+            return s.parse().map(CssColor::Srgb).map_err(|err: ParseColorError| err.shift(start));
+        }
+
+        let open = match s.find('(') {
+            Some(open) => open,
+            None if s.eq_ignore_ascii_case("transparent") => return Ok(CssColor::Srgb(Alpha::transparent())),
+            None => {
+                let color = named::from_name(s)
+                    .ok_or_else(|| error(ParseErrorKind::UnknownName, start, "a color name, hex color or color function"))?;
+
+                return Ok(CssColor::Srgb(Alpha::opaque(color)));
+            }
+        };
+
+        if !s.ends_with(')') {
+            return Err(error(ParseErrorKind::InvalidSyntax, start + s.len(), "`)`"));
+        }
+
+        let name = s[..open].trim_end().to_ascii_lowercase();
+        let args = &s[open + 1..s.len() - 1];
+
+        if name == "color" {
+            return CssColor::parse_color_function(input, args);
+        }
+
+        let f = |v: f64| -> T { math::cast(v) };
+
+        let parsed = match name.as_str() {
+            "rgb" | "rgba" | "hsl" | "hsla" | "hwb" | "lab" | "lch" | "oklab" | "oklch" => arguments(input, args)?,
+            _ => {
+                let expected = "`rgb`, `rgba`, `hsl`, `hsla`, `hwb`, `lab`, `lch`, `oklab`, `oklch` or `color`";
+
+                return Err(error(ParseErrorKind::UnknownFunction, start, expected));
+            }
+        };
+
+        let ([a, b, c], alpha) = parsed;
+        let alpha = f(self::alpha(alpha)?);
+
+        Ok(match name.as_str() {
+            "rgb" | "rgba" => {
+                let channel = |v: Argument| v.number(255.0).map(|v| f(clamp(v, 0.0, 255.0) / 255.0));
+
+                CssColor::Srgb(Alpha::from_color(Srgb::with_wp(channel(a)?, channel(b)?, channel(c)?), alpha))
+            }
+            "hsl" | "hsla" => {
+                let (s, l) = (b.number(100.0)?, c.number(100.0)?);
+
+                let hsl = Hsl::with_wp(f(a.hue()?), f(clamp(s / 100.0, 0.0, 1.0)), f(clamp(l / 100.0, 0.0, 1.0)));
+
+                CssColor::Hsl(Alpha::from_color(hsl, alpha))
+            }
+            "hwb" => {
+                let (w, bl) = (b.number(100.0)?, c.number(100.0)?);
+
+                let hwb = Hwb::with_wp(f(a.hue()?), f(clamp(w / 100.0, 0.0, 1.0)), f(clamp(bl / 100.0, 0.0, 1.0)));
+
+                CssColor::Hwb(Alpha::from_color(hwb, alpha))
+            }
+            "lab" => {
+                let lab = Lab::with_wp(f(clamp(a.number(100.0)?, 0.0, 100.0)), f(b.number(125.0)?), f(c.number(125.0)?));
+
+                CssColor::Lab(Alpha::from_color(lab, alpha))
+            }
+            "lch" => {
+                let lch = Lch::with_wp(f(clamp(a.number(100.0)?, 0.0, 100.0)), f(b.number(150.0)?.max(0.0)), f(c.hue()?));
+
+                CssColor::Lch(Alpha::from_color(lch, alpha))
+            }
+            "oklab" => {
+                let lab = Oklab::with_wp(f(clamp(a.number(1.0)?, 0.0, 1.0)), f(b.number(0.4)?), f(c.number(0.4)?));
+
+                CssColor::Oklab(Alpha::from_color(lab, alpha))
+            }
+            _ => {
+                let lch = Oklch::with_wp(f(clamp(a.number(1.0)?, 0.0, 1.0)), f(b.number(0.4)?.max(0.0)), f(c.hue()?));
+
+                CssColor::Oklch(Alpha::from_color(lch, alpha))
+            }
+        })
+    }
+
+    /// Parses the arguments of `color()`, starting with the name of the color space.
+    fn parse_color_function(input: &str, args: &str) -> Result<CssColor<T>, ParseColorError> {
+        let args = args.trim_start();
+
+        let end = args.find(char::is_whitespace).unwrap_or(args.len());
+        let space = &args[..end];
+
+        if space.is_empty() {
+            return Err(error(ParseErrorKind::ArgumentCount, offset_of(input, args), "a color space"));
+        }
+
+        if let Some(i) = args.find(',') {
+            return Err(error(ParseErrorKind::InvalidSyntax, offset_of(input, args) + i, "space-separated values"));
+        }
+
+        let f = |v: f64| -> T { math::cast(v) };
+
+        let new: fn(T, T, T, T) -> CssColor<T> = match space.to_ascii_lowercase().as_str() {
+            "srgb" => |a, b, c, alpha| CssColor::Srgb(Alpha::from_color(Srgb::with_wp(a, b, c), alpha)),
+            "srgb-linear" => |a, b, c, alpha| CssColor::LinearSrgb(Alpha::from_color(LinearSrgb::with_wp(a, b, c), alpha)),
+            "display-p3" => |a, b, c, alpha| CssColor::DisplayP3(Alpha::from_color(DisplayP3::with_wp(a, b, c), alpha)),
+            "xyz" | "xyz-d65" => |a, b, c, alpha| CssColor::XyzD65(Alpha::from_color(Xyz::with_wp(a, b, c), alpha)),
+            "xyz-d50" => |a, b, c, alpha| CssColor::XyzD50(Alpha::from_color(Xyz::with_wp(a, b, c), alpha)),
+            _ => {
+                let expected = "`srgb`, `srgb-linear`, `display-p3`, `xyz`, `xyz-d65` or `xyz-d50`";
+
+                return Err(error(ParseErrorKind::UnknownColorSpace, offset_of(input, space), expected));
+            }
+        };
+
+        let ([a, b, c], alpha) = arguments(input, &args[end..])?;
+
+        Ok(new(f(a.number(1.0)?), f(b.number(1.0)?), f(c.number(1.0)?), f(self::alpha(alpha)?)))
+    }
+
+    /// The alpha of the color
+    pub fn alpha(&self) -> T {
+        match *self {
+            CssColor::Srgb(c) => c.alpha,
+            CssColor::LinearSrgb(c) => c.alpha,
+            CssColor::DisplayP3(c) => c.alpha,
+            CssColor::Hsl(c) => c.alpha,
+            CssColor::Hwb(c) => c.alpha,
+            CssColor::Lab(c) => c.alpha,
+            CssColor::Lch(c) => c.alpha,
+            CssColor::Oklab(c) => c.alpha,
+            CssColor::Oklch(c) => c.alpha,
+            CssColor::XyzD65(c) => c.alpha,
+            CssColor::XyzD50(c) => c.alpha,
+        }
+    }
+
+    /// Converts the color to sRGB, adapting colors relative to D50 with the Bradford transform as CSS does.
+    ///
+    /// Colors outside of the sRGB gamut are not clamped.
+    pub fn to_srgba(&self) -> Alpha<Srgb<T, D65>> {
+        match *self {
+            CssColor::Srgb(c) => c,
+            CssColor::LinearSrgb(c) => c.map_color(Srgb::from),
+            CssColor::DisplayP3(c) => c.map_color(Srgb::from),
+            CssColor::Hsl(c) => c.map_color(Srgb::from),
+            CssColor::Hwb(c) => c.map_color(Srgb::from),
+            CssColor::Lab(c) => c.map_color(Srgb::adapt_from),
+            CssColor::Lch(c) => c.map_color(Srgb::adapt_from),
+            CssColor::Oklab(c) => c.map_color(Srgb::convert_from),
+            CssColor::Oklch(c) => c.map_color(Srgb::convert_from),
+            CssColor::XyzD65(c) => c.map_color(Srgb::from),
+            CssColor::XyzD50(c) => c.map_color(Srgb::adapt_from),
+        }
+    }
+}
+
+impl<T: Channel<FloatChannel = T> + Float> CssColor<T> {
+    /// Serializes the color with the modern syntax and up to 4 decimals.
+    #[inline]
+    pub fn to_css_string(&self) -> String {
+        self.to_css_string_with(&CssFormat::default())
+    }
+
+    /// Serializes the color with the given options.
+    ///
+    /// sRGB colors are written as `rgb()` with channels from 0 to 255, and colors of other `color()` spaces
+    /// as `color()`. Lightness and the saturation, whiteness and blackness of HSL and HWB are percentages,
+    /// hues are in degrees, and alpha is omitted for opaque colors.
+    pub fn to_css_string_with(&self, format: &CssFormat) -> String {
+        match *self {
+            CssColor::Srgb(c) => c.to_css_string_with(format),
+            CssColor::LinearSrgb(c) => c.to_css_string_with(format),
+            CssColor::DisplayP3(c) => c.to_css_string_with(format),
+            CssColor::Hsl(c) => c.to_css_string_with(format),
+            CssColor::Hwb(c) => c.to_css_string_with(format),
+            CssColor::Lab(c) => c.to_css_string_with(format),
+            CssColor::Lch(c) => c.to_css_string_with(format),
+            CssColor::Oklab(c) => c.to_css_string_with(format),
+            CssColor::Oklch(c) => c.to_css_string_with(format),
+            CssColor::XyzD65(c) => c.to_css_string_with(format),
+            CssColor::XyzD50(c) => c.to_css_string_with(format),
+        }
+    }
+}
+
+/// Serializes the spaces CSS can express, with and without alpha, in the CSS function of the space and
+/// relative to the white point CSS defines it with. Values are scaled to their CSS units first.
+macro_rules! impl_css_string {
+    ($(
+        $name:ident<$wp:ident> => $function:expr, $space:expr, |$c:ident| [$(($v:expr, $scale:expr, $unit:expr)),*]
+    ),*) => {
+        $(
+            impl<C: Channel> $name<C, $wp> {
+                /// Serializes the color with the modern syntax and up to 4 decimals.
+                /// See [`CssColor::to_css_string_with`](../../css/enum.CssColor.html#method.to_css_string_with).
+                #[inline]
+                pub fn to_css_string(&self) -> String {
+                    self.to_css_string_with(&CssFormat::default())
+                }
+
+                /// Serializes the color with the given options.
+                pub fn to_css_string_with(&self, format: &CssFormat) -> String {
+                    let f = |v: FloatChannel<C>| v.to_f64().unwrap_or(0.0);
+                    let $c = self.into_float();
+
+                    let mut out = String::new();
+
+                    write_function(&mut out, $function, $space, [$((f($v) * $scale, $unit),)*], 1.0, format);
+
+                    out
+                }
+            }
+
+            impl<C: Channel> Alpha<$name<C, $wp>> {
+                /// Serializes the color with the modern syntax and up to 4 decimals, omitting alpha if opaque.
+                #[inline]
+                pub fn to_css_string(&self) -> String {
+                    self.to_css_string_with(&CssFormat::default())
+                }
+
+                /// Serializes the color with the given options, omitting alpha if opaque.
+                pub fn to_css_string_with(&self, format: &CssFormat) -> String {
+                    let f = |v: FloatChannel<C>| v.to_f64().unwrap_or(0.0);
+                    let $c = self.color().into_float();
+
+                    let mut out = String::new();
+
+                    write_function(&mut out, $function, $space, [$((f($v) * $scale, $unit),)*], f(self.alpha.into_float()), format);
+
+                    out
+                }
+            }
+        )*
+    }
+}
+
+impl_css_string! {
+    Srgb<D65> => "rgb", None, |c| [(c.r, 255.0, ""), (c.g, 255.0, ""), (c.b, 255.0, "")],
+    LinearSrgb<D65> => "color", Some("srgb-linear"), |c| [(c.r, 1.0, ""), (c.g, 1.0, ""), (c.b, 1.0, "")],
+    DisplayP3<D65> => "color", Some("display-p3"), |c| [(c.r, 1.0, ""), (c.g, 1.0, ""), (c.b, 1.0, "")],
+    Hsl<D65> => "hsl", None, |c| [(c.h.degrees(), 1.0, "deg"), (c.s, 100.0, "%"), (c.l, 100.0, "%")],
+    Hwb<D65> => "hwb", None, |c| [(c.h.degrees(), 1.0, "deg"), (c.w, 100.0, "%"), (c.b, 100.0, "%")],
+    Lab<D50> => "lab", None, |c| [(c.l, 1.0, "%"), (c.a, 1.0, ""), (c.b, 1.0, "")],
+    Lch<D50> => "lch", None, |c| [(c.l, 1.0, "%"), (c.c, 1.0, ""), (c.h.degrees(), 1.0, "deg")],
+    Oklab<D65> => "oklab", None, |c| [(c.l, 100.0, "%"), (c.a, 1.0, ""), (c.b, 1.0, "")],
+    Oklch<D65> => "oklch", None, |c| [(c.l, 100.0, "%"), (c.c, 1.0, ""), (c.h.degrees(), 1.0, "deg")],
+    Xyz<D65> => "color", Some("xyz-d65"), |c| [(c.x, 1.0, ""), (c.y, 1.0, ""), (c.z, 1.0, "")],
+    Xyz<D50> => "color", Some("xyz-d50"), |c| [(c.x, 1.0, ""), (c.y, 1.0, ""), (c.z, 1.0, "")]
+}
+
+impl<T: Channel<FloatChannel = T> + Float> Display for CssColor<T> {
+    /// Serializes the color with the modern syntax, and the precision of the formatter or else up to 4 decimals.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let format = CssFormat { precision: f.precision().unwrap_or(4), ..CssFormat::default() };
+
+        f.write_str(&self.to_css_string_with(&format))
+    }
+}
+
+impl<T: Channel<FloatChannel = T> + Float> FromStr for CssColor<T> {
+    type Err = ParseColorError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<CssColor<T>, ParseColorError> {
+        CssColor::parse(s)
+    }
+}
+
+/// The geometry of a CSS gradient
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CssGradientShape<T> {
+    /// `linear-gradient()`, with the direction of the gradient line in degrees clockwise from up
+    ///
+    /// Directions towards a corner, such as `to top right`, depend on the size of the box, and are given the angle
+    /// of the corner of a square box.
+    Linear(T),
+    /// `conic-gradient()`
+    Conic {
+        /// The angle where the gradient starts, in degrees clockwise from up
+        from: T,
+        /// The center, as fractions of the width and height of the box
+        at: [T; 2],
+    },
+}
+
+/// A `linear-gradient()` or `conic-gradient()` parsed from CSS
+///
+/// The color stops are converted to sRGB, without clamping, and interpolated with premultiplied alpha as CSS
+/// interpolates gradients between legacy sRGB colors. Positions are fractions of the gradient line for linear
+/// gradients and of a full turn for conic gradients. Positions in lengths rather than percentages depend on the
+/// size of the box and are not supported.
+///
+/// ```ignore
+/// let css: CssGradient = "linear-gradient(to right, red, 30%, blue 80%)".parse()?;
+/// let middle = css.gradient.sample(0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssGradient<T: Channel<FloatChannel = T> + Float = f32> {
+    /// The geometry of the gradient
+    pub shape: CssGradientShape<T>,
+    /// The color stops and hints of the gradient
+    pub gradient: Gradient<Alpha<Srgb<T, D65>>>,
+}
+
+/// Splits a list at the commas outside of parentheses, trimming each item.
+fn split_list(list: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+
+    for (i, c) in list.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    items.push(list[start..].trim());
+    items
+}
+
+/// Parses the direction of a linear gradient, either an angle or `to` followed by one or two sides.
+fn parse_linear_direction(input: &str, direction: &str) -> Result<f64, ParseColorError> {
+    let mut words = direction.split_whitespace();
+
+    match words.next() {
+        Some(to) if to.eq_ignore_ascii_case("to") => {}
+        _ => {
+            let angle = Argument::parse(input, direction)?;
+
+            return match angle.value {
+                Value::Angle(deg) => Ok(deg),
+                Value::Number(0.0) => Ok(0.0),
+                _ => Err(error(ParseErrorKind::InvalidValue, angle.offset, "an angle or `to`")),
+            };
+        }
+    }
+
+    let (mut x, mut y) = (None, None);
+    let mut count = 0;
+
+    for word in words {
+        let invalid = || error(ParseErrorKind::InvalidValue, offset_of(input, word), "`top`, `bottom`, `left` or `right`");
+
+        let (axis, side) = match word.to_ascii_lowercase().as_str() {
+            "top" => (&mut y, 1.0),
+            "bottom" => (&mut y, -1.0),
+            "left" => (&mut x, -1.0),
+            "right" => (&mut x, 1.0),
+            _ => return Err(invalid()),
+        };
+
+        if axis.is_some() {
+            return Err(invalid());
+        }
+
+        *axis = Some(side);
+        count += 1;
+    }
+
+    if count == 0 {
+        return Err(error(ParseErrorKind::InvalidSyntax, offset_of(input, direction) + direction.len(), "a side"));
+    }
+
+    let deg = f64::atan2(x.unwrap_or(0.0), y.unwrap_or(0.0)).to_degrees();
+
+    Ok(if deg < 0.0 { deg + 360.0 } else { deg })
+}
+
+/// Parses the `from <angle>` and `at <position>` of a conic gradient, returning the angle and center.
+fn parse_conic_geometry(input: &str, geometry: &str) -> Result<(f64, [f64; 2]), ParseColorError> {
+    let mut words = geometry.split_whitespace().peekable();
+    let end = offset_of(input, geometry) + geometry.len();
+
+    let mut from = 0.0;
+    let mut at = [0.5, 0.5];
+
+    if words.peek().is_some_and(|w| w.eq_ignore_ascii_case("from")) {
+        words.next();
+
+        let angle = Argument::parse(input, words.next().ok_or_else(|| error(ParseErrorKind::InvalidSyntax, end, "an angle"))?)?;
+
+        from = match angle.value {
+            Value::Angle(deg) => deg,
+            Value::Number(0.0) => 0.0,
+            _ => return Err(error(ParseErrorKind::InvalidValue, angle.offset, "an angle")),
+        };
+    }
+
+    if words.peek().is_some_and(|w| w.eq_ignore_ascii_case("at")) {
+        words.next();
+
+        // Each value of the position, with whether it is a keyword that only applies to the vertical axis
+        let mut values = Vec::new();
+
+        while let Some(word) = words.next_if(|_| values.len() < 2) {
+            let value = match word.to_ascii_lowercase().as_str() {
+                "left" => (0.0, false),
+                "right" => (1.0, false),
+                "top" => (0.0, true),
+                "bottom" => (1.0, true),
+                "center" => (0.5, false),
+                _ => {
+                    let value = Argument::parse(input, word)?;
+
+                    match value.value {
+                        Value::Percentage(p) => (p / 100.0, false),
+                        Value::Number(0.0) => (0.0, false),
+                        _ => return Err(error(ParseErrorKind::InvalidValue, value.offset, "a percentage or side")),
+                    }
+                }
+            };
+
+            values.push(value);
+        }
+
+        at = match values[..] {
+            [] => return Err(error(ParseErrorKind::InvalidSyntax, end, "a position")),
+            [(y, true)] => [0.5, y],
+            [(x, _)] => [x, 0.5],
+            [(y, true), (x, _)] => [x, y],
+            [(x, _), (y, _)] => [x, y],
+            _ => unreachable!(),
+        };
+    }
+
+    match words.next() {
+        Some(word) => Err(error(ParseErrorKind::InvalidSyntax, offset_of(input, word), "`,`")),
+        None => Ok((from, at)),
+    }
+}
+
+/// A color stop or hint of a gradient, before missing positions are filled in
+enum StopItem<T: Channel> {
+    Stop(Alpha<Srgb<T, D65>>, Option<f64>),
+    Hint(f64, usize),
+}
+
+/// Parses a position of a stop or hint as a fraction of the gradient.
+fn parse_stop_position(input: &str, token: &str, conic: bool) -> Result<f64, ParseColorError> {
+    let expected = if conic { "a percentage or angle" } else { "a percentage" };
+
+    let position = Argument::parse(input, token).map_err(|err| err.expected(expected))?;
+
+    match position.value {
+        Value::Percentage(p) => Ok(p / 100.0),
+        Value::Number(0.0) => Ok(0.0),
+        Value::Angle(deg) if conic => Ok(deg / 360.0),
+        _ => Err(error(ParseErrorKind::InvalidValue, position.offset, expected)),
+    }
+}
+
+impl<T: Channel<FloatChannel = T> + Float> CssGradient<T> {
+    /// Parses a `linear-gradient()` or `conic-gradient()`, ignoring surrounding whitespace.
+    pub fn parse(input: &str) -> Result<CssGradient<T>, ParseColorError> {
+        let s = input.trim();
+        let start = offset_of(input, s);
+
+        if s.is_empty() {
+            return Err(error(ParseErrorKind::Empty, input.len(), "a gradient"));
+        }
+
+        let open = s.find('(').ok_or_else(|| error(ParseErrorKind::InvalidSyntax, start + s.len(), "`(`"))?;
+
+        if !s.ends_with(')') {
+            return Err(error(ParseErrorKind::InvalidSyntax, start + s.len(), "`)`"));
+        }
+
+        let name = s[..open].trim_end().to_ascii_lowercase();
+        let args = &s[open + 1..s.len() - 1];
+
+        let conic = match name.as_str() {
+            "linear-gradient" => false,
+            "conic-gradient" => true,
+            _ => return Err(error(ParseErrorKind::UnknownFunction, start, "`linear-gradient` or `conic-gradient`")),
+        };
+
+        let mut items = split_list(args);
+
+        // The geometry is the first item, unless it is omitted and the first item is a color stop
+        let first = items[0].split_whitespace().next().unwrap_or("").to_ascii_lowercase();
+
+        let is_geometry = if conic {
+            first == "from" || first == "at"
+        } else {
+            first == "to" || Argument::parse(input, items[0]).is_ok()
+        };
+
+        let geometry = if is_geometry { Some(items.remove(0)) } else { None };
+
+        let f = |v: f64| -> T { math::cast(v) };
+
+        let shape = match (geometry, conic) {
+            (Some(geometry), false) => CssGradientShape::Linear(f(parse_linear_direction(input, geometry)?)),
+            (None, false) => CssGradientShape::Linear(f(180.0)),
+            (geometry, true) => {
+                let (from, [x, y]) = match geometry {
+                    Some(geometry) => parse_conic_geometry(input, geometry)?,
+                    None => (0.0, [0.5, 0.5]),
+                };
+
+                CssGradientShape::Conic { from: f(from), at: [f(x), f(y)] }
+            }
+        };
+
+        let mut stops: Vec<StopItem<T>> = Vec::new();
+
+        for item in items {
+            let offset = offset_of(input, item);
+
+            if item.is_empty() {
+                return Err(error(ParseErrorKind::InvalidSyntax, offset, "a color stop"));
+            }
+
+            // A hint is a lone position, which no color can be mistaken for
+            if !item.contains(char::is_whitespace) && Argument::parse(input, item).is_ok() {
+                match stops.last() {
+                    Some(&StopItem::Stop(..)) => stops.push(StopItem::Hint(parse_stop_position(input, item, conic)?, offset)),
+                    _ => return Err(error(ParseErrorKind::InvalidSyntax, offset, "a color")),
+                }
+
+                continue;
+            }
+
+            // The color ends at the closing parenthesis of a function, or at whitespace
+            let color_end = match item.find('(') {
+                Some(_) => item.find(')').map_or(item.len(), |i| i + 1),
+                None => item.find(char::is_whitespace).unwrap_or(item.len()),
+            };
+
+            let color = CssColor::<T>::parse(&item[..color_end]).map_err(|err| err.shift(offset))?.to_srgba();
+
+            let mut positions = item[color_end..].split_whitespace();
+
+            match (positions.next(), positions.next(), positions.next()) {
+                (None, ..) => stops.push(StopItem::Stop(color, None)),
+                (Some(a), None, _) => stops.push(StopItem::Stop(color, Some(parse_stop_position(input, a, conic)?))),
+                (Some(a), Some(b), None) => {
+                    stops.push(StopItem::Stop(color, Some(parse_stop_position(input, a, conic)?)));
+                    stops.push(StopItem::Stop(color, Some(parse_stop_position(input, b, conic)?)));
+                }
+                (.., Some(c)) => return Err(error(ParseErrorKind::InvalidSyntax, offset_of(input, c), "`,`")),
+            }
+        }
+
+        if let Some(&StopItem::Hint(_, offset)) = stops.last() {
+            return Err(error(ParseErrorKind::InvalidSyntax, offset, "a color stop"));
+        }
+
+        let count = stops.iter().filter(|item| matches!(item, StopItem::Stop(..))).count();
+
+        if count < 2 {
+            return Err(error(ParseErrorKind::ArgumentCount, start + s.len() - 1, "at least two color stops"));
+        }
+
+        Ok(CssGradient { shape, gradient: Gradient::new(fix_up_stops(stops)) })
+    }
+}
+
+/// Fills in missing positions of color stops as CSS does, and attaches hints to the stops before them.
+fn fix_up_stops<T: Channel<FloatChannel = T> + Float>(items: Vec<StopItem<T>>) -> Vec<GradientStop<Alpha<Srgb<T, D65>>, T>> {
+    let mut colors = Vec::new();
+    let mut positions = Vec::new();
+    let mut hints = Vec::new();
+
+    for item in items {
+        match item {
+            StopItem::Stop(color, position) => {
+                colors.push(color);
+                positions.push(position);
+                hints.push(None);
+            }
+            StopItem::Hint(hint, _) => {
+                if let Some(last) = hints.last_mut() {
+                    *last = Some(hint);
+                }
+            }
+        }
+    }
+
+    let last = positions.len() - 1;
+
+    // The first and last stops default to the ends of the gradient
+    positions[0] = Some(positions[0].unwrap_or(0.0));
+    positions[last] = Some(positions[last].unwrap_or(1.0));
+
+    // Positions before a higher position are raised to it
+    let mut max = f64::NEG_INFINITY;
+
+    for position in positions.iter_mut().flatten() {
+        *position = position.max(max);
+        max = *position;
+    }
+
+    // Runs of stops without positions are spread evenly between the stops around them
+    let mut before = 0;
+
+    for i in 1..positions.len() {
+        if let Some(end) = positions[i] {
+            let begin = positions[before].unwrap_or(0.0);
+
+            for (k, position) in positions[before + 1..i].iter_mut().enumerate() {
+                *position = Some(begin + (end - begin) * (k + 1) as f64 / (i - before) as f64);
+            }
+
+            before = i;
+        }
+    }
+
+    colors.into_iter().zip(positions).zip(hints)
+        .map(|((color, position), hint)| GradientStop {
+            color,
+            position: math::cast(position.unwrap_or(0.0)),
+            hint: hint.map(math::cast),
+        })
+        .collect()
+}
+
+impl<T: Channel<FloatChannel = T> + Float> CssGradient<T> {
+    /// Serializes the gradient with the modern syntax and up to 4 decimals.
+    #[inline]
+    pub fn to_css_string(&self) -> String {
+        self.to_css_string_with(&CssFormat::default())
+    }
+
+    /// Serializes the gradient with the given options for its values and colors.
+    ///
+    /// Positions are written as percentages, and the geometry is omitted where it is the default.
+    pub fn to_css_string_with(&self, format: &CssFormat) -> String {
+        let f = |v: T| v.to_f64().unwrap_or(0.0);
+
+        let mut out = String::new();
+
+        match self.shape {
+            CssGradientShape::Linear(angle) => {
+                out.push_str("linear-gradient(");
+
+                if f(angle) != 180.0 {
+                    write_number(&mut out, f(angle), format.precision, "deg, ");
+                }
+            }
+            CssGradientShape::Conic { from, at } => {
+                out.push_str("conic-gradient(");
+
+                let (from, at) = (f(from), [f(at[0]), f(at[1])]);
+
+                if from != 0.0 {
+                    out.push_str("from ");
+                    write_number(&mut out, from, format.precision, "deg");
+                }
+
+                if at != [0.5, 0.5] {
+                    out.push_str(if from != 0.0 { " at " } else { "at " });
+                    write_number(&mut out, at[0] * 100.0, format.precision, "% ");
+                    write_number(&mut out, at[1] * 100.0, format.precision, "%");
+                }
+
+                if from != 0.0 || at != [0.5, 0.5] {
+                    out.push_str(", ");
+                }
+            }
+        }
+
+        for (i, stop) in self.gradient.stops().iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+
+            out.push_str(&CssColor::Srgb(stop.color).to_css_string_with(format));
+            out.push(' ');
+            write_number(&mut out, f(stop.position) * 100.0, format.precision, "%");
+
+            if let Some(hint) = stop.hint {
+                out.push_str(", ");
+                write_number(&mut out, f(hint) * 100.0, format.precision, "%");
+            }
+        }
+
+        out.push(')');
+        out
+    }
+}
+
+impl<T: Channel<FloatChannel = T> + Float> Display for CssGradient<T> {
+    /// Serializes the gradient with the modern syntax, and the precision of the formatter or else up to 4 decimals.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let format = CssFormat { precision: f.precision().unwrap_or(4), ..CssFormat::default() };
+
+        f.write_str(&self.to_css_string_with(&format))
+    }
+}
+
+impl<T: Channel<FloatChannel = T> + Float> FromStr for CssGradient<T> {
+    type Err = ParseColorError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<CssGradient<T>, ParseColorError> {
+        CssGradient::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn space_css_strings() {
+        assert_eq!(Srgb::<u8>::new(255, 128, 0).to_css_string(), "rgb(255 128 0)");
+        assert_eq!(Srgb::<u8>::new(255, 128, 0).to_css_string_with(&CssFormat::legacy()), "rgb(255, 128, 0)");
+        assert_eq!(DisplayP3::<f32>::new(1.0, 0.0, 0.0).to_css_string(), "color(display-p3 1 0 0)");
+        assert_eq!(Oklch::<f64>::new(0.62, 0.2, 30.0).to_css_string(), "oklch(62% 0.2 30deg)");
+        assert_eq!(Alpha::<Oklch<f64>>::new(0.62, 0.2, 30.0, 0.5).to_css_string(), "oklch(62% 0.2 30deg / 0.5)");
+
+        let css: CssColor<f64> = "oklch(62% 0.2 30deg / 0.5)".parse().unwrap();
+
+        assert_eq!(css.to_css_string(), "oklch(62% 0.2 30deg / 0.5)");
+    }
+}
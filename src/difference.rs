@@ -0,0 +1,597 @@
+//! Color difference formulas.
+//!
+//! The classic ΔE formulas are defined on CIE L\*a\*b\*. Each is a function of two `Lab` colors, and the
+//! [`DeltaE`](trait.DeltaE.html) trait makes them available to every other space by converting to `Lab`
+//! with the same white point first. L\*a\*b\* is relative to diffuse white, so for high dynamic range content
+//! use ΔE<sub>ITP</sub> instead, which is computed from absolute luminance.
+//!
+//! [`ColorDistance`](trait.ColorDistance.html) abstracts over these metrics, so that algorithms can be
+//! parameterized by the metric they use.
+//!
+//! A difference of about 1.0 is the smallest that is noticeable side by side, although ΔE\*76 overstates
+//! differences between saturated colors.
+
+use num_traits::{Zero, One, Float};
+
+use channels::*;
+use color::{Color, ColorChannel, ColorChannels, ColorWhitePoint};
+use convert::ConvertFrom;
+use white_point::WhitePoint;
+use gamma::pq_inverse_eotf;
+use math::{self, Matrix3, Vector3};
+
+use spaces::lab::Lab;
+use spaces::xyz::Xyz;
+use spaces::primaries::{Primaries, Rec2020};
+use spaces::jzazbz::REFERENCE_WHITE_LUMINANCE;
+
+/// The CIE 1976 color difference, the Euclidean distance between two colors in L\*a\*b\*
+pub fn delta_e_76<C: Channel, Wp>(a: &Lab<C, Wp>, b: &Lab<C, Wp>) -> FloatChannel<C>
+where
+    Wp: WhitePoint<C>,
+{
+    let (a, b) = (a.into_float(), b.into_float());
+
+    let dl = a.l - b.l;
+    let da = a.a - b.a;
+    let db = a.b - b.b;
+
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// The weights of the CIE94 color difference
+///
+/// The chroma and hue weights kC and kH are 1.0 in both published parameter sets, and are omitted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cie94<T> {
+    /// The lightness weight kL
+    pub kl: T,
+    /// The chroma scaling factor K1
+    pub k1: T,
+    /// The hue scaling factor K2
+    pub k2: T,
+}
+
+impl<T: Float> Cie94<T> {
+    /// The parameters for graphic arts, with kL = 1, K1 = 0.045 and K2 = 0.015
+    pub fn graphic_arts() -> Cie94<T> {
+        Cie94 { kl: T::one(), k1: math::cast(0.045), k2: math::cast(0.015) }
+    }
+
+    /// The parameters for textiles, with kL = 2, K1 = 0.048 and K2 = 0.014
+    pub fn textiles() -> Cie94<T> {
+        Cie94 { kl: math::cast(2.0), k1: math::cast(0.048), k2: math::cast(0.014) }
+    }
+}
+
+impl<T: Float> Default for Cie94<T> {
+    /// The parameters for graphic arts
+    #[inline]
+    fn default() -> Cie94<T> {
+        Cie94::graphic_arts()
+    }
+}
+
+/// The CIE 1994 color difference of `sample` from `reference`
+///
+/// Chroma and hue differences are weighted by the chroma of `reference`, so the difference is not symmetric.
+pub fn delta_e_94<C: Channel, Wp>(reference: &Lab<C, Wp>, sample: &Lab<C, Wp>, params: &Cie94<FloatChannel<C>>) -> FloatChannel<C>
+where
+    Wp: WhitePoint<C>,
+{
+    let (reference, sample) = (reference.into_float(), sample.into_float());
+
+    let (l1, a1, b1) = (reference.l, reference.a, reference.b);
+    let (l2, a2, b2) = (sample.l, sample.a, sample.b);
+
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+
+    let dl = l1 - l2;
+    let dc = c1 - c2;
+    let (da, db) = (a1 - a2, b1 - b2);
+
+    // Rounding can make the squared hue difference slightly negative for colors of equal hue
+    let dh2 = (da * da + db * db - dc * dc).max(FloatChannel::<C>::zero());
+
+    let one = FloatChannel::<C>::one();
+
+    let sc = one + params.k1 * c1;
+    let sh = one + params.k2 * c1;
+
+    let l = dl / params.kl;
+    let c = dc / sc;
+
+    (l * l + c * c + dh2 / (sh * sh)).sqrt()
+}
+
+/// The CIEDE2000 color difference, with the parametric weights kL, kC and kH all 1.0
+///
+/// This follows the implementation notes of Sharma, Wu and Dalal, including their handling of achromatic
+/// colors, whose hue is taken as zero, and of mean hues across the 0°/360° boundary. The result is symmetric.
+pub fn delta_e_2000<C: Channel, Wp>(a: &Lab<C, Wp>, b: &Lab<C, Wp>) -> FloatChannel<C>
+where
+    Wp: WhitePoint<C>,
+{
+    let c = |v: f64| -> FloatChannel<C> { math::cast(v) };
+
+    let (zero, one, two) = (c(0.0), c(1.0), c(2.0));
+    let (d180, d360) = (c(180.0), c(360.0));
+    let pow25_7 = c(6103515625.0);
+
+    let (a, b) = (a.into_float(), b.into_float());
+
+    let (l1, a1, b1) = (a.l, a.a, a.b);
+    let (l2, a2, b2) = (b.l, b.a, b.b);
+
+    // Stretch the a* axis near neutral to correct the hue of grays
+    let c_mean = (a1.hypot(b1) + a2.hypot(b2)) / two;
+    let c_mean7 = c_mean.powi(7);
+    let g = c(0.5) * (one - (c_mean7 / (c_mean7 + pow25_7)).sqrt());
+
+    let a1 = a1 * (one + g);
+    let a2 = a2 * (one + g);
+
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+
+    let hue = |b: FloatChannel<C>, a: FloatChannel<C>| {
+        if b == zero && a == zero {
+            zero
+        } else {
+            let h = b.atan2(a).to_degrees();
+
+            if h < zero { h + d360 } else { h }
+        }
+    };
+
+    let h1 = hue(b1, a1);
+    let h2 = hue(b2, a2);
+
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+
+    let c_product = c1 * c2;
+
+    let dh = if c_product == zero {
+        zero
+    } else {
+        let dh = h2 - h1;
+
+        if dh > d180 {
+            dh - d360
+        } else if dh < -d180 {
+            dh + d360
+        } else {
+            dh
+        }
+    };
+
+    let dh = two * c_product.sqrt() * (dh / two).to_radians().sin();
+
+    let l_mean = (l1 + l2) / two;
+    let c_mean = (c1 + c2) / two;
+
+    let h_mean = if c_product == zero {
+        h1 + h2
+    } else if (h1 - h2).abs() <= d180 {
+        (h1 + h2) / two
+    } else if h1 + h2 < d360 {
+        (h1 + h2 + d360) / two
+    } else {
+        (h1 + h2 - d360) / two
+    };
+
+    let cos = |deg: FloatChannel<C>| deg.to_radians().cos();
+
+    let t = one - c(0.17) * cos(h_mean - c(30.0))
+        + c(0.24) * cos(two * h_mean)
+        + c(0.32) * cos(c(3.0) * h_mean + c(6.0))
+        - c(0.20) * cos(c(4.0) * h_mean - c(63.0));
+
+    let d_theta = c(30.0) * (-((h_mean - c(275.0)) / c(25.0)).powi(2)).exp();
+
+    let c_mean7 = c_mean.powi(7);
+    let rc = two * (c_mean7 / (c_mean7 + pow25_7)).sqrt();
+
+    let l50 = (l_mean - c(50.0)).powi(2);
+
+    let sl = one + c(0.015) * l50 / (c(20.0) + l50).sqrt();
+    let sc = one + c(0.045) * c_mean;
+    let sh = one + c(0.015) * c_mean * t;
+
+    // Rotation term, correcting the orientation of ellipses in the blue region
+    let rt = -(two * d_theta).to_radians().sin() * rc;
+
+    let (l, c, h) = (dl / sl, dc / sc, dh / sh);
+
+    (l * l + c * c + h * h + rt * c * h).sqrt()
+}
+
+/// The weights of the CMC l:c color difference
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cmc<T> {
+    /// The lightness weight l
+    pub l: T,
+    /// The chroma weight c
+    pub c: T,
+}
+
+impl<T: Float> Cmc<T> {
+    /// CMC 2:1, for the acceptability of differences
+    pub fn acceptability() -> Cmc<T> {
+        Cmc { l: math::cast(2.0), c: T::one() }
+    }
+
+    /// CMC 1:1, for the perceptibility of differences
+    pub fn perceptibility() -> Cmc<T> {
+        Cmc { l: T::one(), c: T::one() }
+    }
+}
+
+impl<T: Float> Default for Cmc<T> {
+    /// CMC 2:1
+    #[inline]
+    fn default() -> Cmc<T> {
+        Cmc::acceptability()
+    }
+}
+
+/// The CMC l:c color difference of `sample` from `reference`, as used by the textile industry
+///
+/// The tolerance ellipsoid is centered on `reference`, so the difference is not symmetric.
+pub fn delta_e_cmc<C: Channel, Wp>(reference: &Lab<C, Wp>, sample: &Lab<C, Wp>, weights: &Cmc<FloatChannel<C>>) -> FloatChannel<C>
+where
+    Wp: WhitePoint<C>,
+{
+    let c = |v: f64| -> FloatChannel<C> { math::cast(v) };
+
+    let (zero, one) = (c(0.0), c(1.0));
+
+    let (reference, sample) = (reference.into_float(), sample.into_float());
+
+    let (l1, a1, b1) = (reference.l, reference.a, reference.b);
+    let (l2, a2, b2) = (sample.l, sample.a, sample.b);
+
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+
+    let dl = l1 - l2;
+    let dc = c1 - c2;
+    let (da, db) = (a1 - a2, b1 - b2);
+
+    let dh2 = (da * da + db * db - dc * dc).max(zero);
+
+    let h1 = b1.atan2(a1).to_degrees();
+    let h1 = if h1 < zero { h1 + c(360.0) } else { h1 };
+
+    let sl = if l1 < c(16.0) { c(0.511) } else { c(0.040975) * l1 / (one + c(0.01765) * l1) };
+    let sc = c(0.0638) * c1 / (one + c(0.0131) * c1) + c(0.638);
+
+    let c1_4 = c1.powi(4);
+    let f = (c1_4 / (c1_4 + c(1900.0))).sqrt();
+
+    let t = if h1 >= c(164.0) && h1 <= c(345.0) {
+        c(0.56) + (c(0.2) * (h1 + c(168.0)).to_radians().cos()).abs()
+    } else {
+        c(0.36) + (c(0.4) * (h1 + c(35.0)).to_radians().cos()).abs()
+    };
+
+    let sh = sc * (f * t + one - f);
+
+    let l = dl / (weights.l * sl);
+    let c = dc / (weights.c * sc);
+
+    (l * l + c * c + dh2 / (sh * sh)).sqrt()
+}
+
+/// Linear BT.2020 RGB to LMS, from ITU-R BT.2100
+const RGB_TO_LMS: Matrix3<f64> = [
+    [1688.0 / 4096.0, 2146.0 / 4096.0, 262.0 / 4096.0],
+    [683.0 / 4096.0, 2951.0 / 4096.0, 462.0 / 4096.0],
+    [99.0 / 4096.0, 309.0 / 4096.0, 3688.0 / 4096.0],
+];
+
+/// PQ-encoded LMS to ICtCp, from ITU-R BT.2100
+const LMS_TO_ICTCP: Matrix3<f64> = [
+    [2048.0 / 4096.0, 2048.0 / 4096.0, 0.0],
+    [6610.0 / 4096.0, -13613.0 / 4096.0, 7003.0 / 4096.0],
+    [17933.0 / 4096.0, -17390.0 / 4096.0, -543.0 / 4096.0],
+];
+
+/// Converts relative XYZ to the ICtCp of BT.2100, where the white point has the given luminance in cd/m².
+fn ictcp<C: Channel, Wp>(xyz: &Xyz<C, Wp>, white_luminance: FloatChannel<C>) -> Vector3<FloatChannel<C>>
+where
+    Wp: WhitePoint<C>,
+{
+    let xyz = xyz.into_float();
+
+    let rgb = math::mul_vec(&Rec2020::from_xyz_matrix_adapted::<C, Wp>(), [xyz.x, xyz.y, xyz.z]);
+    let [l, m, s] = math::mul_vec(&math::matrix(&RGB_TO_LMS), rgb);
+
+    let pq = |v: FloatChannel<C>| pq_inverse_eotf(v * white_luminance);
+
+    math::mul_vec(&math::matrix(&LMS_TO_ICTCP), [pq(l), pq(m), pq(s)])
+}
+
+/// The ΔE<sub>ITP</sub> color difference of ITU-R BT.2124, for high dynamic range and wide gamut content
+///
+/// The colors are converted to ICtCp at absolute luminance, where the white point has a luminance of
+/// `white_luminance` in cd/m², such as the 203 cd/m² of
+/// [`REFERENCE_WHITE_LUMINANCE`](../spaces/jzazbz/constant.REFERENCE_WHITE_LUMINANCE.html) for HDR reference white.
+/// A difference of 1.0 is about the smallest that is noticeable.
+pub fn delta_e_itp<C: Channel, Wp>(a: &Xyz<C, Wp>, b: &Xyz<C, Wp>, white_luminance: FloatChannel<C>) -> FloatChannel<C>
+where
+    Wp: WhitePoint<C>,
+{
+    let [i1, ct1, cp1] = ictcp(a, white_luminance);
+    let [i2, ct2, cp2] = ictcp(b, white_luminance);
+
+    // The T axis of ITP is half of Ct
+    let di = i1 - i2;
+    let dt = (ct1 - ct2) * math::cast(0.5);
+    let dp = cp1 - cp2;
+
+    (di * di + dt * dt + dp * dp).sqrt() * math::cast(720.0)
+}
+
+/// Color differences of colors in any space, computed in CIE L\*a\*b\*
+///
+/// The conversion to `Lab` uses the channel type of the colors, so colors with integer channels lose precision.
+pub trait DeltaE {
+    /// The floating point type of the difference
+    type Scalar: Float;
+
+    /// The CIE 1976 color difference. See [`delta_e_76`](fn.delta_e_76.html).
+    fn delta_e_76(&self, other: &Self) -> Self::Scalar;
+
+    /// The CIE 1994 color difference of `other` from `self` as the reference. See [`delta_e_94`](fn.delta_e_94.html).
+    fn delta_e_94(&self, other: &Self, params: &Cie94<Self::Scalar>) -> Self::Scalar;
+
+    /// The CIEDE2000 color difference. See [`delta_e_2000`](fn.delta_e_2000.html).
+    fn delta_e_2000(&self, other: &Self) -> Self::Scalar;
+
+    /// The CMC l:c color difference of `other` from `self` as the reference. See [`delta_e_cmc`](fn.delta_e_cmc.html).
+    fn delta_e_cmc(&self, other: &Self, weights: &Cmc<Self::Scalar>) -> Self::Scalar;
+
+    /// The ΔE<sub>ITP</sub> color difference, computed from CIE XYZ rather than L\*a\*b\*, where the white point
+    /// has a luminance of `white_luminance` in cd/m². See [`delta_e_itp`](fn.delta_e_itp.html).
+    fn delta_e_itp(&self, other: &Self, white_luminance: Self::Scalar) -> Self::Scalar;
+}
+
+impl<T> DeltaE for T
+where
+    T: Color + Copy,
+    Lab<ColorChannel<T>, ColorWhitePoint<T>>: ConvertFrom<T>,
+    Xyz<ColorChannel<T>, ColorWhitePoint<T>>: From<T>,
+{
+    type Scalar = FloatChannel<ColorChannel<T>>;
+
+    #[inline]
+    fn delta_e_76(&self, other: &Self) -> Self::Scalar {
+        delta_e_76(&Lab::convert_from(*self), &Lab::convert_from(*other))
+    }
+
+    #[inline]
+    fn delta_e_94(&self, other: &Self, params: &Cie94<Self::Scalar>) -> Self::Scalar {
+        delta_e_94(&Lab::convert_from(*self), &Lab::convert_from(*other), params)
+    }
+
+    #[inline]
+    fn delta_e_2000(&self, other: &Self) -> Self::Scalar {
+        delta_e_2000(&Lab::convert_from(*self), &Lab::convert_from(*other))
+    }
+
+    #[inline]
+    fn delta_e_cmc(&self, other: &Self, weights: &Cmc<Self::Scalar>) -> Self::Scalar {
+        delta_e_cmc(&Lab::convert_from(*self), &Lab::convert_from(*other), weights)
+    }
+
+    #[inline]
+    fn delta_e_itp(&self, other: &Self, white_luminance: Self::Scalar) -> Self::Scalar {
+        delta_e_itp(&Xyz::from(*self), &Xyz::from(*other), white_luminance)
+    }
+}
+
+/// A metric for the distance between two colors
+///
+/// Algorithms such as nearest color matching and clustering can be written once over a `ColorDistance` and
+/// used with any of the metrics of this module, or with a closure taking two colors.
+pub trait ColorDistance<C> {
+    /// The floating point type of the distance
+    type Scalar: Float;
+
+    /// The distance between two colors, which is zero for equal colors and never negative.
+    ///
+    /// Some metrics, such as CIE94 and CMC, are not symmetric and treat `a` as the reference.
+    fn distance(&self, a: &C, b: &C) -> Self::Scalar;
+
+    /// A lower bound of the distance between two colors whose channels at index `axis` differ by `difference`,
+    /// which lets searches such as those of [`Palette`](../palette/struct.Palette.html) skip distant colors.
+    ///
+    /// The default of zero is valid for every metric, but skips nothing.
+    #[inline]
+    fn axis_bound(&self, axis: usize, difference: Self::Scalar) -> Self::Scalar {
+        let _ = (axis, difference);
+
+        Self::Scalar::zero()
+    }
+}
+
+impl<C, T, F> ColorDistance<C> for F
+where
+    T: Float,
+    F: Fn(&C, &C) -> T,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn distance(&self, a: &C, b: &C) -> T {
+        self(a, b)
+    }
+}
+
+/// The Euclidean distance between the channels of two colors in their own space
+///
+/// This is only meaningful for spaces with rectangular coordinates, since hue angles do not wrap, and is
+/// only perceptually uniform in uniform spaces such as `Oklab` or `Lab`. It needs no conversion, so it is
+/// the fastest metric.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Euclidean;
+
+impl<C> ColorDistance<C> for Euclidean
+where
+    C: Color,
+    ColorChannels<C>: ChannelSlice<ColorChannel<C>>,
+{
+    type Scalar = FloatChannel<ColorChannel<C>>;
+
+    fn distance(&self, a: &C, b: &C) -> Self::Scalar {
+        let sum = a.channels().as_slice().iter().zip(b.channels().as_slice()).fold(Self::Scalar::zero(), |sum, (a, b)| {
+            let d = a.into_float() - b.into_float();
+
+            sum + d * d
+        });
+
+        sum.sqrt()
+    }
+
+    #[inline]
+    fn axis_bound(&self, _axis: usize, difference: Self::Scalar) -> Self::Scalar {
+        difference.abs()
+    }
+}
+
+/// The CIE 1976 color difference. See [`delta_e_76`](fn.delta_e_76.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Cie76;
+
+impl<C: DeltaE> ColorDistance<C> for Cie76 {
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn distance(&self, a: &C, b: &C) -> C::Scalar {
+        a.delta_e_76(b)
+    }
+}
+
+impl<C: DeltaE> ColorDistance<C> for Cie94<C::Scalar> {
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn distance(&self, a: &C, b: &C) -> C::Scalar {
+        a.delta_e_94(b, self)
+    }
+}
+
+/// The CIEDE2000 color difference. See [`delta_e_2000`](fn.delta_e_2000.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Ciede2000;
+
+impl<C: DeltaE> ColorDistance<C> for Ciede2000 {
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn distance(&self, a: &C, b: &C) -> C::Scalar {
+        a.delta_e_2000(b)
+    }
+}
+
+impl<C: DeltaE> ColorDistance<C> for Cmc<C::Scalar> {
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn distance(&self, a: &C, b: &C) -> C::Scalar {
+        a.delta_e_cmc(b, self)
+    }
+}
+
+/// The ΔE<sub>ITP</sub> color difference. See [`delta_e_itp`](fn.delta_e_itp.html).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Itp<T> {
+    /// The luminance of the white point in cd/m²
+    pub white_luminance: T,
+}
+
+impl<T> Itp<T> {
+    /// Creates the metric for colors whose white point has the given luminance in cd/m².
+    #[inline]
+    pub fn new(white_luminance: T) -> Itp<T> {
+        Itp { white_luminance }
+    }
+}
+
+impl<T: Float> Default for Itp<T> {
+    /// The HDR reference white of 203 cd/m²
+    #[inline]
+    fn default() -> Itp<T> {
+        Itp::new(math::cast(REFERENCE_WHITE_LUMINANCE))
+    }
+}
+
+impl<C: DeltaE> ColorDistance<C> for Itp<C::Scalar> {
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn distance(&self, a: &C, b: &C) -> C::Scalar {
+        a.delta_e_itp(b, self.white_luminance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The test data of Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula: Implementation Notes,
+    /// Supplementary Test Data, and Mathematical Observations", as (L\*a\*b\* 1, L\*a\*b\* 2, ΔE00)
+    const SHARMA: [([f64; 3], [f64; 3], f64); 34] = [
+        ([50.0000, 2.6772, -79.7751], [50.0000, 0.0000, -82.7485], 2.0425),
+        ([50.0000, 3.1571, -77.2803], [50.0000, 0.0000, -82.7485], 2.8615),
+        ([50.0000, 2.8361, -74.0200], [50.0000, 0.0000, -82.7485], 3.4412),
+        ([50.0000, -1.3802, -84.2814], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, -1.1848, -84.8006], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, -0.9009, -85.5211], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, 0.0000, 0.0000], [50.0000, -1.0000, 2.0000], 2.3669),
+        ([50.0000, -1.0000, 2.0000], [50.0000, 0.0000, 0.0000], 2.3669),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0009], 7.1792),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0010], 7.1792),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0011], 7.2195),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0012], 7.2195),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0009, -2.4900], 4.8045),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0010, -2.4900], 4.8045),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0011, -2.4900], 4.7461),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 0.0000, -2.5000], 4.3065),
+        ([50.0000, 2.5000, 0.0000], [73.0000, 25.0000, -18.0000], 27.1492),
+        ([50.0000, 2.5000, 0.0000], [61.0000, -5.0000, 29.0000], 22.8977),
+        ([50.0000, 2.5000, 0.0000], [56.0000, -27.0000, -3.0000], 31.9030),
+        ([50.0000, 2.5000, 0.0000], [58.0000, 24.0000, 15.0000], 19.4535),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.1736, 0.5854], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.2972, 0.0000], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 1.8634, 0.5757], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.2592, 0.3350], 1.0000),
+        ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+        ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+        ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+        ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+        ([22.7233, 20.0904, -46.6940], [23.0331, 14.9730, -42.5619], 2.0373),
+        ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+        ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+        ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+        ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+        ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082),
+    ];
+
+    #[test]
+    fn delta_e_2000_sharma() {
+        for (i, &(a, b, expected)) in SHARMA.iter().enumerate() {
+            let a = Lab::<f64>::new(a[0], a[1], a[2]);
+            let b = Lab::<f64>::new(b[0], b[1], b[2]);
+
+            let ab = delta_e_2000(&a, &b);
+            let ba = delta_e_2000(&b, &a);
+
+            assert!((ab - expected).abs() < 1e-4, "pair {}: {} != {}", i + 1, ab, expected);
+            assert!((ba - expected).abs() < 1e-4, "pair {} reversed: {} != {}", i + 1, ba, expected);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! The filter functions of CSS, such as `sepia()` and `hue-rotate()`.
+//!
+//! Each function of the CSS Filter Effects specification is a [`ColorMatrix`](../color_matrix/struct.ColorMatrix.html)
+//! applied to gamma-encoded sRGB with straight alpha. Browsers clamp channels between the functions of a
+//! filter list, so composing the matrices of a list only matches browsers while no channel leaves the
+//! range from 0.0 to 1.0. [`apply`](fn.apply.html) clamps after each function, and reproduces CSS exactly.
+//!
+//! Amounts are given as in CSS, where 1.0 is 100%, and are clamped to the ranges allowed by the specification.
+
+use num_traits::Float;
+
+use channels::*;
+use alpha::Alpha;
+use color::{Color, ColorChannel};
+use color_matrix::ColorMatrix;
+use math;
+
+/// A CSS filter function
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Filter<T> {
+    /// `brightness()`
+    Brightness(T),
+    /// `contrast()`
+    Contrast(T),
+    /// `grayscale()`
+    Grayscale(T),
+    /// `hue-rotate()`, in degrees
+    HueRotate(T),
+    /// `invert()`
+    Invert(T),
+    /// `opacity()`
+    Opacity(T),
+    /// `saturate()`
+    Saturate(T),
+    /// `sepia()`
+    Sepia(T),
+}
+
+impl<T: Float> Filter<T> {
+    /// The color matrix of the filter function
+    pub fn matrix(&self) -> ColorMatrix<T> {
+        match *self {
+            Filter::Brightness(amount) => brightness(amount),
+            Filter::Contrast(amount) => contrast(amount),
+            Filter::Grayscale(amount) => grayscale(amount),
+            Filter::HueRotate(degrees) => hue_rotate(degrees),
+            Filter::Invert(amount) => invert(amount),
+            Filter::Opacity(amount) => opacity(amount),
+            Filter::Saturate(amount) => saturate(amount),
+            Filter::Sepia(amount) => sepia(amount),
+        }
+    }
+}
+
+#[inline]
+fn unit<T: Float>(amount: T) -> T {
+    amount.max(T::zero()).min(T::one())
+}
+
+#[inline]
+fn non_negative<T: Float>(amount: T) -> T {
+    amount.max(T::zero())
+}
+
+/// Scales each color channel by `slope` and adds `intercept`, leaving alpha unchanged.
+fn linear<T: Float>(slope: T, intercept: T) -> ColorMatrix<T> {
+    let mut m = ColorMatrix::identity();
+
+    for (i, row) in m.m[..3].iter_mut().enumerate() {
+        row[i] = slope;
+        row[4] = intercept;
+    }
+
+    m
+}
+
+/// `brightness()`, which scales the color channels, where 0.0 is black and 1.0 is unchanged.
+pub fn brightness<T: Float>(amount: T) -> ColorMatrix<T> {
+    linear(non_negative(amount), T::zero())
+}
+
+/// `contrast()`, which scales the color channels around one half, where 0.0 is gray and 1.0 is unchanged.
+pub fn contrast<T: Float>(amount: T) -> ColorMatrix<T> {
+    let amount = non_negative(amount);
+    let half: T = math::cast(0.5);
+
+    linear(amount, half - half * amount)
+}
+
+/// `grayscale()`, where 1.0 is completely gray and 0.0 is unchanged.
+pub fn grayscale<T: Float>(amount: T) -> ColorMatrix<T> {
+    let s = T::one() - unit(amount);
+    let c = |a: f64, b: f64| math::cast::<T>(a) + math::cast::<T>(b) * s;
+
+    ColorMatrix::from_rgb(&[
+        [c(0.2126, 0.7874), c(0.7152, -0.7152), c(0.0722, -0.0722)],
+        [c(0.2126, -0.2126), c(0.7152, 0.2848), c(0.0722, -0.0722)],
+        [c(0.2126, -0.2126), c(0.7152, -0.7152), c(0.0722, 0.9278)],
+    ])
+}
+
+/// `hue-rotate()`, rotating hues by `degrees`.
+#[inline]
+pub fn hue_rotate<T: Float>(degrees: T) -> ColorMatrix<T> {
+    ColorMatrix::hue_rotate(degrees)
+}
+
+/// `invert()`, where 1.0 is completely inverted and 0.0 is unchanged.
+pub fn invert<T: Float>(amount: T) -> ColorMatrix<T> {
+    let amount = unit(amount);
+
+    linear(T::one() - amount - amount, amount)
+}
+
+/// `opacity()`, which scales alpha, where 0.0 is completely transparent and 1.0 is unchanged.
+pub fn opacity<T: Float>(amount: T) -> ColorMatrix<T> {
+    let mut m = ColorMatrix::identity();
+
+    m.m[3][3] = unit(amount);
+
+    m
+}
+
+/// `saturate()`, where 0.0 is completely unsaturated, 1.0 is unchanged, and larger values oversaturate.
+#[inline]
+pub fn saturate<T: Float>(amount: T) -> ColorMatrix<T> {
+    ColorMatrix::saturate(non_negative(amount))
+}
+
+/// `sepia()`, where 1.0 is completely sepia and 0.0 is unchanged.
+pub fn sepia<T: Float>(amount: T) -> ColorMatrix<T> {
+    let s = T::one() - unit(amount);
+    let c = |a: f64, b: f64| math::cast::<T>(a) + math::cast::<T>(b) * s;
+
+    ColorMatrix::from_rgb(&[
+        [c(0.393, 0.607), c(0.769, -0.769), c(0.189, -0.189)],
+        [c(0.349, -0.349), c(0.686, 0.314), c(0.168, -0.168)],
+        [c(0.272, -0.272), c(0.534, -0.534), c(0.131, 0.869)],
+    ])
+}
+
+/// Applies a list of filter functions in order to a color with straight alpha, clamping channels
+/// after each function as browsers do.
+///
+/// To match CSS, the color should be gamma-encoded sRGB.
+pub fn apply<C>(filters: &[Filter<FloatChannel<ColorChannel<C>>>], color: Alpha<C>) -> Alpha<C>
+where
+    C: Color<Channels = TripleChannel<ColorChannel<C>>>,
+{
+    let (mut color, alpha) = color.into_parts();
+
+    let alpha = {
+        let channels = color.channels_mut().as_mut_slice();
+
+        let mut rgba = [channels[0].into_float(), channels[1].into_float(), channels[2].into_float(), alpha.into_float()];
+
+        for filter in filters {
+            rgba = filter.matrix().apply_rgba(rgba);
+
+            for v in &mut rgba {
+                *v = unit(*v);
+            }
+        }
+
+        for (c, v) in channels.iter_mut().zip(&rgba) {
+            *c = Channel::from_float(*v);
+        }
+
+        rgba[3]
+    };
+
+    Alpha::from_color(color, Channel::from_float(alpha))
+}
+
+/// Applies a list of filter functions in order to an opaque color, clamping channels after each function
+/// as browsers do.
+///
+/// To match CSS, the color should be gamma-encoded sRGB.
+pub fn apply_opaque<C>(filters: &[Filter<FloatChannel<ColorChannel<C>>>], color: C) -> C
+where
+    C: Color<Channels = TripleChannel<ColorChannel<C>>>,
+{
+    apply(filters, Alpha::opaque(color)).into_parts().0
+}
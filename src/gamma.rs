@@ -0,0 +1,797 @@
+//! Transfer functions between linear light and gamma-encoded values.
+//!
+//! Most RGB images are stored gamma-encoded, with more precision given to dark values where the eye is
+//! most sensitive. Light only mixes linearly, so blending, scaling and filtering colors should be done
+//! on linear values, and encoded again afterwards.
+//!
+//! The sRGB transfer function is a short linear segment near black joined to a power curve,
+//! which approximates a gamma of 2.2 overall.
+
+use std::sync::OnceLock;
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::Float;
+use typenum::Unsigned;
+use typenum::consts::{U18, U22};
+
+use channels::Channel;
+use white_point::WhitePoint;
+use math;
+
+use spaces::srgb::Srgb;
+use spaces::linear_srgb::LinearSrgb;
+
+/// The sRGB opto-electronic transfer function, from linear light to encoded values.
+#[inline]
+pub fn srgb_encode<T: Float>(linear: T) -> T {
+    if linear <= math::cast(0.0031308) {
+        linear * math::cast(12.92)
+    } else {
+        linear.powf(math::cast(1.0 / 2.4)) * math::cast(1.055) - math::cast(0.055)
+    }
+}
+
+/// The inverse of the sRGB transfer function, from encoded values to linear light.
+#[inline]
+pub fn srgb_decode<T: Float>(encoded: T) -> T {
+    if encoded <= math::cast(0.04045) {
+        encoded / math::cast(12.92)
+    } else {
+        ((encoded + math::cast(0.055)) / math::cast(1.055)).powf(math::cast(2.4))
+    }
+}
+
+/// Encodes a linear channel with the sRGB transfer function.
+#[inline]
+pub fn encode<C: Channel>(linear: C) -> C {
+    C::from_float(srgb_encode(linear.into_float()))
+}
+
+/// Decodes an sRGB-encoded channel into linear light.
+#[inline]
+pub fn decode<C: Channel>(encoded: C) -> C {
+    C::from_float(srgb_decode(encoded.into_float()))
+}
+
+impl<C: Channel, Wp> Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Decodes the color into linear light.
+    #[inline]
+    pub fn linearize(self) -> LinearSrgb<C, Wp> {
+        self.into()
+    }
+}
+
+impl<C: Channel, Wp> LinearSrgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Encodes the color with the sRGB transfer function.
+    #[inline]
+    pub fn encode(self) -> Srgb<C, Wp> {
+        self.into()
+    }
+}
+
+/// The number of entries of the table used to encode linear `f32` values as sRGB `u8` channels
+const SRGB_ENCODE_TABLE_SIZE: usize = 4096;
+
+fn srgb_decode_table() -> &'static [f32; 256] {
+    static TABLE: OnceLock<[f32; 256]> = OnceLock::new();
+
+    TABLE.get_or_init(|| {
+        let mut table = [0.0; 256];
+
+        for (i, v) in table.iter_mut().enumerate() {
+            *v = srgb_decode(i as f64 / 255.0) as f32;
+        }
+
+        table
+    })
+}
+
+fn srgb_encode_table() -> &'static [u8; SRGB_ENCODE_TABLE_SIZE] {
+    static TABLE: OnceLock<[u8; SRGB_ENCODE_TABLE_SIZE]> = OnceLock::new();
+
+    TABLE.get_or_init(|| {
+        let mut table = [0; SRGB_ENCODE_TABLE_SIZE];
+        let last = (SRGB_ENCODE_TABLE_SIZE - 1) as f64;
+
+        for (i, v) in table.iter_mut().enumerate() {
+            *v = (srgb_encode(i as f64 / last) * 255.0).round() as u8;
+        }
+
+        table
+    })
+}
+
+/// Decodes an sRGB-encoded `u8` channel into linear light, using a precomputed table.
+#[inline]
+pub fn srgb_u8_to_linear(encoded: u8) -> f32 {
+    srgb_decode_table()[encoded as usize]
+}
+
+/// Encodes a linear channel as an sRGB `u8` channel, using a precomputed table of 4096 entries.
+///
+/// Values outside of 0.0 to 1.0 are clamped. The result may differ by one from
+/// [`srgb_encode`](fn.srgb_encode.html) near black, where the table is coarsest.
+#[inline]
+pub fn linear_to_srgb_u8(linear: f32) -> u8 {
+    let last = (SRGB_ENCODE_TABLE_SIZE - 1) as f32;
+
+    // NaN is mapped to zero by the saturating cast
+    let i = (linear * last + 0.5).max(0.0).min(last) as usize;
+
+    srgb_encode_table()[i]
+}
+
+/// Decodes a buffer of sRGB-encoded `u8` channels into linear light, using a precomputed table.
+///
+/// # Panics
+///
+/// Panics if the buffers have different lengths.
+pub fn decode_srgb_u8_slice(encoded: &[u8], linear: &mut [f32]) {
+    assert_eq!(encoded.len(), linear.len(), "buffers must have the same length");
+
+    let table = srgb_decode_table();
+
+    for (l, &e) in linear.iter_mut().zip(encoded) {
+        *l = table[e as usize];
+    }
+}
+
+/// Encodes a buffer of linear channels as sRGB `u8` channels, using a precomputed table.
+///
+/// # Panics
+///
+/// Panics if the buffers have different lengths.
+pub fn encode_srgb_u8_slice(linear: &[f32], encoded: &mut [u8]) {
+    assert_eq!(encoded.len(), linear.len(), "buffers must have the same length");
+
+    for (e, &l) in encoded.iter_mut().zip(linear) {
+        *e = linear_to_srgb_u8(l);
+    }
+}
+
+impl<Wp> Srgb<u8, Wp>
+where
+    Wp: WhitePoint<u8> + WhitePoint<f32>,
+{
+    /// Decodes the color into linear light with `f32` channels, using a precomputed table.
+    #[inline]
+    pub fn linearize_f32(self) -> LinearSrgb<f32, Wp> {
+        LinearSrgb::with_wp(srgb_u8_to_linear(self.r), srgb_u8_to_linear(self.g), srgb_u8_to_linear(self.b))
+    }
+}
+
+impl<Wp> LinearSrgb<f32, Wp>
+where
+    Wp: WhitePoint<f32> + WhitePoint<u8>,
+{
+    /// Encodes the color as sRGB with `u8` channels, using a precomputed table.
+    #[inline]
+    pub fn encode_u8(self) -> Srgb<u8, Wp> {
+        Srgb::with_wp(linear_to_srgb_u8(self.r), linear_to_srgb_u8(self.g), linear_to_srgb_u8(self.b))
+    }
+}
+
+/// A transfer function between linear light and encoded values
+///
+/// Transfer functions are used as a type parameter of [`Rgb`](../spaces/generic_rgb/struct.Rgb.html)
+/// to select the encoding of its channels, and can be implemented for custom curves.
+/// Parameterized curves are created with `Default` when used as a type parameter.
+pub trait TransferFunction {
+    /// Encodes linear light into encoded values.
+    fn encode<T: Float>(&self, linear: T) -> T;
+
+    /// Decodes encoded values into linear light.
+    fn decode<T: Float>(&self, encoded: T) -> T;
+}
+
+/// The encoding of the channels of an RGB color, a transfer function usable as a type parameter
+///
+/// Only colors tagged with the [`Linear`](struct.Linear.html) encoding support arithmetic,
+/// blending and matrix transforms, since those are only meaningful on linear light.
+pub trait Encoding: TransferFunction + Default {}
+
+impl<E: TransferFunction + Default> Encoding for E {}
+
+/// The sRGB transfer function, see [`srgb_encode`](fn.srgb_encode.html)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SrgbTransfer;
+
+/// A pure power curve with the exponent `N` in tenths, such as `Gamma<U22>` for a gamma of 2.2
+///
+/// For an exponent known only at runtime, see [`PowerGamma`](struct.PowerGamma.html).
+pub struct Gamma<N>(PhantomData<N>);
+
+/// A pure power curve with an exponent of 2.2, as used by Adobe RGB and many displays
+pub type Gamma22 = Gamma<U22>;
+
+/// A pure power curve with an exponent of 1.8, as used by classic Mac OS displays
+pub type Gamma18 = Gamma<U18>;
+
+/// The identity transfer function, for channels that store linear light
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Linear;
+
+impl TransferFunction for SrgbTransfer {
+    #[inline]
+    fn encode<T: Float>(&self, linear: T) -> T {
+        srgb_encode(linear)
+    }
+
+    #[inline]
+    fn decode<T: Float>(&self, encoded: T) -> T {
+        srgb_decode(encoded)
+    }
+}
+
+impl<N: Unsigned> Gamma<N> {
+    /// The decoding exponent of the curve.
+    #[inline]
+    pub fn exponent() -> f64 {
+        N::to_u32() as f64 / 10.0
+    }
+}
+
+impl<N> Debug for Gamma<N>
+where
+    N: Unsigned,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Gamma({})", Self::exponent())
+    }
+}
+
+impl<N> Clone for Gamma<N> {
+    fn clone(&self) -> Gamma<N> {
+        *self
+    }
+}
+
+impl<N> Copy for Gamma<N> {}
+
+impl<N> Default for Gamma<N> {
+    fn default() -> Gamma<N> {
+        Gamma(PhantomData)
+    }
+}
+
+impl<N> PartialEq for Gamma<N> {
+    fn eq(&self, _: &Gamma<N>) -> bool {
+        true
+    }
+}
+
+impl<N> Eq for Gamma<N> {}
+
+impl<N: Unsigned> TransferFunction for Gamma<N> {
+    #[inline]
+    fn encode<T: Float>(&self, linear: T) -> T {
+        linear.max(T::zero()).powf(math::cast(1.0 / Self::exponent()))
+    }
+
+    #[inline]
+    fn decode<T: Float>(&self, encoded: T) -> T {
+        encoded.max(T::zero()).powf(math::cast(Self::exponent()))
+    }
+}
+
+impl TransferFunction for Linear {
+    #[inline(always)]
+    fn encode<T: Float>(&self, linear: T) -> T {
+        linear
+    }
+
+    #[inline(always)]
+    fn decode<T: Float>(&self, encoded: T) -> T {
+        encoded
+    }
+}
+
+/// The peak luminance of the PQ transfer function, in cd/m²
+pub const PQ_PEAK_LUMINANCE: f64 = 10000.0;
+
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// The PQ (SMPTE ST 2084) electro-optical transfer function, from encoded values to absolute luminance in cd/m².
+pub fn pq_eotf<T: Float>(encoded: T) -> T {
+    let e = encoded.max(T::zero()).powf(math::cast(1.0 / PQ_M2));
+
+    let y = ((e - math::cast(PQ_C1)).max(T::zero()) / (math::cast::<T>(PQ_C2) - e * math::cast(PQ_C3)))
+        .powf(math::cast(1.0 / PQ_M1));
+
+    y * math::cast(PQ_PEAK_LUMINANCE)
+}
+
+/// The inverse of the PQ transfer function, from absolute luminance in cd/m² to encoded values.
+pub fn pq_inverse_eotf<T: Float>(luminance: T) -> T {
+    let y = (luminance / math::cast(PQ_PEAK_LUMINANCE)).max(T::zero()).powf(math::cast(PQ_M1));
+
+    ((y * math::cast(PQ_C2) + math::cast(PQ_C1)) / (y * math::cast(PQ_C3) + T::one())).powf(math::cast(PQ_M2))
+}
+
+/// The PQ (SMPTE ST 2084) transfer function of BT.2100 for high dynamic range video
+///
+/// PQ encodes absolute luminance up to 10000 cd/m². Linear values are scaled by `luminance`,
+/// the luminance in cd/m² of a linear value of 1.0, which defaults to the 10000 cd/m² peak of PQ.
+/// A luminance of 203 cd/m² maps linear 1.0 to the HDR reference white of BT.2408 instead.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Pq {
+    /// The luminance of a linear value of 1.0, in cd/m²
+    pub luminance: f64,
+}
+
+impl Pq {
+    pub fn new(luminance: f64) -> Pq {
+        Pq { luminance }
+    }
+}
+
+impl Default for Pq {
+    fn default() -> Pq {
+        Pq::new(PQ_PEAK_LUMINANCE)
+    }
+}
+
+impl TransferFunction for Pq {
+    #[inline]
+    fn encode<T: Float>(&self, linear: T) -> T {
+        pq_inverse_eotf(linear * math::cast(self.luminance))
+    }
+
+    #[inline]
+    fn decode<T: Float>(&self, encoded: T) -> T {
+        pq_eotf(encoded) / math::cast(self.luminance)
+    }
+}
+
+const HLG_A: f64 = 0.17883277;
+const HLG_B: f64 = 1.0 - 4.0 * HLG_A;
+const HLG_C: f64 = 0.55991073;
+
+/// The HLG (Hybrid Log-Gamma) opto-electronic transfer function, from normalized scene light to encoded values.
+pub fn hlg_oetf<T: Float>(scene: T) -> T {
+    let scene = scene.max(T::zero());
+
+    if scene <= math::cast(1.0 / 12.0) {
+        (scene * math::cast(3.0)).sqrt()
+    } else {
+        (scene * math::cast(12.0) - math::cast(HLG_B)).ln() * math::cast(HLG_A) + math::cast(HLG_C)
+    }
+}
+
+/// The inverse of the HLG opto-electronic transfer function, from encoded values to normalized scene light.
+pub fn hlg_inverse_oetf<T: Float>(encoded: T) -> T {
+    let encoded = encoded.max(T::zero());
+
+    if encoded <= math::cast(0.5) {
+        encoded * encoded / math::cast(3.0)
+    } else {
+        (((encoded - math::cast(HLG_C)) / math::cast(HLG_A)).exp() + math::cast(HLG_B)) / math::cast(12.0)
+    }
+}
+
+/// The HLG (Hybrid Log-Gamma) transfer function of BT.2100 for high dynamic range broadcast
+///
+/// As a `TransferFunction`, `Hlg` is the opto-electronic transfer function between scene light,
+/// normalized to 0.0 to 1.0, and encoded values. Displaying HLG also requires the opto-optical transfer
+/// function, which depends on the luminance of all three channels and the display,
+/// see [`HlgDisplay`](struct.HlgDisplay.html).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Hlg;
+
+impl TransferFunction for Hlg {
+    #[inline]
+    fn encode<T: Float>(&self, linear: T) -> T {
+        hlg_oetf(linear)
+    }
+
+    #[inline]
+    fn decode<T: Float>(&self, encoded: T) -> T {
+        hlg_inverse_oetf(encoded)
+    }
+}
+
+/// A display for HLG signals, which maps scene light to display light with a system gamma
+/// depending on its peak luminance
+///
+/// The luminance of scene light is computed with the BT.2020 luma weights, so the RGB channels
+/// passed to the display should use the BT.2020 primaries.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HlgDisplay {
+    /// The nominal peak luminance of the display for white, in cd/m²
+    pub peak_luminance: f64,
+    /// The luminance of the display for black, in cd/m²
+    pub black_luminance: f64,
+}
+
+impl HlgDisplay {
+    pub fn new(peak_luminance: f64, black_luminance: f64) -> HlgDisplay {
+        HlgDisplay { peak_luminance, black_luminance }
+    }
+
+    /// The system gamma of the display, 1.2 for the 1000 cd/m² reference display.
+    pub fn system_gamma(&self) -> f64 {
+        1.2 + 0.42 * (self.peak_luminance / 1000.0).log10()
+    }
+
+    #[inline]
+    fn scene_luminance<T: Float>([r, g, b]: [T; 3]) -> T {
+        r * math::cast(0.2627) + g * math::cast(0.6780) + b * math::cast(0.0593)
+    }
+
+    /// The opto-optical transfer function, from normalized scene light to display light in cd/m².
+    pub fn ootf<T: Float>(&self, scene: [T; 3]) -> [T; 3] {
+        let gamma = self.system_gamma();
+
+        let ys = Self::scene_luminance(scene);
+
+        let scale = if ys > T::zero() {
+            ys.powf(math::cast(gamma - 1.0)) * math::cast(self.peak_luminance)
+        } else {
+            T::zero()
+        };
+
+        [scene[0] * scale, scene[1] * scale, scene[2] * scale]
+    }
+
+    /// The inverse of the opto-optical transfer function, from display light in cd/m² to normalized scene light.
+    pub fn inverse_ootf<T: Float>(&self, display: [T; 3]) -> [T; 3] {
+        let gamma = self.system_gamma();
+
+        let alpha: T = math::cast(self.peak_luminance);
+
+        let ys = (Self::scene_luminance(display) / alpha).powf(math::cast(1.0 / gamma));
+
+        let scale = if ys > T::zero() {
+            ys.powf(math::cast(1.0 - gamma)) / alpha
+        } else {
+            T::zero()
+        };
+
+        [display[0] * scale, display[1] * scale, display[2] * scale]
+    }
+
+    /// The lift applied to encoded values to reach the black level of the display.
+    #[inline]
+    fn beta(&self) -> f64 {
+        (3.0 * (self.black_luminance / self.peak_luminance).powf(1.0 / self.system_gamma())).sqrt()
+    }
+
+    /// The electro-optical transfer function, from encoded values to display light in cd/m².
+    pub fn eotf<T: Float>(&self, encoded: [T; 3]) -> [T; 3] {
+        let beta: T = math::cast(self.beta());
+
+        let lift = |e: T| hlg_inverse_oetf((e * (T::one() - beta) + beta).max(T::zero()));
+
+        self.ootf([lift(encoded[0]), lift(encoded[1]), lift(encoded[2])])
+    }
+
+    /// The inverse of the electro-optical transfer function, from display light in cd/m² to encoded values.
+    pub fn inverse_eotf<T: Float>(&self, display: [T; 3]) -> [T; 3] {
+        let beta: T = math::cast(self.beta());
+
+        let [r, g, b] = self.inverse_ootf(display);
+
+        let unlift = |s: T| (hlg_oetf(s) - beta) / (T::one() - beta);
+
+        [unlift(r), unlift(g), unlift(b)]
+    }
+}
+
+impl Default for HlgDisplay {
+    /// The 1000 cd/m² reference display of BT.2100, with a black level of zero
+    fn default() -> HlgDisplay {
+        HlgDisplay::new(1000.0, 0.0)
+    }
+}
+
+/// The BT.1886 reference electro-optical transfer function of displays for HDTV
+///
+/// BT.1886 models a display with a gamma of 2.4 whose black level is raised above zero, as in
+/// real displays, by offsetting the signal rather than adding light. With a black luminance of zero
+/// it is a pure power curve. As a `TransferFunction`, linear values are relative to the white luminance.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bt1886 {
+    /// The luminance of the display for white, in cd/m²
+    pub white_luminance: f64,
+    /// The luminance of the display for black, in cd/m²
+    pub black_luminance: f64,
+}
+
+impl Bt1886 {
+    const GAMMA: f64 = 2.4;
+
+    pub fn new(white_luminance: f64, black_luminance: f64) -> Bt1886 {
+        Bt1886 { white_luminance, black_luminance }
+    }
+
+    /// Returns the user gain `a` and black level lift `b` of the display.
+    fn gain_lift(&self) -> (f64, f64) {
+        let w = self.white_luminance.powf(1.0 / Self::GAMMA);
+        let b = self.black_luminance.powf(1.0 / Self::GAMMA);
+
+        ((w - b).powf(Self::GAMMA), b / (w - b))
+    }
+
+    /// The electro-optical transfer function, from a normalized signal to display light in cd/m².
+    pub fn eotf<T: Float>(&self, signal: T) -> T {
+        let (a, b) = self.gain_lift();
+
+        (signal + math::cast(b)).max(T::zero()).powf(math::cast(Self::GAMMA)) * math::cast(a)
+    }
+
+    /// The inverse of the electro-optical transfer function, from display light in cd/m² to a normalized signal.
+    pub fn inverse_eotf<T: Float>(&self, luminance: T) -> T {
+        let (a, b) = self.gain_lift();
+
+        (luminance / math::cast(a)).max(T::zero()).powf(math::cast(1.0 / Self::GAMMA)) - math::cast(b)
+    }
+
+    /// The electro-optical transfer function for a narrow (legal) range code value of the given bit depth,
+    /// where black is 16 and white is 235 for 8 bits, or 64 and 940 for 10 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bit depth is not between 8 and 32, as narrow range video is only defined from 8 bits.
+    pub fn eotf_code(&self, code: u32, bit_depth: u32) -> f64 {
+        assert!((8..=32).contains(&bit_depth), "narrow range bit depth must be between 8 and 32");
+
+        let scale = 2f64.powi(bit_depth as i32 - 8);
+
+        self.eotf((f64::from(code) - 16.0 * scale) / (219.0 * scale))
+    }
+}
+
+impl Default for Bt1886 {
+    /// A display with a white luminance of 100 cd/m² and a black level of zero
+    fn default() -> Bt1886 {
+        Bt1886::new(100.0, 0.0)
+    }
+}
+
+impl TransferFunction for Bt1886 {
+    #[inline]
+    fn encode<T: Float>(&self, linear: T) -> T {
+        self.inverse_eotf(linear * math::cast(self.white_luminance))
+    }
+
+    #[inline]
+    fn decode<T: Float>(&self, encoded: T) -> T {
+        self.eotf(encoded) / math::cast(self.white_luminance)
+    }
+}
+
+/// A pure power curve with an exponent known at runtime, and an optional linear segment near black
+///
+/// Content tagged with an arbitrary gamma, such as PNG images with a `gAMA` chunk or images
+/// for classic Mac OS displays with a gamma of 1.8, can be decoded with `PowerGamma`.
+/// When used as a type parameter the exponent is 2.2, so runtime exponents should be passed
+/// explicitly, such as with [`Rgb::into_linear_with`](../spaces/generic_rgb/struct.Rgb.html#method.into_linear_with).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PowerGamma {
+    /// The decoding exponent, where linear light is the encoded value raised to the exponent
+    pub exponent: f64,
+    /// The linear value below which the curve is a straight line through zero instead of a power curve,
+    /// or zero for a pure power curve
+    pub toe: f64,
+}
+
+impl PowerGamma {
+    /// Creates a pure power curve with the given decoding exponent.
+    pub fn new(exponent: f64) -> PowerGamma {
+        PowerGamma { exponent, toe: 0.0 }
+    }
+
+    /// Creates a power curve with the given decoding exponent and a straight line below the linear value `toe`.
+    pub fn with_toe(exponent: f64, toe: f64) -> PowerGamma {
+        PowerGamma { exponent, toe }
+    }
+
+    /// Creates a power curve from the value of a PNG `gAMA` chunk, the encoding exponent times 100000,
+    /// such as 45455 for a decoding exponent of 2.2.
+    pub fn from_png_gama(gama: u32) -> PowerGamma {
+        PowerGamma::new(100000.0 / f64::from(gama))
+    }
+
+    /// The encoded value at the end of the linear segment.
+    #[inline]
+    fn encoded_toe(&self) -> f64 {
+        self.toe.powf(1.0 / self.exponent)
+    }
+}
+
+impl Default for PowerGamma {
+    fn default() -> PowerGamma {
+        PowerGamma::new(2.2)
+    }
+}
+
+impl TransferFunction for PowerGamma {
+    /// Negative values, such as those outside the gamut of extended range RGB, are mirrored around zero.
+    fn encode<T: Float>(&self, linear: T) -> T {
+        let magnitude = linear.abs();
+
+        let encoded = if self.toe > 0.0 && magnitude < math::cast(self.toe) {
+            magnitude * math::cast(self.encoded_toe() / self.toe)
+        } else {
+            magnitude.powf(math::cast(1.0 / self.exponent))
+        };
+
+        if linear < T::zero() { -encoded } else { encoded }
+    }
+
+    /// Negative values are mirrored around zero, as with `encode`.
+    fn decode<T: Float>(&self, encoded: T) -> T {
+        let magnitude = encoded.abs();
+
+        let linear = if self.toe > 0.0 && magnitude < math::cast(self.encoded_toe()) {
+            magnitude * math::cast(self.toe / self.encoded_toe())
+        } else {
+            magnitude.powf(math::cast(self.exponent))
+        };
+
+        if encoded < T::zero() { -linear } else { linear }
+    }
+}
+
+/// The range of JND indices of the DICOM Grayscale Standard Display Function
+pub const GSDF_JND_RANGE: (f64, f64) = (1.0, 1023.0);
+
+/// The range of luminance of the DICOM Grayscale Standard Display Function, in cd/m²
+pub const GSDF_LUMINANCE_RANGE: (f64, f64) = (0.05, 3993.4);
+
+/// Evaluates a polynomial with the coefficients in increasing order of degree.
+#[inline]
+fn polynomial<T: Float>(x: T, coefficients: &[f64]) -> T {
+    coefficients.iter().rev().fold(T::zero(), |acc, &c| acc * x + math::cast(c))
+}
+
+/// The DICOM Grayscale Standard Display Function, from a just-noticeable difference (JND) index
+/// to luminance in cd/m².
+///
+/// The JND index ranges from 1 to 1023, see [`GSDF_JND_RANGE`](constant.GSDF_JND_RANGE.html).
+pub fn gsdf_luminance<T: Float>(jnd: T) -> T {
+    let j = jnd.ln();
+
+    let num = polynomial(j, &[-1.3011877, 8.0242636e-2, 1.3646699e-1, -2.5468404e-2, 1.3635334e-3]);
+    let den = polynomial(j, &[1.0, -2.5840191e-2, -1.0320229e-1, 2.8745620e-2, -3.1978977e-3, 1.2992634e-4]);
+
+    math::cast::<T>(10.0).powf(num / den)
+}
+
+/// The inverse of the DICOM Grayscale Standard Display Function, from luminance in cd/m² to a JND index.
+pub fn gsdf_jnd_index<T: Float>(luminance: T) -> T {
+    polynomial(luminance.log10(), &[
+        71.498068, 94.593053, 41.912053, 9.8247004, 0.28175407,
+        -1.1878455, -0.18014349, 0.14710899, -0.017046845,
+    ])
+}
+
+/// The DICOM PS3.14 Grayscale Standard Display Function (GSDF) of a calibrated display
+///
+/// The GSDF spaces luminance by just-noticeable differences of contrast, as modelled by the Barten
+/// model of the human visual system, so that equal steps of the signal are equally perceptible.
+/// A display calibrated to the GSDF maps the signal linearly onto the range of JND indices between
+/// its minimum and maximum luminance. As a `TransferFunction`, linear values are relative to
+/// the maximum luminance.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Gsdf {
+    /// The luminance of the display for the highest signal, in cd/m²
+    pub max_luminance: f64,
+    /// The luminance of the display for the lowest signal, in cd/m²
+    pub min_luminance: f64,
+}
+
+impl Gsdf {
+    pub fn new(max_luminance: f64, min_luminance: f64) -> Gsdf {
+        Gsdf { max_luminance, min_luminance }
+    }
+
+    /// Returns the JND indices of the minimum and maximum luminance.
+    fn jnd_range(&self) -> (f64, f64) {
+        (gsdf_jnd_index(self.min_luminance), gsdf_jnd_index(self.max_luminance))
+    }
+
+    /// The electro-optical transfer function, from a normalized signal to display light in cd/m².
+    pub fn eotf<T: Float>(&self, signal: T) -> T {
+        let (min, max) = self.jnd_range();
+
+        gsdf_luminance(math::cast::<T>(min) + signal * math::cast(max - min))
+    }
+
+    /// The inverse of the electro-optical transfer function, from display light in cd/m² to a normalized signal.
+    pub fn inverse_eotf<T: Float>(&self, luminance: T) -> T {
+        let (min, max) = self.jnd_range();
+
+        (gsdf_jnd_index(luminance) - math::cast(min)) / math::cast(max - min)
+    }
+
+    /// The electro-optical transfer function for a presentation value (P-value) of the given bit depth.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bit depth is not between 1 and 32.
+    pub fn eotf_code(&self, code: u32, bit_depth: u32) -> f64 {
+        assert!((1..=32).contains(&bit_depth), "P-value bit depth must be between 1 and 32");
+
+        self.eotf(f64::from(code) / (2f64.powi(bit_depth as i32) - 1.0))
+    }
+}
+
+impl Default for Gsdf {
+    /// A diagnostic display with a maximum luminance of 500 cd/m² and a minimum of 0.5 cd/m²
+    fn default() -> Gsdf {
+        Gsdf::new(500.0, 0.5)
+    }
+}
+
+impl TransferFunction for Gsdf {
+    #[inline]
+    fn encode<T: Float>(&self, linear: T) -> T {
+        self.inverse_eotf(linear * math::cast(self.max_luminance))
+    }
+
+    #[inline]
+    fn decode<T: Float>(&self, encoded: T) -> T {
+        self.eotf(encoded) / math::cast(self.max_luminance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_gamma_negative() {
+        let pure = PowerGamma::new(2.2);
+
+        assert_eq!(pure.encode(-0.1), -pure.encode(0.1));
+        assert_eq!(pure.decode(-0.1), -pure.decode(0.1));
+        assert_eq!(pure.encode(0.0), 0.0);
+
+        let toe = PowerGamma::with_toe(2.2, 0.01);
+
+        assert_eq!(toe.encode(-0.005), -toe.encode(0.005));
+        assert_eq!(toe.decode(-0.1), -toe.decode(0.1));
+        assert!((toe.decode(toe.encode(-0.5f64)) + 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bt1886_eotf_code() {
+        let display = Bt1886::default();
+
+        assert_eq!(display.eotf_code(16, 8), 0.0);
+        assert!((display.eotf_code(940, 10) - 100.0).abs() < 1e-9);
+        assert!((display.eotf_code(235 << 24, 32) - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bt1886_eotf_code_low_bit_depth() {
+        Bt1886::default().eotf_code(0, 7);
+    }
+
+    #[test]
+    fn gsdf_eotf_code() {
+        let display = Gsdf::default();
+
+        assert!((display.eotf_code(255, 8) - display.eotf(1.0)).abs() < 1e-9);
+        assert!((display.eotf_code(u32::MAX, 32) - display.eotf(1.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gsdf_eotf_code_zero_bit_depth() {
+        Gsdf::default().eotf_code(0, 0);
+    }
+}
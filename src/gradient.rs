@@ -0,0 +1,115 @@
+//! Gradients of colors through a list of stops.
+//!
+//! A [`Gradient`](struct.Gradient.html) interpolates between color stops with [`Mix`](../mix/trait.Mix.html), so
+//! the space the stops are stored in is the space the gradient is interpolated in. Store the stops in `Oklab`
+//! for perceptually even gradients, or in `Alpha` to interpolate with premultiplied alpha.
+//!
+//! Stops may have a hint, which moves the midpoint of the transition to the next stop, as in CSS gradients.
+
+use num_traits::{Zero, One, Float};
+
+use mix::Mix;
+use math;
+
+/// A color at a position along a gradient
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop<C, T> {
+    /// The color of the stop
+    pub color: C,
+    /// The position of the stop, usually from 0.0 to 1.0
+    pub position: T,
+    /// The position at which the transition to the next stop is halfway, instead of halfway between the stops
+    pub hint: Option<T>,
+}
+
+impl<C, T> GradientStop<C, T> {
+    /// Creates a stop without a hint.
+    #[inline]
+    pub fn new(color: C, position: T) -> GradientStop<C, T> {
+        GradientStop { color, position, hint: None }
+    }
+
+    /// Creates a stop with a hint for the transition to the next stop.
+    #[inline]
+    pub fn with_hint(color: C, position: T, hint: T) -> GradientStop<C, T> {
+        GradientStop { color, position, hint: Some(hint) }
+    }
+}
+
+/// A gradient through a list of color stops
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient<C: Mix> {
+    stops: Vec<GradientStop<C, C::Scalar>>,
+}
+
+impl<C: Mix + Copy> Gradient<C> {
+    /// Creates a gradient through the given stops, in order.
+    ///
+    /// As in CSS, a position lower than that of any stop before it is raised to the highest position before it,
+    /// so stops at the same position give a hard transition.
+    pub fn new(stops: Vec<GradientStop<C, C::Scalar>>) -> Gradient<C> {
+        let mut stops = stops;
+        let mut max = C::Scalar::neg_infinity();
+
+        for stop in &mut stops {
+            stop.position = stop.position.max(max);
+            max = stop.position;
+        }
+
+        Gradient { stops }
+    }
+
+    /// Creates a gradient through the given colors, spaced evenly from 0.0 to 1.0.
+    pub fn evenly_spaced(colors: &[C]) -> Gradient<C> {
+        let last: C::Scalar = math::cast(colors.len().saturating_sub(1).max(1) as f64);
+
+        let stops = colors.iter().enumerate()
+            .map(|(i, &color)| GradientStop::new(color, math::cast::<C::Scalar>(i as f64) / last))
+            .collect();
+
+        Gradient { stops }
+    }
+
+    /// The stops of the gradient, in order of position
+    #[inline]
+    pub fn stops(&self) -> &[GradientStop<C, C::Scalar>] {
+        &self.stops
+    }
+
+    /// The color of the gradient at a position, or `None` if the gradient has no stops.
+    ///
+    /// Positions before the first stop or after the last have the color of that stop. At the position of several
+    /// stops, the color is that of the last of them.
+    pub fn sample(&self, position: C::Scalar) -> Option<C> {
+        let (first, last) = (self.stops.first()?, self.stops.last()?);
+
+        if position.is_nan() || position < first.position {
+            return Some(first.color);
+        }
+
+        // The first stop after the position, which the position is between along with the stop before it
+        let next = match self.stops.iter().position(|stop| stop.position > position) {
+            Some(next) => next,
+            None => return Some(last.color),
+        };
+
+        let (a, b) = (&self.stops[next - 1], &self.stops[next]);
+
+        let mut t = (position - a.position) / (b.position - a.position);
+
+        if let Some(hint) = a.hint {
+            let h = (hint - a.position) / (b.position - a.position);
+
+            // The CSS transition function, which is halfway at `h`
+            t = if h <= C::Scalar::zero() {
+                C::Scalar::one()
+            } else if h >= C::Scalar::one() {
+                C::Scalar::zero()
+            } else {
+                t.powf(math::cast::<C::Scalar>(0.5).ln() / h.ln())
+            };
+        }
+
+        Some(a.color.mix(b.color, t))
+    }
+}
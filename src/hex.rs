@@ -0,0 +1,181 @@
+//! Hexadecimal notation of sRGB colors, as used by CSS and most design tools.
+//!
+//! `Srgb` parses from `#rgb` and `#rrggbb`, and `Srgba` additionally from `#rgba` and `#rrggbbaa`, where each
+//! digit of the short forms is repeated, so `#f80` is `#ff8800`. The `#` is optional and digits are case-insensitive.
+//! Invalid colors fail with a [`ParseColorError`](../parse/struct.ParseColorError.html).
+//!
+//! ```ignore
+//! let orange: Srgb<u8> = "#ff8800".parse()?;
+//! let translucent: Srgba<f32, D65> = "f808".parse()?;
+//! ```
+//!
+//! 8-bit colors format back to hex with `{:x}` and `{:X}`, with the `#` added by the alternate flag and padded
+//! to any width as strings are, so `format!("{:#x}", orange)` is `#ff8800`.
+
+use std::str::FromStr;
+use std::fmt::{self, Formatter, LowerHex, UpperHex, Write};
+
+use num_traits::{Zero, One, Float, ToPrimitive};
+
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use parse::{ParseColorError, ParseErrorKind};
+use math;
+
+use spaces::srgb::Srgb;
+
+/// Parses the channels of a hex color as bytes, with alpha if there are 4 or 8 digits and `alpha` allows it.
+fn parse_hex(s: &str, alpha: bool) -> Result<([u8; 3], Option<u8>), ParseColorError> {
+    let start = if s.starts_with('#') { 1 } else { 0 };
+
+    let (max, expected) = if alpha { (8, "3, 4, 6 or 8 hex digits") } else { (6, "3 or 6 hex digits") };
+
+    let mut digits = [0u8; 8];
+    let mut len = 0;
+
+    for (i, c) in s[start..].char_indices() {
+        let digit = c.to_digit(16).ok_or_else(|| {
+            ParseColorError::new(ParseErrorKind::InvalidDigit(c), start + i).expected("a hex digit")
+        })?;
+
+        if len < digits.len() {
+            digits[len] = digit as u8;
+        }
+
+        len += 1;
+    }
+
+    let channel = |i: usize| match len {
+        3 | 4 => digits[i] * 0x11,
+        _ => digits[i * 2] << 4 | digits[i * 2 + 1],
+    };
+
+    match len {
+        0 => Err(ParseColorError::new(ParseErrorKind::Empty, s.len()).expected(expected)),
+        3 | 6 => Ok(([channel(0), channel(1), channel(2)], None)),
+        4 | 8 if alpha => Ok(([channel(0), channel(1), channel(2)], Some(channel(3)))),
+        // Point at the first digit too many, or at the end if there are too few
+        _ => {
+            let offset = if len > max { start + max } else { s.len() };
+
+            Err(ParseColorError::new(ParseErrorKind::InvalidLength(len), offset).expected(expected))
+        }
+    }
+}
+
+/// Converts a byte into any channel type.
+#[inline]
+fn from_byte<C: Channel>(byte: u8) -> C {
+    C::from_float(math::cast(byte as f64 / 255.0))
+}
+
+/// Converts any channel type into a byte, clamping it from 0.0 to 1.0 and rounding.
+#[inline]
+pub(crate) fn to_byte<C: Channel>(channel: C) -> u8 {
+    let v = channel.into_float().max(FloatChannel::<C>::zero()).min(FloatChannel::<C>::one());
+
+    (v * math::cast(255.0)).round().to_u8().unwrap_or(0)
+}
+
+impl<C: Channel, Wp> FromStr for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    type Err = ParseColorError;
+
+    /// Parses `#rgb` or `#rrggbb`, with an optional `#`.
+    fn from_str(s: &str) -> Result<Srgb<C, Wp>, ParseColorError> {
+        let ([r, g, b], _) = parse_hex(s, false)?;
+
+        Ok(Srgb::with_wp(from_byte(r), from_byte(g), from_byte(b)))
+    }
+}
+
+impl<C: Channel, Wp> FromStr for Alpha<Srgb<C, Wp>>
+where
+    Wp: WhitePoint<C>,
+{
+    type Err = ParseColorError;
+
+    /// Parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, with an optional `#`. Colors without alpha are opaque.
+    fn from_str(s: &str) -> Result<Alpha<Srgb<C, Wp>>, ParseColorError> {
+        let ([r, g, b], alpha) = parse_hex(s, true)?;
+
+        Ok(Alpha::from_color(Srgb::with_wp(from_byte(r), from_byte(g), from_byte(b)), from_byte(alpha.unwrap_or(0xFF))))
+    }
+}
+
+/// Formats bytes as hex digits, with a leading `#` for the alternate flag, and pads them as a string.
+fn format_hex(f: &mut Formatter, bytes: &[u8], upper: bool) -> fmt::Result {
+    let mut s = String::with_capacity(9);
+
+    if f.alternate() {
+        s.push('#');
+    }
+
+    for byte in bytes {
+        if upper {
+            write!(s, "{:02X}", byte)?;
+        } else {
+            write!(s, "{:02x}", byte)?;
+        }
+    }
+
+    f.pad(&s)
+}
+
+impl<Wp> LowerHex for Srgb<u8, Wp>
+where
+    Wp: WhitePoint<u8>,
+{
+    /// Formats as `rrggbb`, or `#rrggbb` with the alternate flag.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        format_hex(f, &[self.r, self.g, self.b], false)
+    }
+}
+
+impl<Wp> UpperHex for Srgb<u8, Wp>
+where
+    Wp: WhitePoint<u8>,
+{
+    /// Formats as `RRGGBB`, or `#RRGGBB` with the alternate flag.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        format_hex(f, &[self.r, self.g, self.b], true)
+    }
+}
+
+impl<Wp> LowerHex for Alpha<Srgb<u8, Wp>>
+where
+    Wp: WhitePoint<u8>,
+{
+    /// Formats as `rrggbbaa`, or `#rrggbbaa` with the alternate flag.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        format_hex(f, &[self.r, self.g, self.b, self.alpha], false)
+    }
+}
+
+impl<Wp> UpperHex for Alpha<Srgb<u8, Wp>>
+where
+    Wp: WhitePoint<u8>,
+{
+    /// Formats as `RRGGBBAA`, or `#RRGGBBAA` with the alternate flag.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        format_hex(f, &[self.r, self.g, self.b, self.alpha], true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        for s in &["", "#"] {
+            assert_eq!(s.parse::<Srgb<u8>>().unwrap_err().kind(), ParseErrorKind::Empty);
+            assert_eq!(s.parse::<Alpha<Srgb<u8>>>().unwrap_err().kind(), ParseErrorKind::Empty);
+        }
+
+        assert_eq!("#12".parse::<Srgb<u8>>().unwrap_err().kind(), ParseErrorKind::InvalidLength(2));
+    }
+}
@@ -0,0 +1,311 @@
+//! Hue angles with wrapping arithmetic.
+//!
+//! [`Deg`](struct.Deg.html) and [`Rad`](struct.Rad.html) always wrap into a single turn, so that angles a full
+//! turn apart compare equal and differences take the shortest way around the circle.
+//!
+//! The hue components of cylindrical spaces are `Deg` angles, stored alongside their other channels. Floating
+//! point channels hold the hue in degrees, and integer channels a fraction of a full turn over their full
+//! range, so that `Hsl<u8>` stores a hue of 180 degrees as 128.
+//!
+//! The [`Hue`](trait.Hue.html) trait reads and adjusts the hue of any cylindrical space through `Deg`,
+//! and [`HueRotate`](trait.HueRotate.html) rotates the hues of RGB colors without leaving RGB.
+
+use std::ops::{Add, Sub, Neg};
+use std::f64::consts::PI;
+
+use num_traits::Float;
+
+use channels::*;
+use white_point::WhitePoint;
+use convert::ConvertFrom;
+use limited::Limited;
+use color_matrix::ColorMatrix;
+use gamma::{Encoding, TransferFunction, srgb_encode, srgb_decode};
+use math;
+
+use spaces::all::*;
+use spaces::generic_rgb::Rgb;
+use spaces::primaries::Primaries;
+use spaces::ycbcr::YCbCrStandard;
+use spaces::hsp::Hsp;
+
+/// Wraps an angle into `[0, full)`, including values that round up to a full turn.
+#[inline]
+fn wrap<T: Float>(angle: T, full: T) -> T {
+    let angle = angle % full;
+    let angle = if angle < T::zero() { angle + full } else { angle };
+
+    if angle >= full { T::zero() } else { angle }
+}
+
+/// Wraps the difference between two angles into `(-half, half]`.
+#[inline]
+fn shortest<T: Float>(diff: T, full: T) -> T {
+    let half = full / math::cast(2.0);
+
+    let diff = wrap(diff, full);
+
+    if diff > half { diff - full } else { diff }
+}
+
+macro_rules! declare_angles {
+    ($($(#[$attr:meta])* $name:ident: $full:expr, $getter:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[repr(transparent)]
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+            pub struct $name<T>(pub(crate) T);
+
+            impl<T: Float> $name<T> {
+                /// Creates an angle, wrapping it into a single turn.
+                #[inline]
+                pub fn new(angle: T) -> $name<T> {
+                    $name(wrap(angle, math::cast($full)))
+                }
+
+                /// The angle, from zero up to a full turn
+                #[inline]
+                pub fn $getter(self) -> T {
+                    wrap(self.0, math::cast($full))
+                }
+
+                /// The signed difference from `self` to `other` along the shorter arc, from minus a half turn
+                /// up to a half turn.
+                #[inline]
+                pub fn difference(self, other: $name<T>) -> T {
+                    shortest(other.0 - self.0, math::cast($full))
+                }
+
+                /// The unsigned distance between two angles along the shorter arc
+                #[inline]
+                pub fn distance(self, other: $name<T>) -> T {
+                    self.difference(other).abs()
+                }
+            }
+
+            impl<T: Float> Add for $name<T> {
+                type Output = $name<T>;
+
+                #[inline]
+                fn add(self, rhs: $name<T>) -> $name<T> {
+                    $name::new(self.0 + rhs.0)
+                }
+            }
+
+            impl<T: Float> Sub for $name<T> {
+                type Output = $name<T>;
+
+                #[inline]
+                fn sub(self, rhs: $name<T>) -> $name<T> {
+                    $name::new(self.0 - rhs.0)
+                }
+            }
+
+            impl<T: Float> Add<T> for $name<T> {
+                type Output = $name<T>;
+
+                #[inline]
+                fn add(self, rhs: T) -> $name<T> {
+                    $name::new(self.0 + rhs)
+                }
+            }
+
+            impl<T: Float> Sub<T> for $name<T> {
+                type Output = $name<T>;
+
+                #[inline]
+                fn sub(self, rhs: T) -> $name<T> {
+                    $name::new(self.0 - rhs)
+                }
+            }
+
+            impl<T: Float> Neg for $name<T> {
+                type Output = $name<T>;
+
+                #[inline]
+                fn neg(self) -> $name<T> {
+                    $name::new(-self.0)
+                }
+            }
+        )*
+    }
+}
+
+declare_angles! {
+    /// A hue angle in degrees, wrapped into `[0, 360)`
+    Deg: 360.0, degrees;
+    /// A hue angle in radians, wrapped into `[0, 2π)`
+    Rad: 2.0 * PI, radians;
+}
+
+impl<C: Channel> Deg<C> {
+    /// Converts a hue stored in a channel into floating point. Integer channels store the hue as a fraction of
+    /// a full turn, over their full range.
+    #[inline]
+    pub fn into_float(self) -> Deg<FloatChannel<C>> {
+        Deg(self.0.into_float_in(0.0, 360.0))
+    }
+
+    /// Stores a hue in a channel, the inverse of `into_float`.
+    #[inline]
+    pub fn from_float(angle: Deg<FloatChannel<C>>) -> Deg<C> {
+        Deg(C::from_float_in(angle.degrees(), 0.0, 360.0))
+    }
+}
+
+impl<T: Float> From<Rad<T>> for Deg<T> {
+    #[inline]
+    fn from(angle: Rad<T>) -> Deg<T> {
+        Deg::new(angle.0.to_degrees())
+    }
+}
+
+impl<T: Float> From<Deg<T>> for Rad<T> {
+    #[inline]
+    fn from(angle: Deg<T>) -> Rad<T> {
+        Rad::new(angle.0.to_radians())
+    }
+}
+
+/// Access to the hue of colors in cylindrical spaces
+pub trait Hue: Sized {
+    /// The floating point type of the hue
+    type Scalar: Float;
+
+    /// The hue of the color
+    fn get_hue(&self) -> Deg<Self::Scalar>;
+
+    /// Returns the color with its hue replaced.
+    fn with_hue(self, hue: Deg<Self::Scalar>) -> Self;
+
+    /// Rotates the hue of the color by `degrees`.
+    #[inline]
+    fn shift_hue(self, degrees: Self::Scalar) -> Self {
+        let hue = self.get_hue() + degrees;
+
+        self.with_hue(hue)
+    }
+
+    /// Rotates the hue by 180°, giving the complementary color of the same lightness and saturation.
+    ///
+    /// See [`Invert`](../invert/trait.Invert.html) for the photographic negative of RGB colors instead.
+    #[inline]
+    fn complement(self) -> Self {
+        self.shift_hue(math::cast(180.0))
+    }
+}
+
+macro_rules! impl_hue {
+    ($($name:ident $(< $($p:ident: $bound:path),* >)* { $h:ident }),*) => {
+        $(
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> Hue for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C>,
+            {
+                type Scalar = FloatChannel<C>;
+
+                #[inline]
+                fn get_hue(&self) -> Deg<FloatChannel<C>> {
+                    Deg::new(self.$h.into_float().degrees())
+                }
+
+                #[inline]
+                fn with_hue(mut self, hue: Deg<FloatChannel<C>>) -> Self {
+                    self.$h = Deg::from_float(hue);
+                    self
+                }
+            }
+        )*
+    }
+}
+
+impl_hue! {
+    Hsl { h }, Hsv { h }, Hwb { h }, Hsi { h }, Hsp<S: YCbCrStandard> { h },
+    Lch { h }, Lchuv { h }, Oklch { h }, Okhsl { h }, Okhsv { h }, Jzczhz { hz }
+}
+
+/// Rotation of the hues of RGB colors
+pub trait HueRotate: Sized {
+    /// The floating point type of the angle
+    type Scalar: Float;
+
+    /// Rotates hues by `degrees` with the rotation matrix of the CSS `hue-rotate()` filter, applied in linear RGB.
+    ///
+    /// This is a single matrix multiplication, but it only approximately preserves lightness and saturation.
+    /// As with the filter, the result is clamped to the gamut of the space.
+    fn hue_rotate(self, degrees: Self::Scalar) -> Self;
+
+    /// Rotates hues by `degrees` in Oklch, preserving perceptual lightness and chroma.
+    ///
+    /// Colors that leave the gamut of their space are clamped.
+    fn hue_rotate_exact(self, degrees: Self::Scalar) -> Self;
+}
+
+macro_rules! impl_hue_rotate {
+    ($($name:ident $(< $($p:ident: $bound:path),* >)* => $decode:expr, $encode:expr;)*) => {
+        $(
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> HueRotate for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C>,
+                Self: Limited,
+                Oklch<FloatChannel<C>, Wp>: ConvertFrom<$name<FloatChannel<C>, Wp $($(, $p)*)*>>,
+                $name<FloatChannel<C>, Wp $($(, $p)*)*>: ConvertFrom<Oklch<FloatChannel<C>, Wp>>,
+            {
+                type Scalar = FloatChannel<C>;
+
+                fn hue_rotate(self, degrees: FloatChannel<C>) -> Self {
+                    let rgb = self.into_float();
+
+                    let m = ColorMatrix::hue_rotate(degrees);
+                    let [r, g, b] = m.apply_rgb([$decode(rgb.r), $decode(rgb.g), $decode(rgb.b)]);
+
+                    $name::with_wp(C::from_float($encode(r)), C::from_float($encode(g)), C::from_float($encode(b))).clamp()
+                }
+
+                fn hue_rotate_exact(self, degrees: FloatChannel<C>) -> Self {
+                    let mut lch = Oklch::convert_from(self.into_float());
+
+                    lch.h = lch.h + degrees;
+
+                    $name::from_float(ConvertFrom::convert_from(lch)).clamp()
+                }
+            }
+        )*
+    }
+}
+
+impl_hue_rotate! {
+    LinearSrgb => |v| v, |v| v;
+    Srgb => srgb_decode, srgb_encode;
+    Rgb<P: Primaries, E: Encoding> => |v| E::default().decode(v), |v| E::default().encode(v);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(Deg::new(370.0f64), Deg::new(10.0));
+        assert_eq!(Deg::new(-90.0f64).degrees(), 270.0);
+        assert_eq!((Deg::new(350.0f64) + 20.0).degrees(), 10.0);
+
+        assert_eq!(Deg::new(350.0f64).difference(Deg::new(10.0)), 20.0);
+        assert_eq!(Deg::new(10.0f64).difference(Deg::new(350.0)), -20.0);
+        assert!((Deg::from(Rad::new(-PI / 2.0)).degrees() - 270.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn integer_hue() {
+        let hsl = Hsl::<u8>::new(0, 255, 128).with_hue(Deg::new(180.0));
+
+        assert_eq!(hsl.h, Deg(128));
+        assert!(hsl.get_hue().distance(Deg::new(180.0)) < 1.0);
+        assert!(hsl.complement().get_hue().distance(Deg::new(0.0)) < 1.0);
+
+        let hsl = Hsl::<f32>::new(400.0, 1.0, 0.5);
+
+        assert_eq!(hsl.h.degrees(), 40.0);
+        assert_eq!(hsl.shift_hue(-50.0).get_hue().degrees(), 350.0);
+    }
+}
@@ -0,0 +1,60 @@
+//! Photographic negatives of RGB colors.
+//!
+//! Inverting complements each channel of the encoded RGB values, as with the `invert()` CSS filter. This differs
+//! from [`Hue::complement`](../hue/trait.Hue.html#method.complement), which keeps lightness and saturation
+//! and only rotates the hue by 180°.
+
+use num_traits::One;
+
+use channels::*;
+use alpha::Alpha;
+use color::Color;
+use white_point::WhitePoint;
+use gamma::Encoding;
+
+use spaces::all::*;
+use spaces::generic_rgb::Rgb;
+use spaces::primaries::Primaries;
+
+/// Inverting colors into their photographic negative
+pub trait Invert {
+    /// Complements each channel, so black becomes white and red becomes cyan.
+    fn invert(self) -> Self;
+}
+
+/// Complements a channel within its range from 0.0 to 1.0.
+#[inline]
+fn complement<C: Channel>(value: C) -> C {
+    C::from_float(FloatChannel::<C>::one() - value.into_float())
+}
+
+macro_rules! impl_invert {
+    ($($name:ident $(< $($p:ident: $bound:path),* >)*),*) => {
+        $(
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> Invert for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C>,
+            {
+                #[inline]
+                fn invert(mut self) -> Self {
+                    self.r = complement(self.r);
+                    self.g = complement(self.g);
+                    self.b = complement(self.b);
+                    self
+                }
+            }
+        )*
+    }
+}
+
+impl_invert! {
+    Srgb, DisplayP3, Rgb<P: Primaries, E: Encoding>
+}
+
+impl<C: Color + Invert> Invert for Alpha<C> {
+    /// Inverts the color, leaving alpha untouched
+    #[inline]
+    fn invert(self) -> Self {
+        self.map_color(Invert::invert)
+    }
+}
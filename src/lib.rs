@@ -7,21 +7,65 @@ extern crate generic_array;
 #[macro_use]
 extern crate numeric_array;
 extern crate nalgebra;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
 
 pub mod channels;
+mod math;
 #[macro_use]
 pub mod color;
 pub mod alpha;
-//pub mod limited;
+pub mod compose;
+pub mod observer;
 pub mod white_point;
+pub mod temperature;
 pub mod spaces;
-//pub mod blend;
-//pub mod gamma;
+pub mod convert;
+pub mod adapt;
+pub mod blend;
+pub mod gamma;
+pub mod limited;
+pub mod mix;
+pub mod gradient;
+pub mod shade;
+pub mod saturate;
+pub mod hue;
+pub mod invert;
+pub mod luminance;
+pub mod color_matrix;
+pub mod filters;
+pub mod adjust;
+pub mod white_balance;
+pub mod chroma_key;
+pub mod difference;
+pub mod contrast;
+pub mod palette;
+pub mod macadam;
+pub mod parse;
+pub mod hex;
+pub mod named;
+pub mod css;
+pub mod ansi;
+#[cfg(feature = "serde")]
+pub mod serialize;
+#[cfg(feature = "bytemuck")]
+mod pod;
+pub mod referred;
 
 pub mod prelude {
     //pub use ::channels::Channel;
     //pub use ::color::Color;
-    //pub use ::blend::{Blend, BlendSync, GenericBlend, BoxedGenericBlend, BoxedGenericBlendSync};
+    pub use ::convert::{ConvertFrom, IntoColor};
+    pub use ::blend::{Blend, BlendSync, GenericBlend, BoxedGenericBlend, BoxedGenericBlendSync};
+    pub use ::mix::Mix;
+    pub use ::shade::Shade;
+    pub use ::saturate::{Saturate, Vibrance};
+    pub use ::hue::{Hue, HueRotate};
+    pub use ::invert::Invert;
+    pub use ::luminance::{RelativeLuminance, Luma};
+    pub use ::difference::{DeltaE, ColorDistance};
     //pub use ::formats::rgb;
-    //pub use ::gamma;
+    pub use ::gamma;
 }
@@ -1,3 +1,27 @@
+//! Valid ranges of color components.
+//!
+//! Ranges are given for floating point channels, and colors with integer channels are checked after
+//! converting them to floating point, so unsigned integer channels of bounded spaces are always valid.
+//!
+//! Opponent axes, such as a* and b* of L*a*b*, have no fixed bounds, and only need to be finite.
+//! Hue angles are periodic and are valid at any finite angle, and clamping replaces others with zero.
+
+use num_traits::{Zero, Float, ToPrimitive};
+
+use channels::*;
+use color::{Color, ColorChannel};
+use alpha::Alpha;
+use white_point::WhitePoint;
+use hue::Deg;
+use math;
+
+use spaces::all::*;
+use spaces::generic_rgb::Rgb;
+use spaces::ycbcr::YCbCr;
+use spaces::ypbpr::YPbPr;
+use spaces::lms::Lms;
+use spaces::hsp::Hsp;
+
 /// A trait for clamping and checking if colors are within their ranges.
 pub trait Limited {
     /// Check if the color's components are within the expected ranges.
@@ -9,4 +33,143 @@ pub trait Limited {
 
     /// Clamp color components in-place
     fn clamp_self(&mut self);
-}
\ No newline at end of file
+}
+
+/// Checks if a channel is within the given range. NaN and infinite values are never valid.
+#[inline]
+fn in_range<C: Channel>(value: C, min: f64, max: f64) -> bool {
+    let value = value.into_float();
+
+    value.is_finite() && value >= math::cast(min) && value <= math::cast(max)
+}
+
+/// Clamps a channel to the given range, leaving valid values untouched. NaN is clamped to zero.
+#[inline]
+fn clamp_channel<C: Channel>(value: C, min: f64, max: f64) -> C {
+    let (min, max): (FloatChannel<C>, FloatChannel<C>) = (math::cast(min), math::cast(max));
+
+    let f = value.into_float();
+
+    if f.is_nan() {
+        C::from_float(FloatChannel::<C>::zero().max(min).min(max))
+    } else if !f.is_finite() || f < min || f > max {
+        C::from_float(f.max(min).min(max).max(FloatChannel::<C>::min_value()).min(FloatChannel::<C>::max_value()))
+    } else {
+        value
+    }
+}
+
+/// The XYZ of the white point, as `f64`.
+#[inline]
+fn white<C: Channel, Wp: WhitePoint<C>>() -> [f64; 3] {
+    Wp::tristimulus()
+}
+
+macro_rules! impl_limited {
+    ($(
+        $name:ident $(< $($p:ident),* >)* $([$($h:ident),*])* { $($c:ident: $min:expr => $max:expr),* }
+    )*) => {
+        $(
+            impl<C: Channel, Wp $($(, $p)*)*> Limited for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C>,
+            {
+                fn is_valid(&self) -> bool {
+                    let color = self.into_float();
+
+                    $($(color.$h.degrees().is_finite() &&)*)* $(in_range(color.$c, $min, $max))&&*
+                }
+
+                fn clamp(&self) -> Self {
+                    let mut color = *self;
+
+                    color.clamp_self();
+
+                    color
+                }
+
+                fn clamp_self(&mut self) {
+                    let mut color = self.into_float();
+
+                    $($(
+                        if !color.$h.degrees().is_finite() {
+                            color.$h = Deg::new(Zero::zero());
+                        }
+                    )*)*
+
+                    $(color.$c = clamp_channel(color.$c, $min, $max);)*
+
+                    *self = $name::from_float(color);
+                }
+            }
+        )*
+    }
+}
+
+impl_limited! {
+    Srgb { r: 0.0 => 1.0, g: 0.0 => 1.0, b: 0.0 => 1.0 }
+    LinearSrgb { r: 0.0 => 1.0, g: 0.0 => 1.0, b: 0.0 => 1.0 }
+    ScRgb { r: -0.5 => 7.4999, g: -0.5 => 7.4999, b: -0.5 => 7.4999 }
+    Rgb<P, E> { r: 0.0 => 1.0, g: 0.0 => 1.0, b: 0.0 => 1.0 }
+    DisplayP3 { r: 0.0 => 1.0, g: 0.0 => 1.0, b: 0.0 => 1.0 }
+    Aces2065 { r: 0.0 => f64::INFINITY, g: 0.0 => f64::INFINITY, b: 0.0 => f64::INFINITY }
+    AcesCg { r: 0.0 => f64::INFINITY, g: 0.0 => f64::INFINITY, b: 0.0 => f64::INFINITY }
+
+    Hsl [h] { s: 0.0 => 1.0, l: 0.0 => 1.0 }
+    Hsv [h] { s: 0.0 => 1.0, v: 0.0 => 1.0 }
+    Hwb [h] { w: 0.0 => 1.0, b: 0.0 => 1.0 }
+    Hsi [h] { s: 0.0 => 1.0, i: 0.0 => 1.0 }
+    Hsp<S> [h] { s: 0.0 => 1.0, p: 0.0 => 1.0 }
+
+    Xyz { x: 0.0 => white::<C, Wp>()[0], y: 0.0 => white::<C, Wp>()[1], z: 0.0 => white::<C, Wp>()[2] }
+    Yxy { x: 0.0 => 1.0, y: 0.0 => 1.0, luma: 0.0 => white::<C, Wp>()[1] }
+    Uv1960 { u: 0.0 => 1.0, v: 0.0 => 1.0 }
+    UvPrime { u: 0.0 => 1.0, v: 0.0 => 1.0 }
+
+    Lab { l: 0.0 => 100.0, a: -f64::INFINITY => f64::INFINITY, b: -f64::INFINITY => f64::INFINITY }
+    Lch [h] { l: 0.0 => 100.0, c: 0.0 => f64::INFINITY }
+    Luv { l: 0.0 => 100.0, u: -f64::INFINITY => f64::INFINITY, v: -f64::INFINITY => f64::INFINITY }
+    Lchuv [h] { l: 0.0 => 100.0, c: 0.0 => f64::INFINITY }
+    Oklab { l: 0.0 => 1.0, a: -f64::INFINITY => f64::INFINITY, b: -f64::INFINITY => f64::INFINITY }
+    Oklch [h] { l: 0.0 => 1.0, c: 0.0 => f64::INFINITY }
+    Okhsl [h] { s: 0.0 => 1.0, l: 0.0 => 1.0 }
+    Okhsv [h] { s: 0.0 => 1.0, v: 0.0 => 1.0 }
+    Cam16Ucs { j: 0.0 => 100.0, a: -f64::INFINITY => f64::INFINITY, b: -f64::INFINITY => f64::INFINITY }
+    Jzazbz { jz: 0.0 => 1.0, az: -f64::INFINITY => f64::INFINITY, bz: -f64::INFINITY => f64::INFINITY }
+    Jzczhz [hz] { jz: 0.0 => 1.0, cz: 0.0 => f64::INFINITY }
+    Din99 { l: 0.0 => 100.0, a: -f64::INFINITY => f64::INFINITY, b: -f64::INFINITY => f64::INFINITY }
+    Din99d { l: 0.0 => 100.0, a: -f64::INFINITY => f64::INFINITY, b: -f64::INFINITY => f64::INFINITY }
+    Ipt { i: 0.0 => 1.0, p: -f64::INFINITY => f64::INFINITY, t: -f64::INFINITY => f64::INFINITY }
+    OsaUcs { l: -f64::INFINITY => f64::INFINITY, j: -f64::INFINITY => f64::INFINITY, g: -f64::INFINITY => f64::INFINITY }
+    Xyb { x: -f64::INFINITY => f64::INFINITY, y: 0.0 => f64::INFINITY, b: 0.0 => f64::INFINITY }
+    Lms<M> { l: 0.0 => f64::INFINITY, m: 0.0 => f64::INFINITY, s: 0.0 => f64::INFINITY }
+
+    YCbCr<S, R> { y: 0.0 => 1.0, cb: 0.0 => 1.0, cr: 0.0 => 1.0 }
+    YPbPr<S> { y: 0.0 => 1.0, pb: -0.5 => 0.5, pr: -0.5 => 0.5 }
+    YDbDr { y: 0.0 => 1.0, db: -1.333 => 1.333, dr: -1.333 => 1.333 }
+    YCoCg { y: 0.0 => 1.0, co: 0.0 => 1.0, cg: 0.0 => 1.0 }
+    YCoCgR { y: 0.0 => 1.0, co: -0.5 => 1.5, cg: -0.5 => 1.5 }
+
+    Cmy { c: 0.0 => 1.0, m: 0.0 => 1.0, y: 0.0 => 1.0 }
+    Cmyk { c: 0.0 => 1.0, m: 0.0 => 1.0, y: 0.0 => 1.0, k: 0.0 => 1.0 }
+}
+
+impl<C: Color + Limited + Copy> Limited for Alpha<C> {
+    /// Checks the color and that alpha is from 0.0 to 1.0
+    fn is_valid(&self) -> bool {
+        self.color().is_valid() && in_range(self.alpha, 0.0, 1.0)
+    }
+
+    fn clamp(&self) -> Self {
+        let mut color = *self;
+
+        color.clamp_self();
+
+        color
+    }
+
+    fn clamp_self(&mut self) {
+        self.color_mut().clamp_self();
+        self.alpha = clamp_channel::<ColorChannel<C>>(self.alpha, 0.0, 1.0);
+    }
+}
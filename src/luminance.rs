@@ -0,0 +1,79 @@
+//! Relative luminance and luma of colors.
+//!
+//! Relative luminance is the CIE Y of a color, relative to the luminance of its white point. It is computed
+//! from linear light, so it is the right measure for contrast ratios and for sorting colors by brightness.
+//!
+//! Luma is the weighted sum of gamma-encoded RGB channels, as used by video standards. It is cheaper to compute,
+//! but only approximates the perceived brightness of saturated colors.
+
+use channels::*;
+use color::{Color, ColorChannel, ColorWhitePoint};
+use white_point::WhitePoint;
+use gamma::Encoding;
+use math;
+
+use spaces::all::*;
+use spaces::generic_rgb::Rgb;
+use spaces::primaries::Primaries;
+use spaces::ycbcr::YCbCrStandard;
+
+/// The relative luminance of colors
+///
+/// This is implemented for every color space that can be converted to CIE XYZ. The conversion uses the channel
+/// type of the color, so colors with integer channels lose precision.
+pub trait RelativeLuminance {
+    /// The floating point type of the luminance
+    type Scalar;
+
+    /// The CIE Y of the color, where the white point has a luminance of 1.0
+    fn relative_luminance(&self) -> Self::Scalar;
+}
+
+impl<T> RelativeLuminance for T
+where
+    T: Color + Copy,
+    Xyz<ColorChannel<T>, ColorWhitePoint<T>>: From<T>,
+{
+    type Scalar = FloatChannel<ColorChannel<T>>;
+
+    #[inline]
+    fn relative_luminance(&self) -> Self::Scalar {
+        Xyz::from(*self).y.into_float()
+    }
+}
+
+/// The luma of gamma-encoded RGB colors
+pub trait Luma {
+    /// The floating point type of the luma
+    type Scalar;
+
+    /// The weighted sum of the encoded channels, using the luma weights of the standard `S`,
+    /// such as [`Bt709`](../spaces/ycbcr/struct.Bt709.html) or [`Bt601`](../spaces/ycbcr/struct.Bt601.html).
+    fn luma<S: YCbCrStandard>(&self) -> Self::Scalar;
+}
+
+macro_rules! impl_luma {
+    ($($name:ident $(< $($p:ident: $bound:path),* >)*),*) => {
+        $(
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> Luma for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C>,
+            {
+                type Scalar = FloatChannel<C>;
+
+                #[inline]
+                fn luma<S: YCbCrStandard>(&self) -> FloatChannel<C> {
+                    let kr: FloatChannel<C> = math::cast(S::KR);
+                    let kb: FloatChannel<C> = math::cast(S::KB);
+                    let kg: FloatChannel<C> = math::cast(1.0 - S::KR - S::KB);
+
+                    kr * self.r.into_float() + kg * self.g.into_float() + kb * self.b.into_float()
+                }
+            }
+        )*
+    }
+}
+
+impl_luma! {
+    Srgb, DisplayP3, Rgb<P: Primaries, E: Encoding>
+}
@@ -0,0 +1,123 @@
+//! Just noticeable differences of chromaticity, for binning light sources.
+//!
+//! MacAdam measured the standard deviation of color matching around 25 chromaticities, giving ellipses in the
+//! CIE 1931 `xy` diagram. Chromaticity tolerances of lamps are specified in steps of those ellipses, or standard
+//! deviations of color matching (SDCM), where a step of 1 is barely noticeable and 3 to 7 steps are typical bins.
+//!
+//! [`macadam_steps`](fn.macadam_steps.html) measures a difference with the nearest of MacAdam's ellipses, and
+//! [`sdcm_steps`](fn.sdcm_steps.html) with the circles of CIE TN 001:2014, which approximate the ellipses in the
+//! more uniform CIE 1976 `u'v'` diagram and are the modern basis for binning. All chromaticities are `xy`.
+
+/// The size of one step of a MacAdam ellipse as a distance in the CIE 1976 `u'v'` diagram, from CIE TN 001:2014
+pub const SDCM_UV_PRIME: f64 = 0.0011;
+
+/// An ellipse of one standard deviation of color matching around a chromaticity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MacAdamEllipse {
+    /// The `xy` chromaticity at the center of the ellipse
+    pub center: [f64; 2],
+    /// The semi-major axis in `xy`
+    pub a: f64,
+    /// The semi-minor axis in `xy`
+    pub b: f64,
+    /// The angle of the major axis from the `x` axis, in degrees
+    pub theta: f64,
+}
+
+impl MacAdamEllipse {
+    /// The difference between two chromaticities in standard deviations of color matching, using the shape of
+    /// this ellipse regardless of where the chromaticities lie.
+    pub fn steps(&self, [x1, y1]: [f64; 2], [x2, y2]: [f64; 2]) -> f64 {
+        let (sin, cos) = self.theta.to_radians().sin_cos();
+
+        let (dx, dy) = (x2 - x1, y2 - y1);
+
+        // Rotate the difference onto the axes of the ellipse
+        let major = dx * cos + dy * sin;
+        let minor = dy * cos - dx * sin;
+
+        (major / self.a).hypot(minor / self.b)
+    }
+
+    /// Checks if a chromaticity lies within the ellipse of the given number of steps around the center.
+    #[inline]
+    pub fn contains(&self, xy: [f64; 2], steps: f64) -> bool {
+        self.steps(self.center, xy) <= steps
+    }
+}
+
+macro_rules! ellipses {
+    ($([$x:expr, $y:expr, $a:expr, $b:expr, $theta:expr],)*) => {
+        [$(MacAdamEllipse { center: [$x, $y], a: $a * 1e-3, b: $b * 1e-3, theta: $theta },)*]
+    }
+}
+
+/// The 25 ellipses of MacAdam (1942), as tabulated by Wyszecki and Stiles
+pub const MACADAM_1942: [MacAdamEllipse; 25] = ellipses![
+    [0.160, 0.057, 0.85, 0.35, 62.5],
+    [0.187, 0.118, 2.2, 0.55, 77.0],
+    [0.253, 0.125, 2.5, 0.5, 55.5],
+    [0.150, 0.680, 9.6, 2.3, 105.0],
+    [0.131, 0.521, 4.7, 2.0, 112.5],
+    [0.212, 0.550, 5.8, 2.3, 100.0],
+    [0.258, 0.450, 5.0, 2.0, 92.0],
+    [0.152, 0.365, 3.8, 1.9, 110.0],
+    [0.280, 0.385, 4.0, 1.5, 75.5],
+    [0.380, 0.498, 4.4, 1.2, 70.0],
+    [0.160, 0.200, 2.1, 0.95, 104.0],
+    [0.228, 0.250, 3.1, 0.9, 72.0],
+    [0.305, 0.323, 2.3, 0.9, 58.0],
+    [0.385, 0.393, 3.8, 1.6, 65.5],
+    [0.472, 0.399, 3.2, 1.4, 51.0],
+    [0.527, 0.350, 2.6, 1.3, 20.0],
+    [0.475, 0.300, 2.9, 1.1, 28.5],
+    [0.510, 0.236, 2.4, 1.2, 29.5],
+    [0.596, 0.283, 2.6, 1.3, 24.0],
+    [0.344, 0.284, 2.3, 0.9, 60.0],
+    [0.390, 0.237, 2.5, 1.0, 47.0],
+    [0.441, 0.198, 2.8, 0.95, 34.5],
+    [0.278, 0.223, 2.4, 0.55, 57.5],
+    [0.300, 0.163, 2.9, 0.6, 54.0],
+    [0.365, 0.153, 3.6, 0.95, 40.0],
+];
+
+/// The MacAdam ellipse whose center is nearest to a chromaticity
+pub fn nearest_macadam_ellipse([x, y]: [f64; 2]) -> &'static MacAdamEllipse {
+    let distance = |e: &MacAdamEllipse| (e.center[0] - x).hypot(e.center[1] - y);
+
+    MACADAM_1942.iter().fold(&MACADAM_1942[0], |nearest, e| if distance(e) < distance(nearest) { e } else { nearest })
+}
+
+/// The difference between two chromaticities in standard deviations of color matching, using the MacAdam ellipse
+/// nearest to their midpoint.
+///
+/// The ellipses were measured far apart, so this is coarse between them. Prefer [`sdcm_steps`](fn.sdcm_steps.html)
+/// for binning to current standards.
+pub fn macadam_steps(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let mid = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+
+    nearest_macadam_ellipse(mid).steps(a, b)
+}
+
+/// Converts `xy` to `u'v'` chromaticity.
+fn uv_prime([x, y]: [f64; 2]) -> [f64; 2] {
+    let d = -2.0 * x + 12.0 * y + 3.0;
+
+    [4.0 * x / d, 9.0 * y / d]
+}
+
+/// The difference between two chromaticities in steps of MacAdam ellipses, approximated as circles of
+/// [`SDCM_UV_PRIME`](constant.SDCM_UV_PRIME.html) in the CIE 1976 `u'v'` diagram as by CIE TN 001:2014
+pub fn sdcm_steps(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let [u1, v1] = uv_prime(a);
+    let [u2, v2] = uv_prime(b);
+
+    (u1 - u2).hypot(v1 - v2) / SDCM_UV_PRIME
+}
+
+/// Checks if two chromaticities are within the given number of steps of each other, by
+/// [`sdcm_steps`](fn.sdcm_steps.html).
+#[inline]
+pub fn within_sdcm(a: [f64; 2], b: [f64; 2], steps: f64) -> bool {
+    sdcm_steps(a, b) <= steps
+}
@@ -0,0 +1,94 @@
+//! Small fixed-size linear algebra helpers used by the color space conversions.
+//!
+//! Conversion matrices are specified as `f64` literals and cast to the floating point
+//! channel type of the color being converted.
+
+use num_traits::Float;
+
+pub type Vector3<T> = [T; 3];
+pub type Matrix3<T> = [[T; 3]; 3];
+
+/// The CIE ε constant, the cube of 6/29
+pub const CIE_EPSILON: f64 = 216.0 / 24389.0;
+
+/// The CIE κ constant, (29/3)³
+pub const CIE_KAPPA: f64 = 24389.0 / 27.0;
+
+/// Casts an `f64` constant to the given floating point type.
+#[inline]
+pub fn cast<T: Float>(value: f64) -> T {
+    T::from(value).unwrap()
+}
+
+/// Casts a matrix of `f64` constants to the given floating point type.
+#[inline]
+pub fn matrix<T: Float>(m: &Matrix3<f64>) -> Matrix3<T> {
+    let mut res = [[T::zero(); 3]; 3];
+
+    for i in 0..3 {
+        for j in 0..3 {
+            res[i][j] = cast(m[i][j]);
+        }
+    }
+
+    res
+}
+
+/// Wraps a hue in degrees into the range `[0, 360)`.
+#[inline]
+pub fn normalize_hue<T: Float>(hue: T) -> T {
+    let full: T = cast(360.0);
+
+    let hue = hue % full;
+
+    if hue < T::zero() { hue + full } else { hue }
+}
+
+#[inline]
+pub fn mul_vec<T: Float>(m: &Matrix3<T>, v: Vector3<T>) -> Vector3<T> {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+pub fn mul<T: Float>(a: &Matrix3<T>, b: &Matrix3<T>) -> Matrix3<T> {
+    let mut res = [[T::zero(); 3]; 3];
+
+    for i in 0..3 {
+        for j in 0..3 {
+            res[i][j] = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+
+    res
+}
+
+/// Inverts a 3x3 matrix using the adjugate. The matrices used for color conversions are
+/// always well-conditioned, so no singularity check is performed.
+pub fn invert<T: Float>(m: &Matrix3<T>) -> Matrix3<T> {
+    let c00 = m[1][1] * m[2][2] - m[1][2] * m[2][1];
+    let c01 = m[1][2] * m[2][0] - m[1][0] * m[2][2];
+    let c02 = m[1][0] * m[2][1] - m[1][1] * m[2][0];
+
+    let det = m[0][0] * c00 + m[0][1] * c01 + m[0][2] * c02;
+
+    [
+        [
+            c00 / det,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) / det,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) / det,
+        ],
+        [
+            c01 / det,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) / det,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) / det,
+        ],
+        [
+            c02 / det,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) / det,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) / det,
+        ],
+    ]
+}
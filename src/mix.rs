@@ -0,0 +1,113 @@
+//! Linear interpolation of colors.
+
+use num_traits::Float;
+
+use channels::*;
+use color::{Color, ColorChannel, ColorChannels};
+use alpha::Alpha;
+use white_point::WhitePoint;
+use hue::Deg;
+
+use spaces::all::*;
+use gamma::Linear;
+
+use spaces::generic_rgb::Rgb;
+use spaces::primaries::Primaries;
+use spaces::ycbcr::{YCbCr, YCbCrStandard, YCbCrRange};
+use spaces::ypbpr::YPbPr;
+use spaces::lms::{Lms, ConeResponse};
+use spaces::hsp::Hsp;
+
+/// Linear interpolation between two colors of the same space
+pub trait Mix {
+    /// The type of the interpolation factor
+    type Scalar: Float;
+
+    /// Interpolates between `self` and `other`, where `factor` of 0.0 is `self` and 1.0 is `other`.
+    ///
+    /// Channels are interpolated linearly, except hue angles, which are interpolated along the shorter arc.
+    fn mix(self, other: Self, factor: Self::Scalar) -> Self;
+}
+
+/// Interpolates between two hue angles in degrees along the shorter arc.
+#[inline]
+pub(crate) fn mix_hue<T: Float>(a: T, b: T, factor: T) -> T {
+    let a = Deg::new(a);
+
+    (a + a.difference(Deg::new(b)) * factor).degrees()
+}
+
+/// Interpolates the floating point channels of two colors, treating the channel at `hue`, if any, as a hue angle.
+fn mix_channels<T: Float>(a: &mut [T], b: &[T], factor: T, hue: Option<usize>) {
+    for (i, (x, &y)) in a.iter_mut().zip(b).enumerate() {
+        *x = if hue == Some(i) { mix_hue(*x, y, factor) } else { *x + (y - *x) * factor };
+    }
+}
+
+macro_rules! impl_mix {
+    ($(
+        $name:ident $(< $($p:ident: $bound:path),* >)* $([hue = $hue:expr])*
+    ),*) => {
+        $(
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> Mix for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+            {
+                type Scalar = FloatChannel<C>;
+
+                #[inline]
+                fn mix(self, other: Self, factor: FloatChannel<C>) -> Self {
+                    let hue: Option<usize> = None $(.or(Some($hue)))*;
+
+                    let (mut a, b) = (self.into_float(), other.into_float());
+
+                    mix_channels(a.channels_mut().as_mut_slice(), b.channels().as_slice(), factor, hue);
+
+                    $name::from_float(a)
+                }
+            }
+        )*
+    }
+}
+
+impl_mix! {
+    Srgb, LinearSrgb, ScRgb, DisplayP3, Aces2065, AcesCg,
+    Hsl [hue = 0], Hsv [hue = 0], Hwb [hue = 0], Hsi [hue = 0], Hsp<S: YCbCrStandard> [hue = 0],
+    Xyz, Yxy, Uv1960, UvPrime,
+    Lab, Lch [hue = 2], Luv, Lchuv [hue = 2],
+    Oklab, Oklch [hue = 2], Okhsl [hue = 0], Okhsv [hue = 0],
+    Cam16Ucs, Jzazbz, Jzczhz [hue = 2], Din99, Din99d, Ipt, OsaUcs, Xyb, Lms<M: ConeResponse>,
+    YCbCr<S: YCbCrStandard, R: YCbCrRange>, YPbPr<S: YCbCrStandard>, YDbDr, YCoCg, YCoCgR,
+    Cmy, Cmyk
+}
+
+// Like the other arithmetic on generic RGB, interpolation is only implemented for linear light,
+// so encoded values must be decoded with `into_linear` first.
+impl<C: Channel, Wp, P: Primaries> Mix for Rgb<C, Wp, P, Linear>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    type Scalar = FloatChannel<C>;
+
+    #[inline]
+    fn mix(self, other: Self, factor: FloatChannel<C>) -> Self {
+        let (mut a, b) = (self.into_float(), other.into_float());
+
+        mix_channels(a.channels_mut().as_mut_slice(), b.channels().as_slice(), factor, None);
+
+        Rgb::from_float(a)
+    }
+}
+
+impl<C: Color> Mix for Alpha<C>
+where
+    ColorChannels<C>: AlphaChannels<ColorChannel<C>>,
+{
+    type Scalar = FloatChannel<ColorChannel<C>>;
+
+    /// Interpolates by way of premultiplied alpha. Hue channels are interpolated linearly.
+    #[inline]
+    fn mix(self, other: Self, factor: Self::Scalar) -> Self {
+        Alpha::mix(self, other, factor)
+    }
+}
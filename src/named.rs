@@ -0,0 +1,208 @@
+//! The named colors of CSS Color Module Level 4.
+//!
+//! The 148 named colors of CSS, which extend the X11 colors with `rebeccapurple`, are available as constants
+//! of 8-bit sRGB channels, and by name with [`from_name`](fn.from_name.html). Several colors have two names,
+//! such as `gray` and `grey`, or `aqua` and `cyan`.
+
+use channels::*;
+use white_point::WhitePoint;
+use math;
+
+use spaces::srgb::Srgb;
+
+macro_rules! named_colors {
+    ($($constant:ident = $name:tt => $r:expr, $g:expr, $b:expr;)*) => {
+        $(
+            #[doc = concat!("`", $name, "`")]
+            pub const $constant: [u8; 3] = [$r, $g, $b];
+        )*
+
+        /// Every named color with its name, sorted by name
+        pub const NAMED_COLORS: [(&str, [u8; 3]); 148] = [$(($name, $constant)),*];
+    }
+}
+
+named_colors! {
+    ALICEBLUE = "aliceblue" => 0xf0, 0xf8, 0xff;
+    ANTIQUEWHITE = "antiquewhite" => 0xfa, 0xeb, 0xd7;
+    AQUA = "aqua" => 0x00, 0xff, 0xff;
+    AQUAMARINE = "aquamarine" => 0x7f, 0xff, 0xd4;
+    AZURE = "azure" => 0xf0, 0xff, 0xff;
+    BEIGE = "beige" => 0xf5, 0xf5, 0xdc;
+    BISQUE = "bisque" => 0xff, 0xe4, 0xc4;
+    BLACK = "black" => 0x00, 0x00, 0x00;
+    BLANCHEDALMOND = "blanchedalmond" => 0xff, 0xeb, 0xcd;
+    BLUE = "blue" => 0x00, 0x00, 0xff;
+    BLUEVIOLET = "blueviolet" => 0x8a, 0x2b, 0xe2;
+    BROWN = "brown" => 0xa5, 0x2a, 0x2a;
+    BURLYWOOD = "burlywood" => 0xde, 0xb8, 0x87;
+    CADETBLUE = "cadetblue" => 0x5f, 0x9e, 0xa0;
+    CHARTREUSE = "chartreuse" => 0x7f, 0xff, 0x00;
+    CHOCOLATE = "chocolate" => 0xd2, 0x69, 0x1e;
+    CORAL = "coral" => 0xff, 0x7f, 0x50;
+    CORNFLOWERBLUE = "cornflowerblue" => 0x64, 0x95, 0xed;
+    CORNSILK = "cornsilk" => 0xff, 0xf8, 0xdc;
+    CRIMSON = "crimson" => 0xdc, 0x14, 0x3c;
+    CYAN = "cyan" => 0x00, 0xff, 0xff;
+    DARKBLUE = "darkblue" => 0x00, 0x00, 0x8b;
+    DARKCYAN = "darkcyan" => 0x00, 0x8b, 0x8b;
+    DARKGOLDENROD = "darkgoldenrod" => 0xb8, 0x86, 0x0b;
+    DARKGRAY = "darkgray" => 0xa9, 0xa9, 0xa9;
+    DARKGREEN = "darkgreen" => 0x00, 0x64, 0x00;
+    DARKGREY = "darkgrey" => 0xa9, 0xa9, 0xa9;
+    DARKKHAKI = "darkkhaki" => 0xbd, 0xb7, 0x6b;
+    DARKMAGENTA = "darkmagenta" => 0x8b, 0x00, 0x8b;
+    DARKOLIVEGREEN = "darkolivegreen" => 0x55, 0x6b, 0x2f;
+    DARKORANGE = "darkorange" => 0xff, 0x8c, 0x00;
+    DARKORCHID = "darkorchid" => 0x99, 0x32, 0xcc;
+    DARKRED = "darkred" => 0x8b, 0x00, 0x00;
+    DARKSALMON = "darksalmon" => 0xe9, 0x96, 0x7a;
+    DARKSEAGREEN = "darkseagreen" => 0x8f, 0xbc, 0x8f;
+    DARKSLATEBLUE = "darkslateblue" => 0x48, 0x3d, 0x8b;
+    DARKSLATEGRAY = "darkslategray" => 0x2f, 0x4f, 0x4f;
+    DARKSLATEGREY = "darkslategrey" => 0x2f, 0x4f, 0x4f;
+    DARKTURQUOISE = "darkturquoise" => 0x00, 0xce, 0xd1;
+    DARKVIOLET = "darkviolet" => 0x94, 0x00, 0xd3;
+    DEEPPINK = "deeppink" => 0xff, 0x14, 0x93;
+    DEEPSKYBLUE = "deepskyblue" => 0x00, 0xbf, 0xff;
+    DIMGRAY = "dimgray" => 0x69, 0x69, 0x69;
+    DIMGREY = "dimgrey" => 0x69, 0x69, 0x69;
+    DODGERBLUE = "dodgerblue" => 0x1e, 0x90, 0xff;
+    FIREBRICK = "firebrick" => 0xb2, 0x22, 0x22;
+    FLORALWHITE = "floralwhite" => 0xff, 0xfa, 0xf0;
+    FORESTGREEN = "forestgreen" => 0x22, 0x8b, 0x22;
+    FUCHSIA = "fuchsia" => 0xff, 0x00, 0xff;
+    GAINSBORO = "gainsboro" => 0xdc, 0xdc, 0xdc;
+    GHOSTWHITE = "ghostwhite" => 0xf8, 0xf8, 0xff;
+    GOLD = "gold" => 0xff, 0xd7, 0x00;
+    GOLDENROD = "goldenrod" => 0xda, 0xa5, 0x20;
+    GRAY = "gray" => 0x80, 0x80, 0x80;
+    GREEN = "green" => 0x00, 0x80, 0x00;
+    GREENYELLOW = "greenyellow" => 0xad, 0xff, 0x2f;
+    GREY = "grey" => 0x80, 0x80, 0x80;
+    HONEYDEW = "honeydew" => 0xf0, 0xff, 0xf0;
+    HOTPINK = "hotpink" => 0xff, 0x69, 0xb4;
+    INDIANRED = "indianred" => 0xcd, 0x5c, 0x5c;
+    INDIGO = "indigo" => 0x4b, 0x00, 0x82;
+    IVORY = "ivory" => 0xff, 0xff, 0xf0;
+    KHAKI = "khaki" => 0xf0, 0xe6, 0x8c;
+    LAVENDER = "lavender" => 0xe6, 0xe6, 0xfa;
+    LAVENDERBLUSH = "lavenderblush" => 0xff, 0xf0, 0xf5;
+    LAWNGREEN = "lawngreen" => 0x7c, 0xfc, 0x00;
+    LEMONCHIFFON = "lemonchiffon" => 0xff, 0xfa, 0xcd;
+    LIGHTBLUE = "lightblue" => 0xad, 0xd8, 0xe6;
+    LIGHTCORAL = "lightcoral" => 0xf0, 0x80, 0x80;
+    LIGHTCYAN = "lightcyan" => 0xe0, 0xff, 0xff;
+    LIGHTGOLDENRODYELLOW = "lightgoldenrodyellow" => 0xfa, 0xfa, 0xd2;
+    LIGHTGRAY = "lightgray" => 0xd3, 0xd3, 0xd3;
+    LIGHTGREEN = "lightgreen" => 0x90, 0xee, 0x90;
+    LIGHTGREY = "lightgrey" => 0xd3, 0xd3, 0xd3;
+    LIGHTPINK = "lightpink" => 0xff, 0xb6, 0xc1;
+    LIGHTSALMON = "lightsalmon" => 0xff, 0xa0, 0x7a;
+    LIGHTSEAGREEN = "lightseagreen" => 0x20, 0xb2, 0xaa;
+    LIGHTSKYBLUE = "lightskyblue" => 0x87, 0xce, 0xfa;
+    LIGHTSLATEGRAY = "lightslategray" => 0x77, 0x88, 0x99;
+    LIGHTSLATEGREY = "lightslategrey" => 0x77, 0x88, 0x99;
+    LIGHTSTEELBLUE = "lightsteelblue" => 0xb0, 0xc4, 0xde;
+    LIGHTYELLOW = "lightyellow" => 0xff, 0xff, 0xe0;
+    LIME = "lime" => 0x00, 0xff, 0x00;
+    LIMEGREEN = "limegreen" => 0x32, 0xcd, 0x32;
+    LINEN = "linen" => 0xfa, 0xf0, 0xe6;
+    MAGENTA = "magenta" => 0xff, 0x00, 0xff;
+    MAROON = "maroon" => 0x80, 0x00, 0x00;
+    MEDIUMAQUAMARINE = "mediumaquamarine" => 0x66, 0xcd, 0xaa;
+    MEDIUMBLUE = "mediumblue" => 0x00, 0x00, 0xcd;
+    MEDIUMORCHID = "mediumorchid" => 0xba, 0x55, 0xd3;
+    MEDIUMPURPLE = "mediumpurple" => 0x93, 0x70, 0xdb;
+    MEDIUMSEAGREEN = "mediumseagreen" => 0x3c, 0xb3, 0x71;
+    MEDIUMSLATEBLUE = "mediumslateblue" => 0x7b, 0x68, 0xee;
+    MEDIUMSPRINGGREEN = "mediumspringgreen" => 0x00, 0xfa, 0x9a;
+    MEDIUMTURQUOISE = "mediumturquoise" => 0x48, 0xd1, 0xcc;
+    MEDIUMVIOLETRED = "mediumvioletred" => 0xc7, 0x15, 0x85;
+    MIDNIGHTBLUE = "midnightblue" => 0x19, 0x19, 0x70;
+    MINTCREAM = "mintcream" => 0xf5, 0xff, 0xfa;
+    MISTYROSE = "mistyrose" => 0xff, 0xe4, 0xe1;
+    MOCCASIN = "moccasin" => 0xff, 0xe4, 0xb5;
+    NAVAJOWHITE = "navajowhite" => 0xff, 0xde, 0xad;
+    NAVY = "navy" => 0x00, 0x00, 0x80;
+    OLDLACE = "oldlace" => 0xfd, 0xf5, 0xe6;
+    OLIVE = "olive" => 0x80, 0x80, 0x00;
+    OLIVEDRAB = "olivedrab" => 0x6b, 0x8e, 0x23;
+    ORANGE = "orange" => 0xff, 0xa5, 0x00;
+    ORANGERED = "orangered" => 0xff, 0x45, 0x00;
+    ORCHID = "orchid" => 0xda, 0x70, 0xd6;
+    PALEGOLDENROD = "palegoldenrod" => 0xee, 0xe8, 0xaa;
+    PALEGREEN = "palegreen" => 0x98, 0xfb, 0x98;
+    PALETURQUOISE = "paleturquoise" => 0xaf, 0xee, 0xee;
+    PALEVIOLETRED = "palevioletred" => 0xdb, 0x70, 0x93;
+    PAPAYAWHIP = "papayawhip" => 0xff, 0xef, 0xd5;
+    PEACHPUFF = "peachpuff" => 0xff, 0xda, 0xb9;
+    PERU = "peru" => 0xcd, 0x85, 0x3f;
+    PINK = "pink" => 0xff, 0xc0, 0xcb;
+    PLUM = "plum" => 0xdd, 0xa0, 0xdd;
+    POWDERBLUE = "powderblue" => 0xb0, 0xe0, 0xe6;
+    PURPLE = "purple" => 0x80, 0x00, 0x80;
+    REBECCAPURPLE = "rebeccapurple" => 0x66, 0x33, 0x99;
+    RED = "red" => 0xff, 0x00, 0x00;
+    ROSYBROWN = "rosybrown" => 0xbc, 0x8f, 0x8f;
+    ROYALBLUE = "royalblue" => 0x41, 0x69, 0xe1;
+    SADDLEBROWN = "saddlebrown" => 0x8b, 0x45, 0x13;
+    SALMON = "salmon" => 0xfa, 0x80, 0x72;
+    SANDYBROWN = "sandybrown" => 0xf4, 0xa4, 0x60;
+    SEAGREEN = "seagreen" => 0x2e, 0x8b, 0x57;
+    SEASHELL = "seashell" => 0xff, 0xf5, 0xee;
+    SIENNA = "sienna" => 0xa0, 0x52, 0x2d;
+    SILVER = "silver" => 0xc0, 0xc0, 0xc0;
+    SKYBLUE = "skyblue" => 0x87, 0xce, 0xeb;
+    SLATEBLUE = "slateblue" => 0x6a, 0x5a, 0xcd;
+    SLATEGRAY = "slategray" => 0x70, 0x80, 0x90;
+    SLATEGREY = "slategrey" => 0x70, 0x80, 0x90;
+    SNOW = "snow" => 0xff, 0xfa, 0xfa;
+    SPRINGGREEN = "springgreen" => 0x00, 0xff, 0x7f;
+    STEELBLUE = "steelblue" => 0x46, 0x82, 0xb4;
+    TAN = "tan" => 0xd2, 0xb4, 0x8c;
+    TEAL = "teal" => 0x00, 0x80, 0x80;
+    THISTLE = "thistle" => 0xd8, 0xbf, 0xd8;
+    TOMATO = "tomato" => 0xff, 0x63, 0x47;
+    TURQUOISE = "turquoise" => 0x40, 0xe0, 0xd0;
+    VIOLET = "violet" => 0xee, 0x82, 0xee;
+    WHEAT = "wheat" => 0xf5, 0xde, 0xb3;
+    WHITE = "white" => 0xff, 0xff, 0xff;
+    WHITESMOKE = "whitesmoke" => 0xf5, 0xf5, 0xf5;
+    YELLOW = "yellow" => 0xff, 0xff, 0x00;
+    YELLOWGREEN = "yellowgreen" => 0x9a, 0xcd, 0x32;
+}
+
+/// Converts 8-bit channels into any channel type.
+#[inline]
+fn from_bytes<C: Channel, Wp>([r, g, b]: [u8; 3]) -> Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    let f = |v: u8| C::from_float(math::cast(v as f64 / 255.0));
+
+    Srgb::with_wp(f(r), f(g), f(b))
+}
+
+/// Looks up a named color, ignoring ASCII case.
+pub fn from_name<C: Channel, Wp>(name: &str) -> Option<Srgb<C, Wp>>
+where
+    Wp: WhitePoint<C>,
+{
+    let name = name.to_ascii_lowercase();
+
+    NAMED_COLORS.binary_search_by_key(&name.as_str(), |&(name, _)| name).ok().map(|i| from_bytes(NAMED_COLORS[i].1))
+}
+
+/// The name of a color that exactly matches a named color, or `None` if none does.
+///
+/// Of colors with two names, the first alphabetically is returned, such as `aqua` rather than `cyan` and
+/// `darkgray` rather than `darkgrey`.
+pub fn to_name<Wp>(color: &Srgb<u8, Wp>) -> Option<&'static str>
+where
+    Wp: WhitePoint<u8>,
+{
+    let rgb = [color.r, color.g, color.b];
+
+    NAMED_COLORS.iter().find(|&&(_, named)| named == rgb).map(|&(name, _)| name)
+}
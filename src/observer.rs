@@ -0,0 +1,33 @@
+//! Defines the CIE standard colorimetric observers.
+//!
+//! The color matching functions of the standard observers differ with the field of view of the stimulus,
+//! so XYZ values, and the white points and colors derived from them, measured with one observer cannot
+//! be compared with those measured with another.
+//!
+//! Each [`WhitePoint`](../white_point/trait.WhitePoint.html) is defined for a specific observer,
+//! and colors take their observer from their white point, so adapting a color between white points of
+//! different observers does not compile.
+
+use std::fmt::Debug;
+
+/// A standard colorimetric observer
+pub trait Observer: Copy + Debug + Default {
+    /// The field of view of the observer, in degrees
+    const FIELD_OF_VIEW: f64;
+}
+
+/// The CIE 1931 2° Standard Observer, for stimuli of up to about 4° of the field of view
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TwoDegree;
+
+/// The CIE 1964 10° Supplementary Standard Observer, for stimuli larger than about 4° of the field of view
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TenDegree;
+
+impl Observer for TwoDegree {
+    const FIELD_OF_VIEW: f64 = 2.0;
+}
+
+impl Observer for TenDegree {
+    const FIELD_OF_VIEW: f64 = 10.0;
+}
@@ -0,0 +1,176 @@
+//! Nearest color lookup against a fixed palette.
+//!
+//! A [`Palette`](struct.Palette.html) indexes its colors in a k-d tree over their channels, so that mapping
+//! each pixel of an image to its nearest palette color does not compare it against every color of the palette.
+//!
+//! Lookups are exact for any [`ColorDistance`](../difference/trait.ColorDistance.html), but the tree can only
+//! skip colors for metrics that bound distances by the difference of single channels. The
+//! [`Euclidean`](../difference/struct.Euclidean.html) metric does, so for the fastest lookups store the palette
+//! in a perceptually uniform space such as `Oklab` and use the Euclidean distance there. Other metrics fall
+//! back to comparing against every color.
+
+use std::cmp::Ordering;
+
+use num_traits::Zero;
+
+use channels::*;
+use color::{Color, ColorChannel, ColorChannels};
+use difference::{ColorDistance, Euclidean};
+
+/// A set of colors indexed for nearest color lookups with the metric `M`
+#[derive(Debug, Clone)]
+pub struct Palette<C, M = Euclidean> {
+    colors: Vec<C>,
+    metric: M,
+    /// Indices of `colors` forming an implicit k-d tree, where the median of each range is the node splitting it
+    tree: Vec<usize>,
+    dimensions: usize,
+}
+
+/// The channel of a color at the given index, as a float
+#[inline]
+fn channel<C>(color: &C, axis: usize) -> FloatChannel<ColorChannel<C>>
+where
+    C: Color,
+    ColorChannels<C>: ChannelSlice<ColorChannel<C>>,
+{
+    color.channels().as_slice()[axis].into_float()
+}
+
+impl<C, M> Palette<C, M>
+where
+    C: Color,
+    ColorChannels<C>: ChannelSlice<ColorChannel<C>>,
+    M: ColorDistance<C, Scalar = FloatChannel<ColorChannel<C>>>,
+{
+    /// Creates a palette using the default value of the metric.
+    #[inline]
+    pub fn new(colors: Vec<C>) -> Palette<C, M>
+    where
+        M: Default,
+    {
+        Palette::with_metric(colors, M::default())
+    }
+
+    /// Creates a palette using the given metric.
+    pub fn with_metric(colors: Vec<C>, metric: M) -> Palette<C, M> {
+        let dimensions = colors.first().map_or(0, |c| c.channels().as_slice().len());
+
+        let mut tree: Vec<usize> = (0..colors.len()).collect();
+
+        build(&colors, &mut tree, 0, dimensions);
+
+        Palette { colors, metric, tree, dimensions }
+    }
+
+    /// The colors of the palette, in the order they were given
+    #[inline]
+    pub fn colors(&self) -> &[C] {
+        &self.colors
+    }
+
+    /// The metric of the palette
+    #[inline]
+    pub fn metric(&self) -> &M {
+        &self.metric
+    }
+
+    /// The number of colors in the palette
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Checks if the palette has no colors.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// The index of the palette color nearest to `color`, or `None` if the palette is empty.
+    ///
+    /// Of equally near colors, any one may be returned.
+    pub fn nearest(&self, color: &C) -> Option<usize> {
+        self.nearest_n(color, 1).first().cloned()
+    }
+
+    /// The indices of the `n` palette colors nearest to `color`, nearest first.
+    pub fn nearest_n(&self, color: &C, n: usize) -> Vec<usize> {
+        let mut best = Vec::with_capacity(n.min(self.colors.len()) + 1);
+
+        if n > 0 {
+            self.search(color, &self.tree, 0, n, &mut best);
+        }
+
+        best.into_iter().map(|(_, i)| i).collect()
+    }
+
+    /// Replaces every color of a buffer with its nearest palette color.
+    pub fn remap(&self, colors: &mut [C])
+    where
+        C: Copy,
+    {
+        for color in colors {
+            if let Some(i) = self.nearest(color) {
+                *color = self.colors[i];
+            }
+        }
+    }
+
+    /// Searches a subtree, keeping the `n` nearest colors found in `best`, sorted by distance.
+    fn search(&self, color: &C, tree: &[usize], depth: usize, n: usize, best: &mut Vec<(M::Scalar, usize)>) {
+        if tree.is_empty() {
+            return;
+        }
+
+        let mid = tree.len() / 2;
+        let index = tree[mid];
+
+        let d = self.metric.distance(color, &self.colors[index]);
+
+        if best.len() < n || d < best[best.len() - 1].0 {
+            let at = best.iter().position(|&(b, _)| d < b).unwrap_or(best.len());
+
+            best.insert(at, (d, index));
+            best.truncate(n);
+        }
+
+        let axis = depth % self.dimensions;
+        let diff = channel(color, axis) - channel(&self.colors[index], axis);
+
+        let (near, far) = if diff < M::Scalar::zero() {
+            (&tree[..mid], &tree[mid + 1..])
+        } else {
+            (&tree[mid + 1..], &tree[..mid])
+        };
+
+        self.search(color, near, depth + 1, n, best);
+
+        if best.len() < n || self.metric.axis_bound(axis, diff) < best[best.len() - 1].0 {
+            self.search(color, far, depth + 1, n, best);
+        }
+    }
+}
+
+/// Arranges `tree` into an implicit k-d tree, splitting each range at its median along the axis for its depth.
+fn build<C>(colors: &[C], tree: &mut [usize], depth: usize, dimensions: usize)
+where
+    C: Color,
+    ColorChannels<C>: ChannelSlice<ColorChannel<C>>,
+{
+    if tree.len() <= 1 {
+        return;
+    }
+
+    let axis = depth % dimensions;
+    let mid = tree.len() / 2;
+
+    tree.select_nth_unstable_by(mid, |&a, &b| {
+        channel(&colors[a], axis).partial_cmp(&channel(&colors[b], axis)).unwrap_or(Ordering::Equal)
+    });
+
+    let (left, right) = tree.split_at_mut(mid);
+
+    build(colors, left, depth + 1, dimensions);
+    build(colors, &mut right[1..], depth + 1, dimensions);
+}
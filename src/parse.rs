@@ -0,0 +1,114 @@
+//! Errors parsing colors from text.
+//!
+//! Hex colors, named colors and CSS color functions all fail with a [`ParseColorError`](struct.ParseColorError.html),
+//! which records what went wrong, the byte offset in the input where it went wrong, and what was expected there,
+//! so that applications can point users at the mistake in a color they entered.
+//!
+//! ```ignore
+//! let err = CssColor::<f32>::parse("rgb(255 0 0deg)").unwrap_err();
+//!
+//! assert_eq!(err.kind(), ParseErrorKind::InvalidValue);
+//! assert_eq!(err.offset(), 10);
+//! assert_eq!(err.to_string(), "invalid value at byte 10, expected a number or percentage");
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// The kind of a [`ParseColorError`](struct.ParseColorError.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// The input is empty or only whitespace
+    Empty,
+    /// A character of a hex color that is not a hexadecimal digit
+    InvalidDigit(char),
+    /// The number of digits of a hex color, which is not one of the accepted lengths
+    InvalidLength(usize),
+    /// A name that is not one of the named colors
+    UnknownName,
+    /// A color function that is not one of the supported functions
+    UnknownFunction,
+    /// A color space of a `color()` function that is not one of the supported spaces
+    UnknownColorSpace,
+    /// A color function with the wrong number of arguments
+    ArgumentCount,
+    /// An argument that is not a number, percentage, angle or `none` where one is allowed
+    InvalidValue,
+    /// Any other unexpected text, such as a missing parenthesis or misplaced separator
+    InvalidSyntax,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            ParseErrorKind::Empty => f.write_str("empty color"),
+            ParseErrorKind::InvalidDigit(c) => write!(f, "invalid hex digit {:?}", c),
+            ParseErrorKind::InvalidLength(len) => write!(f, "invalid hex color length of {} digits", len),
+            ParseErrorKind::UnknownName => f.write_str("unknown color name"),
+            ParseErrorKind::UnknownFunction => f.write_str("unknown color function"),
+            ParseErrorKind::UnknownColorSpace => f.write_str("unknown color space"),
+            ParseErrorKind::ArgumentCount => f.write_str("wrong number of arguments"),
+            ParseErrorKind::InvalidValue => f.write_str("invalid value"),
+            ParseErrorKind::InvalidSyntax => f.write_str("invalid syntax"),
+        }
+    }
+}
+
+/// An error parsing a color from text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseColorError {
+    kind: ParseErrorKind,
+    offset: usize,
+    expected: Option<&'static str>,
+}
+
+impl ParseColorError {
+    #[inline]
+    pub(crate) fn new(kind: ParseErrorKind, offset: usize) -> ParseColorError {
+        ParseColorError { kind, offset, expected: None }
+    }
+
+    /// Sets the description of what was expected at the offset.
+    #[inline]
+    pub(crate) fn expected(self, expected: &'static str) -> ParseColorError {
+        ParseColorError { expected: Some(expected), ..self }
+    }
+
+    /// Moves the offset forward, for errors from parsing a part of a larger input.
+    #[inline]
+    pub(crate) fn shift(self, by: usize) -> ParseColorError {
+        ParseColorError { offset: self.offset + by, ..self }
+    }
+
+    /// What went wrong
+    #[inline]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the input where it went wrong, which is the length of the input if it ended too early
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// A description of what was expected at the offset, such as `a hex digit` or `` `)` ``, if known
+    #[inline]
+    pub fn expected_token(&self) -> Option<&'static str> {
+        self.expected
+    }
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)?;
+
+        if let Some(expected) = self.expected {
+            write!(f, ", expected {}", expected)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseColorError {}
@@ -0,0 +1,102 @@
+//! `Pod` and `Zeroable` implementations from bytemuck, enabled by the `bytemuck` feature.
+//!
+//! Every color type, its component struct and its `Alpha` and `PreAlpha` are `#[repr(C)]` structs of nothing but
+//! channels, so with channels that are `Pod` they are plain data too, and buffers of pixels can be cast to and from
+//! bytes without copying, such as for uploading textures to the GPU or reading and writing raw image files:
+//!
+//! ```ignore
+//! let pixels: Vec<Alpha<Srgb<u8>>> = ...;
+//! let bytes: &[u8] = bytemuck::cast_slice(&pixels);
+//!
+//! let floats: &[f32] = ...;
+//! let colors: &[Oklab] = bytemuck::cast_slice(floats);
+//! ```
+//!
+//! The white point and other type parameters are zero-sized markers, so they only need to be `'static`.
+
+use bytemuck::{Pod, Zeroable};
+use generic_array::ArrayLength;
+
+use channels::*;
+use color::{Color, ColorChannel};
+use alpha::{Alpha, PreAlpha};
+use hue::{Deg, Rad};
+
+use spaces::*;
+use spaces::generic::GenericColor;
+
+macro_rules! impl_pod {
+    ($($module:ident :: $name:ident $(< $($p:ident),* >)* => $component:ident),*) => {
+        $(
+            unsafe impl<C: Channel, Wp $($(, $p)*)*> Zeroable for $module::$name<C, Wp $($(, $p)*)*>
+            where
+                C: Zeroable,
+            {}
+
+            unsafe impl<C: Channel, Wp: 'static $($(, $p: 'static)*)*> Pod for $module::$name<C, Wp $($(, $p)*)*>
+            where
+                C: Pod,
+            {}
+
+            unsafe impl<C: Zeroable> Zeroable for $module::components::$component<C> {}
+
+            unsafe impl<C: Pod> Pod for $module::components::$component<C> {}
+        )*
+    }
+}
+
+impl_pod! {
+    srgb::Srgb => RGB, linear_srgb::LinearSrgb => RGB, scrgb::ScRgb => RGB, generic_rgb::Rgb<P, E> => RGB,
+    display_p3::DisplayP3 => RGB, aces::Aces2065 => RGB, aces_cg::AcesCg => RGB,
+    hsl::Hsl => HSL, hsv::Hsv => HSV, hwb::Hwb => HWB, hsi::Hsi => HSI, hsp::Hsp<S> => HSP,
+    xyz::Xyz => XYZ, yxy::Yxy => YXY, uv1960::Uv1960 => UV1960, uv_prime::UvPrime => UVPRIME,
+    lab::Lab => LAB, lch::Lch => LCH, luv::Luv => LUV, lchuv::Lchuv => LCHUV,
+    oklab::Oklab => OKLAB, oklch::Oklch => OKLCH, okhsl::Okhsl => OKHSL, okhsv::Okhsv => OKHSV,
+    cam16_ucs::Cam16Ucs => CAM16UCS, jzazbz::Jzazbz => JZAZBZ, jzczhz::Jzczhz => JZCZHZ,
+    din99::Din99 => DIN99, din99d::Din99d => DIN99D, ipt::Ipt => IPT, osa_ucs::OsaUcs => OSAUCS, xyb::Xyb => XYB,
+    lms::Lms<M> => LMS, ycbcr::YCbCr<S, R> => YCBCR, ypbpr::YPbPr<S> => YPBPR,
+    ydbdr::YDbDr => YDBDR, ycocg::YCoCg => YCOCG, ycocg_r::YCoCgR => YCOCGR,
+    cmy::Cmy => CMY, cmyk::Cmyk => CMYK
+}
+
+// Angles are `#[repr(transparent)]`, so hue components have the layout of their channels.
+unsafe impl<T: Zeroable> Zeroable for Deg<T> {}
+
+unsafe impl<T: Pod> Pod for Deg<T> {}
+
+unsafe impl<T: Zeroable> Zeroable for Rad<T> {}
+
+unsafe impl<T: Pod> Pod for Rad<T> {}
+
+unsafe impl<C: Channel, N: ArrayLength<C>, Wp> Zeroable for GenericColor<C, N, Wp>
+where
+    C: Zeroable,
+{}
+
+// The array type of every length is a `#[repr(C)]` nesting of `C`, so it has no padding.
+unsafe impl<C: Channel, N: ArrayLength<C> + 'static, Wp: 'static> Pod for GenericColor<C, N, Wp>
+where
+    C: Pod,
+    N::ArrayType: Copy,
+{}
+
+// The alpha channel has the same type as the color channels, so it follows them without padding.
+unsafe impl<C: Color + Zeroable> Zeroable for Alpha<C>
+where
+    ColorChannel<C>: Zeroable,
+{}
+
+unsafe impl<C: Color + Pod> Pod for Alpha<C>
+where
+    ColorChannel<C>: Pod,
+{}
+
+unsafe impl<C: Color + Zeroable> Zeroable for PreAlpha<C>
+where
+    ColorChannel<C>: Zeroable,
+{}
+
+unsafe impl<C: Color + Pod> Pod for PreAlpha<C>
+where
+    ColorChannel<C>: Pod,
+{}
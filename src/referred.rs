@@ -0,0 +1,210 @@
+//! Scene-referred and display-referred color data.
+//!
+//! Scene-referred values describe light in the scene, relative to some exposure, and are unbounded.
+//! Display-referred values describe the light a display should emit, and are bounded by its range.
+//! Mixing the two up, such as encoding scene light directly for a display, is a common source of
+//! clipped highlights in high dynamic range pipelines, so the wrappers in this module only allow
+//! crossing from scene to display light with an explicit tone mapping operator.
+
+use std::ops::{Deref, DerefMut};
+
+use num_traits::Float;
+
+use channels::*;
+use white_point::WhitePoint;
+use gamma::Linear;
+use math;
+
+use spaces::generic_rgb::Rgb;
+use spaces::primaries::Primaries;
+
+/// A color of scene-referred light
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SceneReferred<T> {
+    color: T,
+}
+
+/// A color of display-referred light
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DisplayReferred<T> {
+    color: T,
+}
+
+impl<T> SceneReferred<T> {
+    /// Tags a color as scene-referred.
+    #[inline]
+    pub fn new(color: T) -> SceneReferred<T> {
+        SceneReferred { color }
+    }
+
+    /// Removes the tag.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.color
+    }
+}
+
+impl<T> DisplayReferred<T> {
+    /// Tags a color as display-referred.
+    #[inline]
+    pub fn new(color: T) -> DisplayReferred<T> {
+        DisplayReferred { color }
+    }
+
+    /// Removes the tag.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.color
+    }
+}
+
+impl<T> Deref for SceneReferred<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.color
+    }
+}
+
+impl<T> DerefMut for SceneReferred<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.color
+    }
+}
+
+impl<T> Deref for DisplayReferred<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.color
+    }
+}
+
+impl<T> DerefMut for DisplayReferred<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.color
+    }
+}
+
+/// A tone mapping operator, from linear scene light to linear display light from 0.0 to 1.0
+pub trait ToneMap {
+    fn tone_map<T: Float>(&self, rgb: [T; 3]) -> [T; 3];
+}
+
+/// A tone mapping operator that can be inverted, to recover scene light from display light
+pub trait InverseToneMap: ToneMap {
+    fn inverse_tone_map<T: Float>(&self, rgb: [T; 3]) -> [T; 3];
+}
+
+/// Clips scene light to the range of the display, without compressing highlights
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Clip;
+
+/// The Reinhard operator `x / (1 + x)`, applied to each channel
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Reinhard;
+
+/// The extended Reinhard operator, which maps the scene light `white` to display white,
+/// applied to each channel
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExtendedReinhard {
+    /// The scene light that is mapped to display white
+    pub white: f64,
+}
+
+impl ExtendedReinhard {
+    pub fn new(white: f64) -> ExtendedReinhard {
+        ExtendedReinhard { white }
+    }
+}
+
+impl ToneMap for Clip {
+    fn tone_map<T: Float>(&self, [r, g, b]: [T; 3]) -> [T; 3] {
+        let clip = |x: T| x.max(T::zero()).min(T::one());
+
+        [clip(r), clip(g), clip(b)]
+    }
+}
+
+impl ToneMap for Reinhard {
+    fn tone_map<T: Float>(&self, [r, g, b]: [T; 3]) -> [T; 3] {
+        let map = |x: T| {
+            let x = x.max(T::zero());
+
+            x / (T::one() + x)
+        };
+
+        [map(r), map(g), map(b)]
+    }
+}
+
+impl InverseToneMap for Reinhard {
+    fn inverse_tone_map<T: Float>(&self, [r, g, b]: [T; 3]) -> [T; 3] {
+        let unmap = |x: T| {
+            let x = x.max(T::zero()).min(T::one());
+
+            x / (T::one() - x)
+        };
+
+        [unmap(r), unmap(g), unmap(b)]
+    }
+}
+
+impl ToneMap for ExtendedReinhard {
+    fn tone_map<T: Float>(&self, [r, g, b]: [T; 3]) -> [T; 3] {
+        let w2: T = math::cast(self.white * self.white);
+
+        let map = |x: T| {
+            let x = x.max(T::zero());
+
+            (x * (T::one() + x / w2) / (T::one() + x)).min(T::one())
+        };
+
+        [map(r), map(g), map(b)]
+    }
+}
+
+impl InverseToneMap for ExtendedReinhard {
+    fn inverse_tone_map<T: Float>(&self, [r, g, b]: [T; 3]) -> [T; 3] {
+        let w2: T = math::cast(self.white * self.white);
+        let two: T = math::cast(2.0);
+
+        // Solves x² / w² + x (1 - y) - y = 0 for the positive root
+        let unmap = |y: T| {
+            let y = y.max(T::zero()).min(T::one());
+            let p = T::one() - y;
+
+            (-p + (p * p + y * math::cast(4.0) / w2).sqrt()) * w2 / two
+        };
+
+        [unmap(r), unmap(g), unmap(b)]
+    }
+}
+
+impl<C: Channel, Wp, P: Primaries> SceneReferred<Rgb<C, Wp, P, Linear>>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Maps scene light to display light with the given tone mapping operator.
+    pub fn tone_map<M: ToneMap>(self, op: &M) -> DisplayReferred<Rgb<C, Wp, P, Linear>> {
+        let rgb = self.color.into_float();
+
+        let [r, g, b] = op.tone_map([rgb.r, rgb.g, rgb.b]);
+
+        DisplayReferred::new(Rgb::with_wp(C::from_float(r), C::from_float(g), C::from_float(b)))
+    }
+}
+
+impl<C: Channel, Wp, P: Primaries> DisplayReferred<Rgb<C, Wp, P, Linear>>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Recovers scene light from display light with the inverse of the given tone mapping operator.
+    pub fn inverse_tone_map<M: InverseToneMap>(self, op: &M) -> SceneReferred<Rgb<C, Wp, P, Linear>> {
+        let rgb = self.color.into_float();
+
+        let [r, g, b] = op.inverse_tone_map([rgb.r, rgb.g, rgb.b]);
+
+        SceneReferred::new(Rgb::with_wp(C::from_float(r), C::from_float(g), C::from_float(b)))
+    }
+}
@@ -0,0 +1,171 @@
+//! Saturating and desaturating colors of cylindrical spaces.
+//!
+//! Saturation of HSL, HSV, Okhsl and Okhsv ranges from 0.0 to 1.0. Chroma of the LCh spaces has no fixed
+//! upper bound, so amounts are relative to a reference chroma instead, which is 150 for CIE LCh and 0.4
+//! for Oklch, as with percentages of chroma in CSS.
+//!
+//! [`Vibrance`](trait.Vibrance.html) adjusts chroma selectively, boosting muted colors more than saturated ones.
+
+use num_traits::{Zero, Float};
+
+use channels::*;
+use white_point::WhitePoint;
+use convert::ConvertFrom;
+use limited::Limited;
+use gamma::Encoding;
+use hue::Deg;
+use math;
+
+use spaces::all::*;
+use spaces::generic_rgb::Rgb;
+use spaces::primaries::Primaries;
+
+/// Adjusting the saturation or chroma of colors
+pub trait Saturate: Sized {
+    /// The type of the saturation amount
+    type Scalar: Float;
+
+    /// Increases saturation by `amount` of the full saturation range, so 0.1 gives a color 10% more saturated.
+    ///
+    /// The resulting saturation is clamped to the valid range of the space.
+    fn saturate(self, amount: Self::Scalar) -> Self;
+
+    /// Decreases saturation by `amount` of the full saturation range, so 0.1 gives a color 10% less saturated.
+    ///
+    /// The resulting saturation is clamped to the valid range of the space.
+    #[inline]
+    fn desaturate(self, amount: Self::Scalar) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Removes all saturation, leaving a gray of the same lightness.
+    fn grayscale(self) -> Self;
+}
+
+macro_rules! impl_saturate {
+    ($($name:ident { $c:ident: $range:expr => $max:expr }),*) => {
+        $(
+            impl<C: Channel, Wp> Saturate for $name<C, Wp>
+            where
+                Wp: WhitePoint<C>,
+            {
+                type Scalar = FloatChannel<C>;
+
+                #[inline]
+                fn saturate(self, amount: FloatChannel<C>) -> Self {
+                    let range: FloatChannel<C> = math::cast($range);
+                    let max: FloatChannel<C> = math::cast($max);
+
+                    let mut color = self.into_float();
+
+                    color.$c = (color.$c + amount * range).max(FloatChannel::<C>::zero()).min(max);
+
+                    $name::from_float(color)
+                }
+
+                #[inline]
+                fn grayscale(self) -> Self {
+                    let mut color = self.into_float();
+
+                    color.$c = FloatChannel::<C>::zero();
+
+                    $name::from_float(color)
+                }
+            }
+        )*
+    }
+}
+
+impl_saturate! {
+    Hsl { s: 1.0 => 1.0 },
+    Hsv { s: 1.0 => 1.0 },
+    Okhsl { s: 1.0 => 1.0 },
+    Okhsv { s: 1.0 => 1.0 },
+    Lch { c: 150.0 => f64::INFINITY },
+    Oklch { c: 0.4 => f64::INFINITY }
+}
+
+/// The hue of typical skin tones in degrees, in both CIE LCh and Oklch
+const SKIN_HUE: f64 = 50.0;
+
+/// The angular width of the hues protected as skin tones
+const SKIN_HUE_WIDTH: f64 = 25.0;
+
+/// Computes the new chroma for a vibrance adjustment, with chroma relative to the reference chroma `range`.
+fn vibrance<T: Float>(chroma: T, hue: Deg<T>, amount: T, range: f64) -> T {
+    let one = T::one();
+
+    let relative = (chroma / math::cast(range)).max(T::zero()).min(one);
+
+    // Halve the effect on hues near skin tones
+    let d = hue.distance(Deg::new(math::cast(SKIN_HUE))) / math::cast(SKIN_HUE_WIDTH);
+    let protection = (-d * d).exp() * math::cast(0.5);
+
+    (chroma * (one + amount * (one - relative) * (one - protection))).max(T::zero())
+}
+
+/// Selective saturation of muted colors
+pub trait Vibrance: Sized {
+    /// The type of the vibrance amount
+    type Scalar: Float;
+
+    /// Scales chroma by up to `1 + amount`, where muted colors receive the full effect and fully saturated
+    /// colors none, while hues near skin tones are partly protected. Negative amounts mute colors instead.
+    fn vibrance(self, amount: Self::Scalar) -> Self;
+}
+
+macro_rules! impl_vibrance {
+    ($($name:ident: $range:expr),*) => {
+        $(
+            impl<C: Channel, Wp> Vibrance for $name<C, Wp>
+            where
+                Wp: WhitePoint<C>,
+            {
+                type Scalar = FloatChannel<C>;
+
+                #[inline]
+                fn vibrance(self, amount: FloatChannel<C>) -> Self {
+                    let mut lch = self.into_float();
+
+                    lch.c = vibrance(lch.c, lch.h, amount, $range);
+
+                    $name::from_float(lch)
+                }
+            }
+        )*
+    }
+}
+
+impl_vibrance! {
+    Lch: 150.0,
+    Oklch: 0.4
+}
+
+macro_rules! impl_vibrance_via_oklch {
+    ($($name:ident $(< $($p:ident: $bound:path),* >)*),*) => {
+        $(
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> Vibrance for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C>,
+                Self: Limited,
+                Oklch<FloatChannel<C>, Wp>: ConvertFrom<$name<FloatChannel<C>, Wp $($(, $p)*)*>>,
+                $name<FloatChannel<C>, Wp $($(, $p)*)*>: ConvertFrom<Oklch<FloatChannel<C>, Wp>>,
+            {
+                type Scalar = FloatChannel<C>;
+
+                /// Adjusts vibrance in Oklch, clamping the result to the gamut of the space.
+                fn vibrance(self, amount: FloatChannel<C>) -> Self {
+                    let mut lch = Oklch::convert_from(self.into_float());
+
+                    lch.c = vibrance(lch.c, lch.h, amount, 0.4);
+
+                    $name::from_float(ConvertFrom::convert_from(lch)).clamp()
+                }
+            }
+        )*
+    }
+}
+
+impl_vibrance_via_oklch! {
+    Srgb, LinearSrgb, DisplayP3, Rgb<P: Primaries, E: Encoding>, Hsl, Hsv
+}
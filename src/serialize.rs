@@ -0,0 +1,344 @@
+//! Serialization of colors with serde, enabled by the `serde` feature.
+//!
+//! Every color type and its `Alpha` serializes as a struct of its named channels, such as
+//! `{ "r": 1.0, "g": 0.5, "b": 0.0 }` or `{ "l": 0.7, "c": 0.1, "h": 30.0, "alpha": 0.5 }`, and deserializes
+//! from either that struct form or an array of its channels in order, such as `[1.0, 0.5, 0.0]`.
+//! The white point and other type parameters are not serialized.
+//!
+//! Other representations are selected per field with serde's `with` attribute:
+//!
+//! * [`array`](array/index.html) serializes any color as an array of its channels, which is more compact
+//! * [`hex`](hex/index.html) serializes `Srgb` and `Srgba` as a hex string such as `#ff8800` or `#ff880080`
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Theme {
+//!     background: Oklch,
+//!     #[serde(with = "color::serialize::array")]
+//!     accent: Lab,
+//!     #[serde(with = "color::serialize::hex")]
+//!     text: Srgb<u8>,
+//! }
+//! ```
+
+use std::fmt::{self, Formatter};
+use std::marker::PhantomData;
+
+use serde::ser::{Serialize, Serializer, SerializeStruct, SerializeTuple};
+use serde::de::{self, Deserialize, Deserializer, Visitor, SeqAccess, MapAccess};
+
+use channels::*;
+use color::{Color, ColorChannel, ColorChannels};
+use alpha::Alpha;
+use white_point::WhitePoint;
+use gamma::Encoding;
+use parse::ParseColorError;
+use hex::to_byte;
+
+use spaces::all::*;
+use spaces::generic_rgb::Rgb;
+use spaces::primaries::Primaries;
+use spaces::ycbcr::{YCbCr, YCbCrStandard, YCbCrRange};
+use spaces::ypbpr::YPbPr;
+use spaces::lms::{Lms, ConeResponse};
+use spaces::hsp::Hsp;
+
+/// Colors whose channels are serialized by name
+pub trait NamedChannels: Color {
+    /// The name of the type, without type parameters
+    const NAME: &'static str;
+
+    /// The names of the channels, in order
+    const FIELDS: &'static [&'static str];
+
+    /// Creates a color from exactly as many channels as there are fields.
+    fn from_channel_slice(channels: &[ColorChannel<Self>]) -> Self;
+}
+
+/// Serializes a color as a struct of its named channels.
+fn serialize_struct<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+where
+    C: NamedChannels,
+    ColorChannels<C>: ChannelSlice<ColorChannel<C>>,
+    ColorChannel<C>: Serialize,
+    S: Serializer,
+{
+    let mut state = serializer.serialize_struct(C::NAME, C::FIELDS.len())?;
+
+    for (field, channel) in C::FIELDS.iter().zip(color.channels().as_slice()) {
+        state.serialize_field(field, channel)?;
+    }
+
+    state.end()
+}
+
+/// Visits the struct or array form of a color.
+struct ChannelsVisitor<C>(PhantomData<C>);
+
+impl<'de, C> Visitor<'de> for ChannelsVisitor<C>
+where
+    C: NamedChannels,
+    ColorChannel<C>: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a {} as a struct or array of the channels {:?}", C::NAME, C::FIELDS)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+        let mut channels = Vec::with_capacity(C::FIELDS.len());
+
+        for i in 0..C::FIELDS.len() {
+            match seq.next_element()? {
+                Some(channel) => channels.push(channel),
+                None => return Err(de::Error::invalid_length(i, &self)),
+            }
+        }
+
+        if seq.next_element::<ColorChannel<C>>()?.is_some() {
+            return Err(de::Error::invalid_length(C::FIELDS.len() + 1, &self));
+        }
+
+        Ok(C::from_channel_slice(&channels))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<C, A::Error> {
+        let mut channels: Vec<Option<ColorChannel<C>>> = vec![None; C::FIELDS.len()];
+
+        while let Some(key) = map.next_key::<String>()? {
+            let i = C::FIELDS.iter().position(|field| *field == key)
+                .ok_or_else(|| de::Error::unknown_field(&key, C::FIELDS))?;
+
+            if channels[i].is_some() {
+                return Err(de::Error::duplicate_field(C::FIELDS[i]));
+            }
+
+            channels[i] = Some(map.next_value()?);
+        }
+
+        let channels = channels.iter().zip(C::FIELDS)
+            .map(|(channel, field)| channel.ok_or_else(|| de::Error::missing_field(field)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(C::from_channel_slice(&channels))
+    }
+}
+
+/// Deserializes a color from the struct or array form.
+fn deserialize_struct<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+where
+    C: NamedChannels,
+    ColorChannel<C>: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct(C::NAME, C::FIELDS, ChannelsVisitor(PhantomData))
+}
+
+/// Serializes any color as an array of its channels, with `#[serde(with = "color::serialize::array")]`
+pub mod array {
+    use super::*;
+
+    /// Serializes a color as an array of its channels, in order.
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Color,
+        ColorChannels<C>: ChannelSlice<ColorChannel<C>>,
+        ColorChannel<C>: Serialize,
+        S: Serializer,
+    {
+        let channels = color.channels().as_slice();
+
+        let mut state = serializer.serialize_tuple(channels.len())?;
+
+        for channel in channels {
+            state.serialize_element(channel)?;
+        }
+
+        state.end()
+    }
+
+    /// Deserializes a color from an array of its channels, in order.
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: NamedChannels,
+        ColorChannel<C>: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(C::FIELDS.len(), ChannelsVisitor(PhantomData))
+    }
+}
+
+/// sRGB colors that can be written as hex strings
+pub trait HexColor: Sized {
+    /// Writes the color as `#rrggbb`, or `#rrggbbaa` with alpha, clamping and rounding channels to 8 bits.
+    fn to_hex_string(&self) -> String;
+
+    /// Parses the color from any of the hex forms it accepts.
+    fn from_hex_str(s: &str) -> Result<Self, ParseColorError>;
+}
+
+impl<C: Channel, Wp> HexColor for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn to_hex_string(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", to_byte(self.r), to_byte(self.g), to_byte(self.b))
+    }
+
+    #[inline]
+    fn from_hex_str(s: &str) -> Result<Srgb<C, Wp>, ParseColorError> {
+        s.parse()
+    }
+}
+
+impl<C: Channel, Wp> HexColor for Alpha<Srgb<C, Wp>>
+where
+    Wp: WhitePoint<C>,
+{
+    fn to_hex_string(&self) -> String {
+        let (r, g, b) = (to_byte(self.r), to_byte(self.g), to_byte(self.b));
+
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, to_byte(self.alpha))
+    }
+
+    #[inline]
+    fn from_hex_str(s: &str) -> Result<Alpha<Srgb<C, Wp>>, ParseColorError> {
+        s.parse()
+    }
+}
+
+/// Serializes `Srgb` and `Srgba` as hex strings, with `#[serde(with = "color::serialize::hex")]`
+///
+/// Colors are serialized as `#rrggbb` or `#rrggbbaa`, and deserialized from any form accepted by their `FromStr`.
+pub mod hex {
+    use super::*;
+
+    /// Serializes a color as a hex string.
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: HexColor,
+        S: Serializer,
+    {
+        serializer.serialize_str(&color.to_hex_string())
+    }
+
+    struct HexVisitor<C>(PhantomData<C>);
+
+    impl<'de, C: HexColor> Visitor<'de> for HexVisitor<C> {
+        type Value = C;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a hex color string")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<C, E> {
+            C::from_hex_str(s).map_err(E::custom)
+        }
+    }
+
+    /// Deserializes a color from a hex string.
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: HexColor,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(HexVisitor(PhantomData))
+    }
+}
+
+macro_rules! impl_serde {
+    ($(
+        $name:ident $(< $($p:ident: $bound:path),* >)* [$($c:ident),*]
+    ),*) => {
+        $(
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> NamedChannels for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C>,
+            {
+                const NAME: &'static str = stringify!($name);
+                const FIELDS: &'static [&'static str] = &[$(stringify!($c)),*];
+
+                fn from_channel_slice(channels: &[C]) -> Self {
+                    match *channels {
+                        [$($c),*] => $name::with_wp($($c),*),
+                        _ => panic!("wrong number of channels for {}", stringify!($name)),
+                    }
+                }
+            }
+
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> NamedChannels for Alpha<$name<C, Wp $($(, $p)*)*>>
+            where
+                Wp: WhitePoint<C>,
+            {
+                const NAME: &'static str = "Alpha";
+                const FIELDS: &'static [&'static str] = &[$(stringify!($c),)* "alpha"];
+
+                fn from_channel_slice(channels: &[C]) -> Self {
+                    match *channels {
+                        [$($c,)* alpha] => Alpha::from_color($name::with_wp($($c),*), alpha),
+                        _ => panic!("wrong number of channels for Alpha<{}>", stringify!($name)),
+                    }
+                }
+            }
+
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> Serialize for $name<C, Wp $($(, $p)*)*>
+            where
+                C: Serialize,
+                Wp: WhitePoint<C>,
+            {
+                #[inline]
+                fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+                    serialize_struct(self, serializer)
+                }
+            }
+
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> Serialize for Alpha<$name<C, Wp $($(, $p)*)*>>
+            where
+                C: Serialize,
+                Wp: WhitePoint<C>,
+            {
+                #[inline]
+                fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+                    serialize_struct(self, serializer)
+                }
+            }
+
+            impl<'de, C: Channel, Wp $($(, $p: $bound)*)*> Deserialize<'de> for $name<C, Wp $($(, $p)*)*>
+            where
+                C: Deserialize<'de>,
+                Wp: WhitePoint<C>,
+            {
+                #[inline]
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_struct(deserializer)
+                }
+            }
+
+            impl<'de, C: Channel, Wp $($(, $p: $bound)*)*> Deserialize<'de> for Alpha<$name<C, Wp $($(, $p)*)*>>
+            where
+                C: Deserialize<'de>,
+                Wp: WhitePoint<C>,
+            {
+                #[inline]
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_struct(deserializer)
+                }
+            }
+        )*
+    }
+}
+
+impl_serde! {
+    Srgb [r, g, b], LinearSrgb [r, g, b], ScRgb [r, g, b], Rgb<P: Primaries, E: Encoding> [r, g, b],
+    DisplayP3 [r, g, b], Aces2065 [r, g, b], AcesCg [r, g, b],
+    Hsl [h, s, l], Hsv [h, s, v], Hwb [h, w, b], Hsi [h, s, i], Hsp<S: YCbCrStandard> [h, s, p],
+    Xyz [x, y, z], Yxy [x, y, luma], Uv1960 [u, v], UvPrime [u, v],
+    Lab [l, a, b], Lch [l, c, h], Luv [l, u, v], Lchuv [l, c, h],
+    Oklab [l, a, b], Oklch [l, c, h], Okhsl [h, s, l], Okhsv [h, s, v],
+    Cam16Ucs [j, a, b], Jzazbz [jz, az, bz], Jzczhz [jz, cz, hz], Din99 [l, a, b], Din99d [l, a, b],
+    Ipt [i, p, t], OsaUcs [l, j, g], Xyb [x, y, b], Lms<M: ConeResponse> [l, m, s],
+    YCbCr<S: YCbCrStandard, R: YCbCrRange> [y, cb, cr], YPbPr<S: YCbCrStandard> [y, pb, pr],
+    YDbDr [y, db, dr], YCoCg [y, co, cg], YCoCgR [y, co, cg],
+    Cmy [c, m, y], Cmyk [c, m, y, k]
+}
@@ -0,0 +1,112 @@
+//! Lightening and darkening colors along a perceptual lightness axis.
+//!
+//! Spaces with a perceptual lightness channel, such as L\* of CIE L\*a\*b\* or L of Oklab, adjust that channel
+//! directly. Other spaces, such as RGB and HSL, are converted to CIE L\*a\*b\* and back, since their own
+//! lightness or value channels are far from perceptually uniform. Shifting the lightness of a saturated color
+//! may leave the gamut of its space, so those results are clamped with [`Limited`](../limited/trait.Limited.html).
+
+use num_traits::Float;
+
+use channels::*;
+use convert::ConvertFrom;
+use limited::Limited;
+use white_point::WhitePoint;
+use gamma::Encoding;
+use math;
+
+use spaces::all::*;
+use spaces::generic_rgb::Rgb;
+use spaces::primaries::Primaries;
+
+/// Lightening and darkening of colors
+pub trait Shade: Sized {
+    /// The type of the lightness amount
+    type Scalar: Float;
+
+    /// Lightens the color by `amount` of the full lightness range, so 0.1 gives a color 10% lighter.
+    ///
+    /// The resulting lightness is clamped from black to white.
+    fn lighten(self, amount: Self::Scalar) -> Self;
+
+    /// Darkens the color by `amount` of the full lightness range, so 0.1 gives a color 10% darker.
+    ///
+    /// The resulting lightness is clamped from black to white.
+    #[inline]
+    fn darken(self, amount: Self::Scalar) -> Self {
+        self.lighten(-amount)
+    }
+}
+
+/// Offsets a lightness by `amount` of its range, clamped to the range.
+#[inline]
+fn shift<T: Float>(value: T, amount: T, max: f64) -> T {
+    let max: T = math::cast(max);
+
+    (value + amount * max).max(T::zero()).min(max)
+}
+
+macro_rules! impl_shade_direct {
+    ($($name:ident { $c:ident: $max:expr }),*) => {
+        $(
+            impl<C: Channel, Wp> Shade for $name<C, Wp>
+            where
+                Wp: WhitePoint<C>,
+            {
+                type Scalar = FloatChannel<C>;
+
+                #[inline]
+                fn lighten(self, amount: FloatChannel<C>) -> Self {
+                    let mut color = self.into_float();
+
+                    color.$c = shift(color.$c, amount, $max);
+
+                    $name::from_float(color)
+                }
+            }
+        )*
+    }
+}
+
+impl_shade_direct! {
+    Lab { l: 100.0 },
+    Lch { l: 100.0 },
+    Luv { l: 100.0 },
+    Lchuv { l: 100.0 },
+    Oklab { l: 1.0 },
+    Oklch { l: 1.0 },
+    Okhsl { l: 1.0 },
+    Cam16Ucs { j: 100.0 },
+    Din99 { l: 100.0 },
+    Din99d { l: 100.0 }
+}
+
+macro_rules! impl_shade_via_lab {
+    ($($name:ident $(< $($p:ident: $bound:path),* >)*),*) => {
+        $(
+            impl<C: Channel, Wp $($(, $p: $bound)*)*> Shade for $name<C, Wp $($(, $p)*)*>
+            where
+                Wp: WhitePoint<C>,
+                Self: Limited,
+                Lab<FloatChannel<C>, Wp>: ConvertFrom<$name<FloatChannel<C>, Wp $($(, $p)*)*>>,
+                $name<FloatChannel<C>, Wp $($(, $p)*)*>: ConvertFrom<Lab<FloatChannel<C>, Wp>>,
+            {
+                type Scalar = FloatChannel<C>;
+
+                #[inline]
+                fn lighten(self, amount: FloatChannel<C>) -> Self {
+                    let mut lab = Lab::convert_from(self.into_float());
+
+                    lab.l = shift(lab.l, amount, 100.0);
+
+                    $name::from_float(ConvertFrom::convert_from(lab)).clamp()
+                }
+            }
+        )*
+    }
+}
+
+impl_shade_via_lab! {
+    Srgb, LinearSrgb, ScRgb, Rgb<P: Primaries, E: Encoding>, DisplayP3, Aces2065, AcesCg,
+    Hsl, Hsv, Hwb, Hsi, Okhsv,
+    Xyz, Yxy
+}
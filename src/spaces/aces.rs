@@ -0,0 +1,102 @@
+//! The ACES2065-1 color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math::{self, Vector3};
+
+use super::rgb;
+use super::primaries::{Primaries, Ap0};
+
+/// Chromaticities of the ACES AP0 red, green and blue primaries
+pub const AP0_PRIMARIES: [[f64; 2]; 3] = Ap0::PRIMARIES;
+
+/// The native XYZ white point of the ACES encodings, see [`white_point::Aces`](../../white_point/struct.Aces.html)
+pub const ACES_WHITE: Vector3<f64> = [0.95265, 1.0, 1.00883];
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The ACES2065-1 color space
+    ///
+    /// ACES2065-1 is the archival and interchange encoding of the Academy Color Encoding System.
+    /// It uses the AP0 primaries, which enclose the entire spectral locus, and stores scene-referred
+    /// linear light without any transfer function.
+    ///
+    /// The primaries are defined relative to the ACES white point. Conversions to and from XYZ
+    /// are chromatically adapted to the white point of the color with the Bradford transform.
+    struct Aces2065 : TripleChannel => RGB {
+        /// The linear amount of red light.
+        pub r,
+        /// The linear amount of green light.
+        pub g,
+        /// The linear amount of blue light.
+        pub b,
+    }
+}
+
+pub type Aces2065a<C, Wp> = Alpha<Aces2065<C, Wp>>;
+
+impl_color_arithmetic!(Aces2065);
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Aces2065<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Aces2065<C, Wp> {
+        Aces2065::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Aces2065<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Aces2065<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let m = rgb::from_xyz_matrix_adapted::<C, Wp>(&AP0_PRIMARIES, &ACES_WHITE);
+        let [r, g, b] = math::mul_vec(&m, [xyz.x, xyz.y, xyz.z]);
+
+        Aces2065::from_float(Aces2065::raw(r, g, b))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Aces2065<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Aces2065<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<AcesCg<C, Wp>> for Aces2065<C, Wp>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    fn from(cg: AcesCg<C, Wp>) -> Aces2065<C, Wp> {
+        Aces2065::from_float(Xyz::from(cg.into_float()).into())
+    }
+}
+
+impl<C: Channel, Wp> From<Aces2065<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Aces2065<C, Wp>) -> Xyz<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let m = rgb::to_xyz_matrix_adapted::<C, Wp>(&AP0_PRIMARIES, &ACES_WHITE);
+        let [x, y, z] = math::mul_vec(&m, [rgb.r, rgb.g, rgb.b]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
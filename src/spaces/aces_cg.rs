@@ -0,0 +1,100 @@
+//! The ACEScg color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::rgb;
+use super::primaries::{Primaries, Ap1};
+use super::aces::ACES_WHITE;
+
+/// Chromaticities of the ACES AP1 red, green and blue primaries
+pub const AP1_PRIMARIES: [[f64; 2]; 3] = Ap1::PRIMARIES;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The ACEScg color space
+    ///
+    /// ACEScg is the working space of the Academy Color Encoding System intended for rendering and
+    /// compositing. It uses the AP1 primaries, which lie close to the spectral locus without enclosing
+    /// imaginary colors, and stores scene-referred linear light without any transfer function.
+    ///
+    /// The primaries are defined relative to the ACES white point. Conversions to and from XYZ
+    /// are chromatically adapted to the white point of the color with the Bradford transform.
+    struct AcesCg : TripleChannel => RGB {
+        /// The linear amount of red light.
+        pub r,
+        /// The linear amount of green light.
+        pub g,
+        /// The linear amount of blue light.
+        pub b,
+    }
+}
+
+pub type AcesCga<C, Wp> = Alpha<AcesCg<C, Wp>>;
+
+impl_color_arithmetic!(AcesCg);
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for AcesCg<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> AcesCg<C, Wp> {
+        AcesCg::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for AcesCg<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> AcesCg<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let m = rgb::from_xyz_matrix_adapted::<C, Wp>(&AP1_PRIMARIES, &ACES_WHITE);
+        let [r, g, b] = math::mul_vec(&m, [xyz.x, xyz.y, xyz.z]);
+
+        AcesCg::from_float(AcesCg::raw(r, g, b))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for AcesCg<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> AcesCg<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Aces2065<C, Wp>> for AcesCg<C, Wp>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    fn from(aces: Aces2065<C, Wp>) -> AcesCg<C, Wp> {
+        AcesCg::from_float(Xyz::from(aces.into_float()).into())
+    }
+}
+
+impl<C: Channel, Wp> From<AcesCg<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: AcesCg<C, Wp>) -> Xyz<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let m = rgb::to_xyz_matrix_adapted::<C, Wp>(&AP1_PRIMARIES, &ACES_WHITE);
+        let [x, y, z] = math::mul_vec(&m, [rgb.r, rgb.g, rgb.b]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
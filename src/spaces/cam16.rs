@@ -0,0 +1,316 @@
+//! The CAM16 color appearance model.
+//!
+//! CAM16 predicts how a color is perceived under specific viewing conditions, such as the
+//! luminance of the adapting field and the brightness of the surround. It produces a set of
+//! appearance correlates rather than a single color space, see [`Cam16`](struct.Cam16.html).
+//!
+//! For a uniform color space built on CAM16, see [`Cam16Ucs`](../cam16_ucs/struct.Cam16Ucs.html).
+
+use num_traits::Float;
+
+use channels::*;
+use white_point::WhitePoint;
+use math::{self, Vector3};
+
+use super::xyz::Xyz;
+use super::lms::{ConeResponse, Cat16};
+
+/// The relative luminance of the surround of the stimulus
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Surround {
+    /// Surface colors, such as prints viewed in a lit room
+    Average,
+    /// Displays viewed in a dim room, such as television
+    Dim,
+    /// Projectors viewed in a dark room, such as cinema
+    Dark,
+}
+
+impl Surround {
+    /// Returns the `F`, `c` and `Nc` factors of the surround
+    fn factors(self) -> (f64, f64, f64) {
+        match self {
+            Surround::Average => (1.0, 0.69, 1.0),
+            Surround::Dim => (0.9, 0.59, 0.9),
+            Surround::Dark => (0.8, 0.525, 0.8),
+        }
+    }
+}
+
+/// Viewing conditions for the CAM16 color appearance model
+///
+/// The reference white of the viewing conditions is the white point of the colors being converted.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ViewingConditions<T> {
+    /// Luminance of the adapting field in cd/m², typically 20% of the luminance of white
+    pub adapting_luminance: T,
+    /// Relative luminance of the background, from 0 to 100
+    pub background_luminance: T,
+    /// Relative luminance of the surround
+    pub surround: Surround,
+    /// Assume complete adaptation to the white point, as when discounting the illuminant
+    pub discounting: bool,
+}
+
+impl<T: Float> ViewingConditions<T> {
+    pub fn new(adapting_luminance: T, background_luminance: T, surround: Surround) -> ViewingConditions<T> {
+        ViewingConditions {
+            adapting_luminance,
+            background_luminance,
+            surround,
+            discounting: false,
+        }
+    }
+}
+
+impl<T: Float> Default for ViewingConditions<T> {
+    /// Average surround, with an adapting luminance of 40 cd/m² and a background luminance of 20
+    fn default() -> ViewingConditions<T> {
+        ViewingConditions::new(math::cast(40.0), math::cast(20.0), Surround::Average)
+    }
+}
+
+/// Values derived from the viewing conditions and white point
+#[derive(Copy, Clone, Debug)]
+pub struct Parameters<T> {
+    n: T,
+    z: T,
+    nbb: T,
+    nc: T,
+    c: T,
+    fl: T,
+    d_rgb: Vector3<T>,
+    aw: T,
+}
+
+impl<T: Channel + Float> Parameters<T> {
+    /// Computes the parameters of the viewing conditions for the white point `Wp`.
+    pub fn new<C, Wp>(conditions: &ViewingConditions<T>) -> Parameters<T>
+    where
+        C: Channel<FloatChannel = T>,
+        Wp: WhitePoint<C>,
+    {
+        let one = T::one();
+        let hundred: T = math::cast(100.0);
+
+        let [wx, wy, wz] = Wp::float_xyz();
+        let white = [wx * hundred, wy * hundred, wz * hundred];
+
+        let (f, c, nc) = conditions.surround.factors();
+        let (f, c, nc): (T, T, T) = (math::cast(f), math::cast(c), math::cast(nc));
+
+        let la = conditions.adapting_luminance;
+
+        let k = one / (la * math::cast(5.0) + one);
+        let k4 = k.powi(4);
+
+        let fl = k4 * la * math::cast(5.0) * math::cast(0.2)
+            + (one - k4).powi(2) * math::cast(0.1) * (la * math::cast(5.0)).cbrt();
+
+        let n = conditions.background_luminance / white[1];
+        let z = n.sqrt() + math::cast(1.48);
+        let nbb = n.powf(math::cast(-0.2)) * math::cast(0.725);
+
+        let d = if conditions.discounting {
+            one
+        } else {
+            let d: T = f * (one - ((-la - math::cast(42.0)) / math::cast(92.0)).exp() / math::cast(3.6));
+
+            d.max(T::zero()).min(one)
+        };
+
+        let rgb_w = math::mul_vec(&math::matrix(&Cat16::MATRIX), white);
+
+        let mut d_rgb = [one; 3];
+        let mut rgb_aw = [one; 3];
+
+        for i in 0..3 {
+            d_rgb[i] = d * white[1] / rgb_w[i] + one - d;
+            rgb_aw[i] = adapt(fl, d_rgb[i] * rgb_w[i]);
+        }
+
+        let aw = achromatic(rgb_aw, nbb);
+
+        Parameters { n, z, nbb, nc, c, fl, d_rgb, aw }
+    }
+
+    /// The luminance level adaptation factor, F<sub>L</sub>
+    #[inline]
+    pub fn luminance_adaptation(&self) -> T {
+        self.fl
+    }
+
+    /// The brightness correlate, Q, of a color with the lightness J under these viewing conditions
+    #[inline]
+    pub(crate) fn brightness(&self, j: T) -> T {
+        (j / math::cast(100.0)).sqrt() * (self.aw + math::cast(4.0)) * self.fl.powf(math::cast(0.25)) * math::cast(4.0) / self.c
+    }
+}
+
+/// Applies the nonlinear post-adaptation cone response compression.
+#[inline]
+fn adapt<T: Float>(fl: T, x: T) -> T {
+    let p = (fl * x.abs() / math::cast(100.0)).powf(math::cast(0.42));
+
+    p * math::cast(400.0) / (p + math::cast(27.13)) * x.signum() + math::cast(0.1)
+}
+
+/// Inverts the nonlinear post-adaptation cone response compression.
+#[inline]
+fn unadapt<T: Float>(fl: T, x: T) -> T {
+    let x = x - math::cast(0.1);
+    let a = x.abs();
+
+    (a * math::cast(27.13) / (math::cast::<T>(400.0) - a)).powf(math::cast(1.0 / 0.42)) * math::cast(100.0) / fl * x.signum()
+}
+
+/// Computes the achromatic response from the adapted cone responses.
+#[inline]
+fn achromatic<T: Float>([r, g, b]: Vector3<T>, nbb: T) -> T {
+    (r * math::cast(2.0) + g + b / math::cast(20.0) - math::cast(0.305)) * nbb
+}
+
+/// The eccentricity factor of a hue in degrees.
+#[inline]
+fn eccentricity<T: Float>(h: T) -> T {
+    ((h.to_radians() + math::cast(2.0)).cos() + math::cast(3.8)) / math::cast(4.0)
+}
+
+/// Appearance correlates of a color under some viewing conditions, as predicted by CAM16
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cam16<T> {
+    /// Lightness, J
+    pub j: T,
+    /// Chroma, C
+    pub c: T,
+    /// Hue angle in degrees, h
+    pub h: T,
+    /// Brightness, Q
+    pub q: T,
+    /// Colorfulness, M
+    pub m: T,
+    /// Saturation, s
+    pub s: T,
+}
+
+impl<T: Channel + Float> Cam16<T> {
+    /// Predicts the appearance of a color under the given viewing conditions.
+    pub fn from_xyz<C, Wp>(xyz: Xyz<C, Wp>, conditions: &ViewingConditions<T>) -> Cam16<T>
+    where
+        C: Channel<FloatChannel = T>,
+        Wp: WhitePoint<C>,
+    {
+        Cam16::from_xyz_with_parameters(xyz, &Parameters::new::<C, Wp>(conditions))
+    }
+
+    /// Predicts the appearance of a color with precomputed viewing condition parameters.
+    pub fn from_xyz_with_parameters<C, Wp>(xyz: Xyz<C, Wp>, p: &Parameters<T>) -> Cam16<T>
+    where
+        C: Channel<FloatChannel = T>,
+        Wp: WhitePoint<C>,
+    {
+        let one = T::one();
+        let hundred: T = math::cast(100.0);
+
+        let xyz = xyz.into_float();
+
+        let rgb = math::mul_vec(&math::matrix(&Cat16::MATRIX), [xyz.x * hundred, xyz.y * hundred, xyz.z * hundred]);
+
+        let mut rgb_a = [one; 3];
+
+        for i in 0..3 {
+            rgb_a[i] = adapt(p.fl, p.d_rgb[i] * rgb[i]);
+        }
+
+        let [ra, ga, ba] = rgb_a;
+
+        let a = ra - ga * math::cast(12.0 / 11.0) + ba / math::cast(11.0);
+        let b = (ra + ga - ba * math::cast(2.0)) / math::cast(9.0);
+
+        let h = math::normalize_hue(b.atan2(a).to_degrees());
+
+        let j = (achromatic(rgb_a, p.nbb) / p.aw).powf(p.c * p.z) * hundred;
+        let q = p.brightness(j);
+
+        let t = eccentricity(h) * p.nc * p.nbb * math::cast(50000.0 / 13.0) * a.hypot(b)
+            / (ra + ga + ba * math::cast(21.0 / 20.0));
+
+        let c = t.powf(math::cast(0.9)) * (j / hundred).sqrt() * (math::cast::<T>(1.64) - math::cast::<T>(0.29).powf(p.n)).powf(math::cast(0.73));
+        let m = c * p.fl.powf(math::cast(0.25));
+        let s = if q.is_normal() { (m / q).sqrt() * hundred } else { T::zero() };
+
+        Cam16 { j, c, h, q, m, s }
+    }
+
+    /// Computes the color with this lightness, chroma and hue under the given viewing conditions.
+    ///
+    /// The brightness, colorfulness and saturation correlates are ignored.
+    pub fn into_xyz<C, Wp>(self, conditions: &ViewingConditions<T>) -> Xyz<C, Wp>
+    where
+        C: Channel<FloatChannel = T>,
+        Wp: WhitePoint<C>,
+    {
+        self.into_xyz_with_parameters(&Parameters::new::<C, Wp>(conditions))
+    }
+
+    /// Computes the color with this lightness, chroma and hue with precomputed viewing condition parameters.
+    ///
+    /// The brightness, colorfulness and saturation correlates are ignored.
+    pub fn into_xyz_with_parameters<C, Wp>(self, p: &Parameters<T>) -> Xyz<C, Wp>
+    where
+        C: Channel<FloatChannel = T>,
+        Wp: WhitePoint<C>,
+    {
+        let zero = T::zero();
+        let hundred: T = math::cast(100.0);
+
+        if self.j <= zero {
+            return Xyz::with_wp(C::zero(), C::zero(), C::zero());
+        }
+
+        let t = (self.c / ((self.j / hundred).sqrt() * (math::cast::<T>(1.64) - math::cast::<T>(0.29).powf(p.n)).powf(math::cast(0.73))))
+            .powf(math::cast(1.0 / 0.9));
+
+        let p2 = (self.j / hundred).powf(T::one() / (p.c * p.z)) * p.aw / p.nbb + math::cast(0.305);
+
+        let (a, b) = if t.is_normal() {
+            let p1 = eccentricity(self.h) * p.nc * p.nbb * math::cast(50000.0 / 13.0) / t;
+            let p3: T = math::cast(21.0 / 20.0);
+
+            let (sin, cos) = self.h.to_radians().sin_cos();
+
+            let two: T = math::cast(2.0);
+            let k = p2 * (two + p3) * math::cast(460.0 / 1403.0);
+
+            if sin.abs() >= cos.abs() {
+                let b = k / (p1 / sin + (two + p3) * math::cast(220.0 / 1403.0) * (cos / sin)
+                    - math::cast(27.0 / 1403.0) + p3 * math::cast(6300.0 / 1403.0));
+
+                (b * cos / sin, b)
+            } else {
+                let a = k / (p1 / cos + (two + p3) * math::cast(220.0 / 1403.0)
+                    - (math::cast::<T>(27.0 / 1403.0) - p3 * math::cast(6300.0 / 1403.0)) * (sin / cos));
+
+                (a, a * sin / cos)
+            }
+        } else {
+            (zero, zero)
+        };
+
+        let rgb_a = [
+            (p2 * math::cast(460.0) + a * math::cast(451.0) + b * math::cast(288.0)) / math::cast(1403.0),
+            (p2 * math::cast(460.0) - a * math::cast(891.0) - b * math::cast(261.0)) / math::cast(1403.0),
+            (p2 * math::cast(460.0) - a * math::cast(220.0) - b * math::cast(6300.0)) / math::cast(1403.0),
+        ];
+
+        let mut rgb = [zero; 3];
+
+        for i in 0..3 {
+            rgb[i] = unadapt(p.fl, rgb_a[i]) / p.d_rgb[i];
+        }
+
+        let [x, y, z] = math::mul_vec(&math::invert(&math::matrix(&Cat16::MATRIX)), rgb);
+
+        Xyz::with_wp(C::from_float(x / hundred), C::from_float(y / hundred), C::from_float(z / hundred))
+    }
+}
@@ -0,0 +1,144 @@
+//! The CAM16-UCS color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::cam16::{Cam16, Parameters, ViewingConditions};
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The CAM16-UCS uniform color space
+    ///
+    /// CAM16-UCS rescales the lightness and colorfulness correlates of the CAM16 color appearance
+    /// model so that Euclidean distances approximate perceived color differences. It is the modern
+    /// reference for color difference work.
+    ///
+    /// Conversions with `From` use the default [`ViewingConditions`](../cam16/struct.ViewingConditions.html),
+    /// relative to the white point of the color. Use [`from_xyz_with`](#method.from_xyz_with) and
+    /// [`into_xyz_with`](#method.into_xyz_with) for other viewing conditions.
+    struct Cam16Ucs : TripleChannel => CAM16UCS {
+        /// J' is the lightness of the color, from 0.0 for black to 100.0 for white.
+        pub j in 0.0..100.0,
+        /// a' is how green or red the color is.
+        pub a in -50.0..50.0,
+        /// b' is how blue or yellow the color is.
+        pub b in -50.0..50.0,
+    }
+}
+
+pub type Cam16Ucsa<C, Wp> = Alpha<Cam16Ucs<C, Wp>>;
+
+use ::spaces::all::*;
+
+const C1: f64 = 0.007;
+const C2: f64 = 0.0228;
+
+impl<C: Channel, Wp> Default for Cam16Ucs<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Cam16Ucs<C, Wp> {
+        Cam16Ucs::from_float(Cam16Ucs::raw(Zero::zero(), Zero::zero(), Zero::zero()))
+    }
+}
+
+impl<C: Channel, Wp> Cam16Ucs<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Converts CAM16 appearance correlates to CAM16-UCS.
+    pub fn from_cam16(cam: Cam16<FloatChannel<C>>) -> Cam16Ucs<C, Wp> {
+        let one = FloatChannel::<C>::one();
+
+        let j = cam.j * math::cast(1.0 + 100.0 * C1) / (one + cam.j * math::cast(C1));
+        let m = (one + cam.m * math::cast(C2)).ln() / math::cast(C2);
+
+        let (sin, cos) = cam.h.to_radians().sin_cos();
+
+        Cam16Ucs::from_float(Cam16Ucs::raw(j, m * cos, m * sin))
+    }
+
+    /// Converts this color to CAM16 appearance correlates, with precomputed viewing condition parameters.
+    pub fn into_cam16(self, p: &Parameters<FloatChannel<C>>) -> Cam16<FloatChannel<C>> {
+        let ucs = self.into_float();
+
+        let one = FloatChannel::<C>::one();
+        let hundred: FloatChannel<C> = math::cast(100.0);
+
+        let j = ucs.j / (math::cast::<FloatChannel<C>>(1.0 + 100.0 * C1) - ucs.j * math::cast(C1));
+        let m = ((ucs.a.hypot(ucs.b) * math::cast(C2)).exp() - one) / math::cast(C2);
+        let h = math::normalize_hue(ucs.b.atan2(ucs.a).to_degrees());
+
+        let c = m / p.luminance_adaptation().powf(math::cast(0.25));
+        let q = p.brightness(j);
+        let s = if q.is_normal() { (m / q).sqrt() * hundred } else { Zero::zero() };
+
+        Cam16 { j, c, h, q, m, s }
+    }
+
+    /// Converts from XYZ under the given viewing conditions.
+    pub fn from_xyz_with(xyz: Xyz<C, Wp>, conditions: &ViewingConditions<FloatChannel<C>>) -> Cam16Ucs<C, Wp> {
+        Cam16Ucs::from_cam16(Cam16::from_xyz(xyz, conditions))
+    }
+
+    /// Converts to XYZ under the given viewing conditions.
+    pub fn into_xyz_with(self, conditions: &ViewingConditions<FloatChannel<C>>) -> Xyz<C, Wp> {
+        let p = Parameters::new::<C, Wp>(conditions);
+
+        self.into_cam16(&p).into_xyz_with_parameters(&p)
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Cam16Ucs<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Cam16Ucs<C, Wp> {
+        Cam16Ucs::from_xyz_with(xyz, &ViewingConditions::default())
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Cam16Ucs<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Cam16Ucs<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Cam16Ucs<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ucs: Cam16Ucs<C, Wp>) -> Xyz<C, Wp> {
+        ucs.into_xyz_with(&ViewingConditions::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cam16_round_trip() {
+        let xyz = Xyz::<f64>::new(0.3, 0.2, 0.1);
+        let conditions = ViewingConditions::default();
+        let p = Parameters::new::<f64, ::white_point::D65>(&conditions);
+
+        let cam = Cam16::from_xyz(xyz, &conditions);
+        let back = Cam16Ucs::<f64>::from_cam16(cam).into_cam16(&p);
+
+        for &(a, b) in &[(cam.j, back.j), (cam.c, back.c), (cam.h, back.h), (cam.q, back.q), (cam.m, back.m), (cam.s, back.s)] {
+            assert!((a - b).abs() < 1e-9, "{:?} != {:?}", cam, back);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! The CMY color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The CMY (cyan, magenta, yellow) color space
+    ///
+    /// CMY is the subtractive complement of gamma-encoded sRGB, describing the amount of each ink
+    /// needed to absorb red, green and blue light respectively. The conversion is naive and does not
+    /// model any real printing process, for which a profile is needed.
+    struct Cmy : TripleChannel => CMY {
+        /// The amount of cyan ink, which absorbs red light.
+        pub c,
+        /// The amount of magenta ink, which absorbs green light.
+        pub m,
+        /// The amount of yellow ink, which absorbs blue light.
+        pub y,
+    }
+}
+
+pub type Cmya<C, Wp> = Alpha<Cmy<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Cmy<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Cmy<C, Wp> {
+        Cmy::with_wp(C::from_float(One::one()), C::from_float(One::one()), C::from_float(One::one()))
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for Cmy<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> Cmy<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let one = FloatChannel::<C>::one();
+
+        Cmy::from_float(Cmy::raw(one - rgb.r, one - rgb.g, one - rgb.b))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Cmy<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Cmy<C, Wp> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Cmy<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Cmy<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Cmy<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(cmy: Cmy<C, Wp>) -> Srgb<C, Wp> {
+        let cmy = cmy.into_float();
+
+        let one = FloatChannel::<C>::one();
+
+        Srgb::with_wp(Channel::from_float(one - cmy.c),
+                      Channel::from_float(one - cmy.m),
+                      Channel::from_float(one - cmy.y))
+    }
+}
+
+impl<C: Channel, Wp> From<Cmy<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(cmy: Cmy<C, Wp>) -> Xyz<C, Wp> {
+        Srgb::from(cmy).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_defaults_are_black() {
+        let cmy = Srgb::from(Cmy::<u8>::default());
+        let cmyk = Srgb::from(Cmyk::<u8>::default());
+
+        assert_eq!((cmy.r, cmy.g, cmy.b), (0, 0, 0));
+        assert_eq!((cmyk.r, cmyk.g, cmyk.b), (0, 0, 0));
+    }
+}
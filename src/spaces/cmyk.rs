@@ -0,0 +1,286 @@
+//! The CMYK color space.
+//!
+//! CMYK adds black ink to CMY. How much of the gray component of a color is printed with black ink
+//! instead of equal amounts of cyan, magenta and yellow is chosen by a [`BlackGeneration`](trait.BlackGeneration.html)
+//! strategy, and the colored inks are reduced accordingly, which is known as undercolor removal.
+//!
+//! The conversions provided here are naive and device independent. Real printing processes are described
+//! by profiles, which can be plugged in by implementing [`CmykProfile`](trait.CmykProfile.html).
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The CMYK (cyan, magenta, yellow, black) color space
+    ///
+    /// The standard `From` conversions use the naive model with [`MaximumBlack`](struct.MaximumBlack.html)
+    /// black generation. Use [`from_cmy_with`](#method.from_cmy_with) to choose another strategy, or
+    /// [`from_xyz_with`](#method.from_xyz_with) to convert with a profile.
+    struct Cmyk : QuadChannel => CMYK {
+        /// The amount of cyan ink, which absorbs red light.
+        pub c,
+        /// The amount of magenta ink, which absorbs green light.
+        pub m,
+        /// The amount of yellow ink, which absorbs blue light.
+        pub y,
+        /// The amount of black ink.
+        pub k,
+    }
+}
+
+pub type Cmyka<C, Wp> = Alpha<Cmyk<C, Wp>>;
+
+use ::spaces::all::*;
+
+/// A black generation strategy, deciding how much black ink to use for a CMY color.
+///
+/// The colored inks are then reduced so that the combination of all four inks reproduces the CMY color,
+/// so any strategy round-trips exactly in the naive model.
+///
+/// Closures taking the CMY channels and returning the amount of black also implement this trait.
+pub trait BlackGeneration<T: Float> {
+    /// Computes the amount of black ink for the given CMY channels, between 0.0 and the smallest of the channels.
+    fn black(&self, cmy: [T; 3]) -> T;
+}
+
+/// Replaces the entire gray component with black ink, which minimizes ink usage.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MaximumBlack;
+
+/// Never uses black ink, leaving CMYK equivalent to CMY.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NoBlack;
+
+/// Replaces a fixed fraction of the gray component with black ink.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GrayComponentReplacement<T> {
+    /// The fraction of the gray component to replace, from 0.0 to 1.0
+    pub amount: T,
+}
+
+/// Only uses black ink in dark colors, increasing smoothly from none at the start of the curve
+/// to the entire gray component for black.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SkeletonBlack<T> {
+    /// The size of the gray component where black ink starts being used, from 0.0 to 1.0
+    pub start: T,
+}
+
+impl<T: Float> BlackGeneration<T> for MaximumBlack {
+    #[inline]
+    fn black(&self, [c, m, y]: [T; 3]) -> T {
+        c.min(m).min(y)
+    }
+}
+
+impl<T: Float> BlackGeneration<T> for NoBlack {
+    #[inline]
+    fn black(&self, _: [T; 3]) -> T {
+        T::zero()
+    }
+}
+
+impl<T: Float> BlackGeneration<T> for GrayComponentReplacement<T> {
+    #[inline]
+    fn black(&self, [c, m, y]: [T; 3]) -> T {
+        c.min(m).min(y) * self.amount
+    }
+}
+
+impl<T: Float> BlackGeneration<T> for SkeletonBlack<T> {
+    fn black(&self, [c, m, y]: [T; 3]) -> T {
+        let gray = c.min(m).min(y);
+
+        if gray <= self.start {
+            T::zero()
+        } else {
+            gray * (gray - self.start) / (T::one() - self.start)
+        }
+    }
+}
+
+impl<T: Float, F> BlackGeneration<T> for F
+where
+    F: Fn([T; 3]) -> T,
+{
+    #[inline]
+    fn black(&self, cmy: [T; 3]) -> T {
+        self(cmy)
+    }
+}
+
+/// Converts between CMYK and device independent colors.
+///
+/// This is the extension point for profile-based conversions, such as those described by ICC profiles
+/// for a particular press and paper.
+pub trait CmykProfile<C: Channel, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Converts XYZ to the CMYK values reproducing it.
+    fn xyz_to_cmyk(&self, xyz: Xyz<C, Wp>) -> Cmyk<C, Wp>;
+
+    /// Converts CMYK values to the XYZ color they reproduce.
+    fn cmyk_to_xyz(&self, cmyk: Cmyk<C, Wp>) -> Xyz<C, Wp>;
+}
+
+/// The naive profile, treating CMY as the complement of sRGB with the given black generation strategy.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct NaiveProfile<B = MaximumBlack> {
+    pub black_generation: B,
+}
+
+impl<B> NaiveProfile<B> {
+    pub fn new(black_generation: B) -> NaiveProfile<B> {
+        NaiveProfile { black_generation }
+    }
+}
+
+impl<C: Channel, Wp, B> CmykProfile<C, Wp> for NaiveProfile<B>
+where
+    Wp: WhitePoint<C>,
+    B: BlackGeneration<FloatChannel<C>>,
+{
+    fn xyz_to_cmyk(&self, xyz: Xyz<C, Wp>) -> Cmyk<C, Wp> {
+        Cmyk::from_cmy_with(Cmy::from(xyz), &self.black_generation)
+    }
+
+    fn cmyk_to_xyz(&self, cmyk: Cmyk<C, Wp>) -> Xyz<C, Wp> {
+        cmyk.into_cmy().into()
+    }
+}
+
+impl<C: Channel, Wp> Default for Cmyk<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Cmyk<C, Wp> {
+        Cmyk::with_wp(C::zero(), C::zero(), C::zero(), C::from_float(One::one()))
+    }
+}
+
+impl<C: Channel, Wp> Cmyk<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Separates a CMY color into CMYK using the given black generation strategy.
+    pub fn from_cmy_with<B>(cmy: Cmy<C, Wp>, black_generation: &B) -> Cmyk<C, Wp>
+    where
+        B: BlackGeneration<FloatChannel<C>>,
+    {
+        let cmy = cmy.into_float();
+
+        let one = FloatChannel::<C>::one();
+
+        let gray = cmy.c.min(cmy.m).min(cmy.y);
+        let k = black_generation.black([cmy.c, cmy.m, cmy.y]).max(Zero::zero()).min(gray);
+
+        let white = one - k;
+
+        if white <= Zero::zero() {
+            return Cmyk::from_float(Cmyk::raw(Zero::zero(), Zero::zero(), Zero::zero(), one));
+        }
+
+        Cmyk::from_float(Cmyk::raw((cmy.c - k) / white, (cmy.m - k) / white, (cmy.y - k) / white, k))
+    }
+
+    /// Combines the black ink back into the colored inks.
+    pub fn into_cmy(self) -> Cmy<C, Wp> {
+        let cmyk = self.into_float();
+
+        let white = FloatChannel::<C>::one() - cmyk.k;
+
+        Cmy::with_wp(Channel::from_float(cmyk.c * white + cmyk.k),
+                     Channel::from_float(cmyk.m * white + cmyk.k),
+                     Channel::from_float(cmyk.y * white + cmyk.k))
+    }
+
+    /// Converts from XYZ with the given profile.
+    #[inline]
+    pub fn from_xyz_with<P>(xyz: Xyz<C, Wp>, profile: &P) -> Cmyk<C, Wp>
+    where
+        P: CmykProfile<C, Wp>,
+    {
+        profile.xyz_to_cmyk(xyz)
+    }
+
+    /// Converts to XYZ with the given profile.
+    #[inline]
+    pub fn into_xyz_with<P>(self, profile: &P) -> Xyz<C, Wp>
+    where
+        P: CmykProfile<C, Wp>,
+    {
+        profile.cmyk_to_xyz(self)
+    }
+}
+
+impl<C: Channel, Wp> From<Cmy<C, Wp>> for Cmyk<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(cmy: Cmy<C, Wp>) -> Cmyk<C, Wp> {
+        Cmyk::from_cmy_with(cmy, &MaximumBlack)
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for Cmyk<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> Cmyk<C, Wp> {
+        Cmy::from(rgb).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Cmyk<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Cmyk<C, Wp> {
+        Cmy::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Cmyk<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Cmyk<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Cmyk<C, Wp>> for Cmy<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(cmyk: Cmyk<C, Wp>) -> Cmy<C, Wp> {
+        cmyk.into_cmy()
+    }
+}
+
+impl<C: Channel, Wp> From<Cmyk<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(cmyk: Cmyk<C, Wp>) -> Srgb<C, Wp> {
+        cmyk.into_cmy().into()
+    }
+}
+
+impl<C: Channel, Wp> From<Cmyk<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(cmyk: Cmyk<C, Wp>) -> Xyz<C, Wp> {
+        cmyk.into_cmy().into()
+    }
+}
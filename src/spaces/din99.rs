@@ -0,0 +1,131 @@
+//! The DIN99 color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The DIN99 color space (DIN 6176)
+    ///
+    /// DIN99 is a transformation of CIE L*a*b* that logarithmically compresses lightness and chroma
+    /// and stretches the a*b* plane slightly, so that Euclidean distances approximate the perceived
+    /// color difference about as well as the much more complex CIE94 formula. See
+    /// [`delta_e`](#method.delta_e).
+    struct Din99 : TripleChannel => DIN99 {
+        /// L99 is the lightness of the color, from 0.0 for black to 100.0 for white.
+        pub l in 0.0..100.0,
+        /// a99 is the red-green axis of the compressed chroma plane.
+        pub a in -50.0..50.0,
+        /// b99 is the yellow-blue axis of the compressed chroma plane.
+        pub b in -50.0..50.0,
+    }
+}
+
+pub type Din99a<C, Wp> = Alpha<Din99<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Din99<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Din99<C, Wp> {
+        Din99::from_float(Din99::raw(Zero::zero(), Zero::zero(), Zero::zero()))
+    }
+}
+
+impl<C: Channel, Wp> Din99<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Computes the ΔE99 color difference, the Euclidean distance between two DIN99 colors.
+    pub fn delta_e(self, other: Din99<C, Wp>) -> FloatChannel<C> {
+        let (a, b) = (self.into_float(), other.into_float());
+
+        ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+    }
+}
+
+impl<C: Channel, Wp> From<Lab<C, Wp>> for Din99<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(lab: Lab<C, Wp>) -> Din99<C, Wp> {
+        let lab = lab.into_float();
+
+        let c = math::cast::<FloatChannel<C>>;
+        let one = FloatChannel::<C>::one();
+
+        let (sin, cos) = c(16.0).to_radians().sin_cos();
+
+        let l = c(105.51) * (one + c(0.0158) * lab.l).ln();
+
+        let e = lab.a * cos + lab.b * sin;
+        let f = c(0.7) * (lab.b * cos - lab.a * sin);
+        let g = e.hypot(f);
+
+        let chroma = (one + c(0.045) * g).ln() / c(0.045);
+
+        let (a, b) = if g.is_normal() { (chroma * e / g, chroma * f / g) } else { (Zero::zero(), Zero::zero()) };
+
+        Din99::from_float(Din99::raw(l, a, b))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Din99<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Din99<C, Wp> {
+        Lab::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Din99<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Din99<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Din99<C, Wp>> for Lab<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(din: Din99<C, Wp>) -> Lab<C, Wp> {
+        let din = din.into_float();
+
+        let c = math::cast::<FloatChannel<C>>;
+        let one = FloatChannel::<C>::one();
+
+        let (sin, cos) = c(16.0).to_radians().sin_cos();
+
+        let chroma = din.a.hypot(din.b);
+        let g = ((c(0.045) * chroma).exp() - one) / c(0.045);
+
+        let (e, f) = if chroma.is_normal() { (g * din.a / chroma, g * din.b / chroma / c(0.7)) } else { (Zero::zero(), Zero::zero()) };
+
+        Lab::from_float(Lab::raw(((din.l / c(105.51)).exp() - one) / c(0.0158),
+                                 e * cos - f * sin,
+                                 e * sin + f * cos))
+    }
+}
+
+impl<C: Channel, Wp> From<Din99<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(din: Din99<C, Wp>) -> Xyz<C, Wp> {
+        Lab::from(din).into()
+    }
+}
@@ -0,0 +1,145 @@
+//! The DIN99d color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The DIN99d color space
+    ///
+    /// DIN99d is a refinement of [`Din99`](../din99/struct.Din99.html) by Cui et al. that modifies the
+    /// X tristimulus value before computing L*a*b* to better predict differences between blue colors,
+    /// and is fitted to more recent color difference data. Euclidean distances approximate the perceived
+    /// color difference about as well as CIEDE2000. See [`delta_e`](#method.delta_e).
+    struct Din99d : TripleChannel => DIN99D {
+        /// L99d is the lightness of the color, from 0.0 for black to 100.0 for white.
+        pub l in 0.0..100.0,
+        /// a99d is the red-green axis of the compressed chroma plane.
+        pub a in -50.0..50.0,
+        /// b99d is the yellow-blue axis of the compressed chroma plane.
+        pub b in -50.0..50.0,
+    }
+}
+
+pub type Din99da<C, Wp> = Alpha<Din99d<C, Wp>>;
+
+use ::spaces::all::*;
+use ::spaces::lab;
+
+impl<C: Channel, Wp> Default for Din99d<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Din99d<C, Wp> {
+        Din99d::from_float(Din99d::raw(Zero::zero(), Zero::zero(), Zero::zero()))
+    }
+}
+
+impl<C: Channel, Wp> Din99d<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Computes the ΔE99d color difference, the Euclidean distance between two DIN99d colors.
+    pub fn delta_e(self, other: Din99d<C, Wp>) -> FloatChannel<C> {
+        let (a, b) = (self.into_float(), other.into_float());
+
+        ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+    }
+}
+
+/// Scale of the modified X value relative to the white point, `X' = 1.12 X - 0.12 Z` over `X'` of the white
+#[inline]
+fn x_scale<C: Channel, Wp>() -> (FloatChannel<C>, FloatChannel<C>)
+where
+    Wp: WhitePoint<C>,
+{
+    let [x, _, z] = Wp::float_xyz();
+
+    (x, x * math::cast(1.12) - z * math::cast(0.12))
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Din99d<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Din99d<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let c = math::cast::<FloatChannel<C>>;
+        let one = FloatChannel::<C>::one();
+
+        // L*a*b* of the modified X, expressed relative to the unmodified white
+        let (white_x, white_x_mod) = x_scale::<C, Wp>();
+        let x = (xyz.x * c(1.12) - xyz.z * c(0.12)) * white_x / white_x_mod;
+
+        let [l, a, b] = lab::xyz_to_lab([x, xyz.y, xyz.z], Wp::float_xyz());
+
+        let (sin, cos) = c(50.0).to_radians().sin_cos();
+
+        let l = c(325.22) * (one + c(0.0036) * l).ln();
+
+        let e = a * cos + b * sin;
+        let f = c(1.14) * (b * cos - a * sin);
+        let g = e.hypot(f);
+
+        let chroma = c(22.5) * (one + c(0.06) * g).ln();
+
+        // The hue is rotated back by the same angle
+        let (a, b) = if g.is_normal() {
+            (chroma * (e * cos - f * sin) / g, chroma * (e * sin + f * cos) / g)
+        } else {
+            (Zero::zero(), Zero::zero())
+        };
+
+        Din99d::from_float(Din99d::raw(l, a, b))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Din99d<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Din99d<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Din99d<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(din: Din99d<C, Wp>) -> Xyz<C, Wp> {
+        let din = din.into_float();
+
+        let c = math::cast::<FloatChannel<C>>;
+        let one = FloatChannel::<C>::one();
+
+        let (sin, cos) = c(50.0).to_radians().sin_cos();
+
+        let chroma = din.a.hypot(din.b);
+        let g = ((chroma / c(22.5)).exp() - one) / c(0.06);
+
+        let (e, f) = if chroma.is_normal() {
+            (g * (din.a * cos + din.b * sin) / chroma, g * (din.b * cos - din.a * sin) / chroma / c(1.14))
+        } else {
+            (Zero::zero(), Zero::zero())
+        };
+
+        let lab = [((din.l / c(325.22)).exp() - one) / c(0.0036), e * cos - f * sin, e * sin + f * cos];
+
+        let [x, y, z] = lab::lab_to_xyz(lab, Wp::float_xyz());
+
+        let (white_x, white_x_mod) = x_scale::<C, Wp>();
+        let x = (x * white_x_mod / white_x + z * c(0.12)) / c(1.12);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
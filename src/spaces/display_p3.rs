@@ -0,0 +1,96 @@
+//! The Display P3 color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::rgb;
+use super::primaries::{Primaries, P3D65};
+
+/// Chromaticities of the DCI-P3 red, green and blue primaries
+pub const P3_PRIMARIES: [[f64; 2]; 3] = P3D65::PRIMARIES;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The Display P3 color space
+    ///
+    /// Display P3 combines the wide-gamut DCI-P3 primaries with the D65 white point and the
+    /// sRGB transfer function. It is the native color space of most recent Apple displays,
+    /// and covers about 25% more colors than sRGB.
+    ///
+    /// Channels are stored gamma-encoded, and range from 0.0 to 1.0 for floating point channels.
+    struct DisplayP3 : TripleChannel => RGB {
+        /// The gamma-encoded amount of red light.
+        pub r,
+        /// The gamma-encoded amount of green light.
+        pub g,
+        /// The gamma-encoded amount of blue light.
+        pub b,
+    }
+}
+
+pub type DisplayP3a<C, Wp> = Alpha<DisplayP3<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for DisplayP3<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> DisplayP3<C, Wp> {
+        DisplayP3::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for DisplayP3<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> DisplayP3<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let m = rgb::from_xyz_matrix::<C, Wp>(&P3_PRIMARIES);
+        let [r, g, b] = math::mul_vec(&m, [xyz.x, xyz.y, xyz.z]);
+
+        DisplayP3::from_float(DisplayP3::raw(rgb::srgb_encode(r), rgb::srgb_encode(g), rgb::srgb_encode(b)))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for DisplayP3<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> DisplayP3<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for DisplayP3<C, Wp>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    fn from(srgb: Srgb<C, Wp>) -> DisplayP3<C, Wp> {
+        DisplayP3::from_float(Xyz::from(srgb.into_float()).into())
+    }
+}
+
+impl<C: Channel, Wp> From<DisplayP3<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: DisplayP3<C, Wp>) -> Xyz<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let m = rgb::to_xyz_matrix::<C, Wp>(&P3_PRIMARIES);
+        let [x, y, z] = math::mul_vec(&m, [rgb::srgb_decode(rgb.r), rgb::srgb_decode(rgb.g), rgb::srgb_decode(rgb.b)]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
@@ -0,0 +1,229 @@
+//! Colors with an arbitrary number of channels.
+
+use std::ops::{Deref, DerefMut, Add, Sub, Mul, Div};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::Zero;
+
+use generic_array::{GenericArray, ArrayLength};
+use numeric_array::NumericArray;
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::{WhitePoint, D65};
+
+/// A color with `N` channels of no particular meaning
+///
+/// `GenericColor` carries channel data that does not fit any of the fixed color spaces, such as
+/// multispectral captures or CMYK with additional spot colors. The number of channels is given
+/// as a type-level integer, for example `GenericColor<f32, U6>` for six channels.
+///
+/// As the channels have no defined meaning, there are no conversions to or from other color spaces.
+/// The channels can be accessed by index or as a slice, and colors can be added, subtracted and
+/// scaled channel-wise.
+#[repr(C)]
+pub struct GenericColor<C: Channel, N: ArrayLength<C>, Wp = D65> {
+    channels: NumericArray<C, N>,
+    white_point: PhantomData<Wp>,
+}
+
+pub type GenericColora<C, N, Wp> = Alpha<GenericColor<C, N, Wp>>;
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Debug for GenericColor<C, N, Wp>
+where
+    C: Debug
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("GenericColor").field("channels", &self.channels).finish()
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Clone for GenericColor<C, N, Wp> {
+    fn clone(&self) -> GenericColor<C, N, Wp> {
+        GenericColor { channels: self.channels.clone(), white_point: PhantomData }
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Copy for GenericColor<C, N, Wp> where N::ArrayType: Copy {}
+
+impl<C: Channel, N: ArrayLength<C>> GenericColor<C, N, D65> {
+    #[inline(always)]
+    pub fn new(channels: GenericArray<C, N>) -> GenericColor<C, N, D65> {
+        GenericColor::with_wp(channels)
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> GenericColor<C, N, Wp> {
+    #[inline]
+    fn raw(channels: GenericArray<C, N>) -> GenericColor<C, N, Wp> {
+        GenericColor { channels: channels.into(), white_point: PhantomData }
+    }
+
+    /// Creates a color from a slice of channels, or returns `None` if the slice
+    /// does not have exactly `N` channels.
+    pub fn from_slice(channels: &[C]) -> Option<GenericColor<C, N, Wp>> {
+        GenericArray::from_exact_iter(channels.iter().cloned()).map(Self::raw)
+    }
+
+    /// Returns the number of channels, `N`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        N::to_usize()
+    }
+
+    /// Returns `true` if the color has no channels.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        N::to_usize() == 0
+    }
+
+    #[inline]
+    pub fn into_float(self) -> GenericColor<FloatChannel<C>, N, Wp>
+    where
+        N: ArrayLength<FloatChannel<C>>,
+    {
+        GenericColor {
+            channels: self.channels.into_array().map(Channel::into_float).into(),
+            white_point: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn from_float(fcolor: GenericColor<FloatChannel<C>, N, Wp>) -> Self
+    where
+        N: ArrayLength<FloatChannel<C>>,
+    {
+        GenericColor {
+            channels: fcolor.channels.into_array().map(Channel::from_float).into(),
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> GenericColor<C, N, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    #[inline(always)]
+    pub fn with_wp(channels: GenericArray<C, N>) -> GenericColor<C, N, Wp> {
+        Self::raw(channels)
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Deref for GenericColor<C, N, Wp> {
+    type Target = GenericArray<C, N>;
+
+    #[inline]
+    fn deref(&self) -> &GenericArray<C, N> {
+        self.as_components()
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> DerefMut for GenericColor<C, N, Wp> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut GenericArray<C, N> {
+        self.as_components_mut()
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Components for GenericColor<C, N, Wp> {
+    type Components = GenericArray<C, N>;
+
+    #[inline]
+    fn as_components(&self) -> &Self::Components {
+        self.channels.as_array()
+    }
+
+    #[inline]
+    fn as_components_mut(&mut self) -> &mut Self::Components {
+        self.channels.as_mut_array()
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> From<GenericArray<C, N>> for GenericColor<C, N, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(channels: GenericArray<C, N>) -> GenericColor<C, N, Wp> {
+        GenericColor::with_wp(channels)
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Color for GenericColor<C, N, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    type Channel = C;
+    type Channels = NumericArray<C, N>;
+    type WhitePoint = Wp;
+
+    fn from_channels(channels: NumericArray<C, N>) -> GenericColor<C, N, Wp> {
+        GenericColor { channels, white_point: PhantomData }
+    }
+
+    fn channels(&self) -> &NumericArray<C, N> {
+        &self.channels
+    }
+
+    fn channels_mut(&mut self) -> &mut NumericArray<C, N> {
+        &mut self.channels
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>> Alpha<GenericColor<C, N, D65>> {
+    pub fn new(channels: GenericArray<C, N>, alpha: C) -> Alpha<GenericColor<C, N, D65>> {
+        Alpha::from_color(GenericColor::new(channels), alpha)
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Alpha<GenericColor<C, N, Wp>>
+where
+    Wp: WhitePoint<C>,
+{
+    pub fn with_wp(channels: GenericArray<C, N>, alpha: C) -> Alpha<GenericColor<C, N, Wp>> {
+        Alpha::from_color(GenericColor::with_wp(channels), alpha)
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Default for GenericColor<C, N, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> GenericColor<C, N, Wp> {
+        GenericColor::with_wp(GenericArray::generate(|_| C::zero()))
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Add for GenericColor<C, N, Wp> {
+    type Output = GenericColor<C, N, Wp>;
+
+    fn add(self, rhs: GenericColor<C, N, Wp>) -> GenericColor<C, N, Wp> {
+        GenericColor::raw(self.channels.into_array().zip(rhs.channels.into_array(), |a, b| a + b))
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Sub for GenericColor<C, N, Wp> {
+    type Output = GenericColor<C, N, Wp>;
+
+    fn sub(self, rhs: GenericColor<C, N, Wp>) -> GenericColor<C, N, Wp> {
+        GenericColor::raw(self.channels.into_array().zip(rhs.channels.into_array(), |a, b| a - b))
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Mul<C> for GenericColor<C, N, Wp> {
+    type Output = GenericColor<C, N, Wp>;
+
+    fn mul(self, rhs: C) -> GenericColor<C, N, Wp> {
+        GenericColor::raw(self.channels.into_array().map(|a| a * rhs))
+    }
+}
+
+impl<C: Channel, N: ArrayLength<C>, Wp> Div<C> for GenericColor<C, N, Wp> {
+    type Output = GenericColor<C, N, Wp>;
+
+    fn div(self, rhs: C) -> GenericColor<C, N, Wp> {
+        GenericColor::raw(self.channels.into_array().map(|a| a / rhs))
+    }
+}
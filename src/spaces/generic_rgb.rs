@@ -0,0 +1,257 @@
+//! RGB color spaces with any primaries and transfer function.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut, Add, Sub, Mul, Div};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::{WhitePoint, D65};
+use gamma::{TransferFunction, Encoding, SrgbTransfer, Linear, Pq, Hlg};
+use math;
+
+use super::rgb;
+use super::primaries::{Primaries, Rec709, Rec2020};
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// An RGB color space defined by its primaries `P` and transfer function `E`
+    ///
+    /// `Rgb` describes any RGB color space that can be defined by the chromaticities of its primaries and
+    /// a transfer function, such as `Rgb<f32, D65, Rec2020, Linear>` for linear BT.2020. As with
+    /// [`Srgb`](../srgb/struct.Srgb.html), the conversion matrices are derived relative to the white point
+    /// of the color rather than the native white of the primaries.
+    ///
+    /// The channels are encoded with the transfer function, and range from 0.0 to 1.0 for floating point
+    /// channels, or over the full range of integer channels.
+    ///
+    /// Arithmetic, blending and matrix transforms are only implemented for the [`Linear`](../../gamma/struct.Linear.html)
+    /// encoding, so encoded values must be decoded with [`into_linear`](#method.into_linear) first.
+    struct Rgb<P: Primaries = Rec709, E: Encoding = SrgbTransfer> : TripleChannel => RGB {
+        /// The encoded amount of red light.
+        pub r,
+        /// The encoded amount of green light.
+        pub g,
+        /// The encoded amount of blue light.
+        pub b,
+    }
+}
+
+/// Linear light with the BT.2020 primaries
+pub type LinearRec2020<C = f32, Wp = D65> = Rgb<C, Wp, Rec2020, Linear>;
+
+/// BT.2100 PQ, the BT.2020 primaries encoded with the PQ transfer function,
+/// where a linear value of 1.0 is 10000 cd/m²
+pub type Rec2100Pq<C = f32, Wp = D65> = Rgb<C, Wp, Rec2020, Pq>;
+
+/// BT.2100 HLG, the BT.2020 primaries encoded with the HLG transfer function,
+/// where linear values are normalized scene light
+pub type Rec2100Hlg<C = f32, Wp = D65> = Rgb<C, Wp, Rec2020, Hlg>;
+
+pub type Rgba<C, Wp, P, E> = Alpha<Rgb<C, Wp, P, E>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp, P: Primaries, E: Encoding> Rgb<C, Wp, P, E>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Decodes the color into linear light with the given instance of the transfer function,
+    /// such as a [`PowerGamma`](../../gamma/struct.PowerGamma.html) with a runtime exponent.
+    pub fn into_linear_with(self, transfer: &E) -> Rgb<C, Wp, P, Linear> {
+        let rgb = self.into_float();
+
+        Rgb::with_wp(Channel::from_float(transfer.decode(rgb.r)),
+                     Channel::from_float(transfer.decode(rgb.g)),
+                     Channel::from_float(transfer.decode(rgb.b)))
+    }
+
+    /// Encodes a color of linear light with the given instance of the transfer function.
+    pub fn from_linear_with(linear: Rgb<C, Wp, P, Linear>, transfer: &E) -> Rgb<C, Wp, P, E> {
+        let rgb = linear.into_float();
+
+        Rgb::with_wp(Channel::from_float(transfer.encode(rgb.r)),
+                     Channel::from_float(transfer.encode(rgb.g)),
+                     Channel::from_float(transfer.encode(rgb.b)))
+    }
+
+    /// Decodes the color into linear light.
+    pub fn into_linear(self) -> Rgb<C, Wp, P, Linear> {
+        self.into_transfer()
+    }
+
+    /// Encodes a color of linear light with the transfer function.
+    pub fn from_linear(linear: Rgb<C, Wp, P, Linear>) -> Rgb<C, Wp, P, E> {
+        linear.into_transfer()
+    }
+
+    /// Re-encodes the color with another transfer function.
+    pub fn into_transfer<E2: Encoding>(self) -> Rgb<C, Wp, P, E2> {
+        let rgb = self.into_float();
+
+        let (from, to) = (E::default(), E2::default());
+
+        Rgb::with_wp(Channel::from_float(to.encode(from.decode(rgb.r))),
+                     Channel::from_float(to.encode(from.decode(rgb.g))),
+                     Channel::from_float(to.encode(from.decode(rgb.b))))
+    }
+}
+
+impl<C: Channel, Wp, P: Primaries> Rgb<C, Wp, P, Linear>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Linearly interpolates between two colors, where `t` of 0.0 is `self` and 1.0 is `other`.
+    pub fn mix(self, other: Rgb<C, Wp, P, Linear>, t: FloatChannel<C>) -> Rgb<C, Wp, P, Linear> {
+        let (a, b) = (self.into_float(), other.into_float());
+
+        Rgb::from_float(Rgb::raw(a.r + (b.r - a.r) * t,
+                                 a.g + (b.g - a.g) * t,
+                                 a.b + (b.b - a.b) * t))
+    }
+
+    /// Multiplies the channels by a 3x3 matrix, such as to apply a color correction.
+    pub fn transform(self, m: &math::Matrix3<f64>) -> Rgb<C, Wp, P, Linear> {
+        let rgb = self.into_float();
+
+        let [r, g, b] = math::mul_vec(&math::matrix(m), [rgb.r, rgb.g, rgb.b]);
+
+        Rgb::from_float(Rgb::raw(r, g, b))
+    }
+}
+
+impl<C: Channel, Wp, P> Add for Rgb<C, Wp, P, Linear> {
+    type Output = Rgb<C, Wp, P, Linear>;
+
+    fn add(self, rhs: Rgb<C, Wp, P, Linear>) -> Rgb<C, Wp, P, Linear> {
+        Rgb::raw(self.r + rhs.r, self.g + rhs.g, self.b + rhs.b)
+    }
+}
+
+impl<C: Channel, Wp, P> Sub for Rgb<C, Wp, P, Linear> {
+    type Output = Rgb<C, Wp, P, Linear>;
+
+    fn sub(self, rhs: Rgb<C, Wp, P, Linear>) -> Rgb<C, Wp, P, Linear> {
+        Rgb::raw(self.r - rhs.r, self.g - rhs.g, self.b - rhs.b)
+    }
+}
+
+impl<C: Channel, Wp, P> Mul<C> for Rgb<C, Wp, P, Linear> {
+    type Output = Rgb<C, Wp, P, Linear>;
+
+    fn mul(self, rhs: C) -> Rgb<C, Wp, P, Linear> {
+        Rgb::raw(self.r * rhs, self.g * rhs, self.b * rhs)
+    }
+}
+
+impl<C: Channel, Wp, P> Mul for Rgb<C, Wp, P, Linear> {
+    type Output = Rgb<C, Wp, P, Linear>;
+
+    fn mul(self, rhs: Rgb<C, Wp, P, Linear>) -> Rgb<C, Wp, P, Linear> {
+        Rgb::raw(self.r * rhs.r, self.g * rhs.g, self.b * rhs.b)
+    }
+}
+
+impl<C: Channel, Wp, P> Div for Rgb<C, Wp, P, Linear> {
+    type Output = Rgb<C, Wp, P, Linear>;
+
+    fn div(self, rhs: Rgb<C, Wp, P, Linear>) -> Rgb<C, Wp, P, Linear> {
+        Rgb::raw(self.r / rhs.r, self.g / rhs.g, self.b / rhs.b)
+    }
+}
+
+impl<C: Channel, Wp, P> Div<C> for Rgb<C, Wp, P, Linear> {
+    type Output = Rgb<C, Wp, P, Linear>;
+
+    fn div(self, rhs: C) -> Rgb<C, Wp, P, Linear> {
+        Rgb::raw(self.r / rhs, self.g / rhs, self.b / rhs)
+    }
+}
+
+impl<C: Channel, Wp, P: Primaries, E: Encoding> Default for Rgb<C, Wp, P, E>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Rgb<C, Wp, P, E> {
+        Rgb::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp, P: Primaries, E: Encoding> From<Xyz<C, Wp>> for Rgb<C, Wp, P, E>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Rgb<C, Wp, P, E> {
+        let xyz = xyz.into_float();
+
+        let m = rgb::from_xyz_matrix::<C, Wp>(&P::PRIMARIES);
+        let [r, g, b] = math::mul_vec(&m, [xyz.x, xyz.y, xyz.z]);
+
+        let e = E::default();
+
+        Rgb::from_float(Rgb::raw(e.encode(r), e.encode(g), e.encode(b)))
+    }
+}
+
+impl<C: Channel, Wp, P: Primaries, E: Encoding> From<Yxy<C, Wp>> for Rgb<C, Wp, P, E>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Rgb<C, Wp, P, E> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp, P: Primaries, E: Encoding> From<Rgb<C, Wp, P, E>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Rgb<C, Wp, P, E>) -> Xyz<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let e = E::default();
+
+        let m = rgb::to_xyz_matrix::<C, Wp>(&P::PRIMARIES);
+        let [x, y, z] = math::mul_vec(&m, [e.decode(rgb.r), e.decode(rgb.g), e.decode(rgb.b)]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for Rgb<C, Wp, Rec709, SrgbTransfer>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(srgb: Srgb<C, Wp>) -> Rgb<C, Wp, Rec709, SrgbTransfer> {
+        Rgb::with_wp(srgb.r, srgb.g, srgb.b)
+    }
+}
+
+impl<C: Channel, Wp> From<Rgb<C, Wp, Rec709, SrgbTransfer>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Rgb<C, Wp, Rec709, SrgbTransfer>) -> Srgb<C, Wp> {
+        Srgb::with_wp(rgb.r, rgb.g, rgb.b)
+    }
+}
+
+impl<C: Channel, Wp> From<LinearSrgb<C, Wp>> for Rgb<C, Wp, Rec709, Linear>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(linear: LinearSrgb<C, Wp>) -> Rgb<C, Wp, Rec709, Linear> {
+        Rgb::with_wp(linear.r, linear.g, linear.b)
+    }
+}
+
+impl<C: Channel, Wp> From<Rgb<C, Wp, Rec709, Linear>> for LinearSrgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Rgb<C, Wp, Rec709, Linear>) -> LinearSrgb<C, Wp> {
+        LinearSrgb::with_wp(rgb.r, rgb.g, rgb.b)
+    }
+}
@@ -0,0 +1,134 @@
+//! The HSI color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float, ToPrimitive};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::rgb;
+use super::srgb::components::RGB;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The HSI (hue, saturation, intensity) color space
+    ///
+    /// HSI is commonly used in machine vision and image processing. Unlike HSL and HSV, its hue is
+    /// the geometric angle around the gray axis of the RGB cube, and its intensity is the plain
+    /// average of the three channels, which decouples intensity from chromatic information.
+    ///
+    /// HSI colors are derived from gamma-encoded sRGB, and share its white point.
+    struct Hsi : TripleChannel => HSI {
+        /// The hue of the color, in degrees. 0.0 is red, 120.0 is green and 240.0 is blue.
+        pub h: Deg,
+        /// The saturation of the color, from 0.0 for gray to 1.0 for fully saturated colors.
+        pub s,
+        /// The intensity of the color, the average of its red, green and blue channels.
+        pub i,
+    }
+}
+
+pub type Hsia<C, Wp> = Alpha<Hsi<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Hsi<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Hsi<C, Wp> {
+        Hsi::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for Hsi<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> Hsi<C, Wp> {
+        let RGB { r, g, b } = *rgb.into_float();
+
+        let one = FloatChannel::<C>::one();
+
+        let i = (r + g + b) / math::cast(3.0);
+        let min = r.min(g).min(b);
+
+        let s = if i.is_normal() { one - min / i } else { Zero::zero() };
+
+        let num = ((r - g) + (r - b)) / math::cast(2.0);
+        let den = ((r - g) * (r - g) + (r - b) * (g - b)).sqrt();
+
+        let h = if den.is_normal() {
+            let theta = (num / den).max(-one).min(one).acos().to_degrees();
+
+            if b > g { math::normalize_hue(-theta) } else { theta }
+        } else {
+            Zero::zero()
+        };
+
+        Hsi::from_float(Hsi::raw(h, s, i))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Hsi<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Hsi<C, Wp> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Hsi<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Hsi<C, Wp> {
+        Srgb::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Hsi<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsi: Hsi<C, Wp>) -> Srgb<C, Wp> {
+        let HSI { h, s, i } = *hsi.into_float();
+
+        let one = FloatChannel::<C>::one();
+        let third: FloatChannel<C> = math::cast(120.0);
+
+        let h = h.degrees();
+
+        // Each 120 degree sector has one channel at the minimum,
+        // and the other two are placed relative to the start of the sector.
+        let sector = (h / third).floor();
+        let h = h - sector * third;
+
+        let low = i * (one - s);
+        let high = i * (one + s * h.to_radians().cos() / (third / math::cast(2.0) - h).to_radians().cos());
+        let rest = i * math::cast(3.0) - (low + high);
+
+        let [r, g, b] = match sector.to_u8().unwrap_or(0) {
+            0 => [high, rest, low],
+            1 => [low, high, rest],
+            _ => [rest, low, high],
+        };
+
+        Srgb::with_wp(Channel::from_float(r), Channel::from_float(g), Channel::from_float(b))
+    }
+}
+
+impl<C: Channel, Wp> From<Hsi<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsi: Hsi<C, Wp>) -> Xyz<C, Wp> {
+        Srgb::from(hsi).into()
+    }
+}
@@ -0,0 +1,130 @@
+//! The HSL color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::rgb;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The HSL (hue, saturation, lightness) color space
+    ///
+    /// HSL is a cylindrical rearrangement of the sRGB color cube, with pure hues at the middle of the
+    /// lightness axis, black at the bottom and white at the top. It is not perceptually uniform, but is
+    /// intuitive enough for picking and adjusting colors by hand.
+    ///
+    /// HSL colors are derived from gamma-encoded sRGB, and share its white point.
+    struct Hsl : TripleChannel => HSL {
+        /// The hue of the color, in degrees. 0.0 is red, 120.0 is green and 240.0 is blue.
+        pub h: Deg,
+        /// The saturation of the color, from 0.0 for gray to 1.0 for fully saturated colors.
+        pub s,
+        /// The lightness of the color, from 0.0 for black to 1.0 for white.
+        pub l,
+    }
+}
+
+pub type Hsla<C, Wp> = Alpha<Hsl<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Hsl<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Hsl<C, Wp> {
+        Hsl::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for Hsl<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> Hsl<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let (h, max, min) = rgb::hue_max_min(rgb.r, rgb.g, rgb.b);
+
+        let one = FloatChannel::<C>::one();
+
+        let l = (max + min) / math::cast(2.0);
+        let d = one - (l * math::cast(2.0) - one).abs();
+
+        let s = if d.is_normal() { (max - min) / d } else { Zero::zero() };
+
+        Hsl::from_float(Hsl::raw(h, s, l))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Hsl<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Hsl<C, Wp> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Hsl<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Hsl<C, Wp> {
+        Srgb::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Hsl<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsl: Hsl<C, Wp>) -> Srgb<C, Wp> {
+        let hsl = hsl.into_float();
+
+        let one = FloatChannel::<C>::one();
+
+        let c = (one - (hsl.l * math::cast(2.0) - one).abs()) * hsl.s;
+
+        let [r, g, b] = rgb::from_hue_chroma(hsl.h.degrees(), c, hsl.l - c / math::cast(2.0));
+
+        Srgb::with_wp(Channel::from_float(r), Channel::from_float(g), Channel::from_float(b))
+    }
+}
+
+impl<C: Channel, Wp> From<Hsl<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsl: Hsl<C, Wp>) -> Xyz<C, Wp> {
+        Srgb::from(hsl).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_round_trip() {
+        let green = Hsl::<u8>::from(Srgb::<u8>::new(0, 255, 0));
+        assert_eq!((green.h.into_float().degrees(), green.s, green.l), (120.0, 255, 128));
+
+        // A step of an 8-bit hue is about 1.4 degrees, which moves saturated channels by up to 3
+        for &(r, g, b) in &[(0, 255, 0), (255, 0, 0), (0, 0, 255), (255, 255, 0), (200, 100, 50), (128, 128, 128)] {
+            let rgb = Srgb::<u8>::from(Hsl::<u8>::from(Srgb::<u8>::new(r, g, b)));
+
+            assert!((rgb.r as i32 - r as i32).abs() <= 3, "{:?}", (r, g, b));
+            assert!((rgb.g as i32 - g as i32).abs() <= 3, "{:?}", (r, g, b));
+            assert!((rgb.b as i32 - b as i32).abs() <= 3, "{:?}", (r, g, b));
+        }
+    }
+}
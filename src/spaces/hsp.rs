@@ -0,0 +1,149 @@
+//! The HSP color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::{WhitePoint, D65};
+use math::{self, Vector3};
+
+use super::rgb;
+use super::ycbcr::{YCbCrStandard, Bt601, Bt709};
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The HSP (hue, saturation, perceived brightness) color space
+    ///
+    /// HSP shares its hue and saturation with [`Hsv`](../hsv/struct.Hsv.html), but replaces the value axis
+    /// with the perceived brightness of the color, the root of the squared sRGB channels weighted by the luma
+    /// coefficients of the standard `S`. Unlike the lightness of HSL and the value of HSV, pure blue is much
+    /// darker than pure yellow in HSP, which makes brightness useful for sorting colors or choosing legible
+    /// text over a thumbnail.
+    ///
+    /// Saturated colors may not reach a brightness of 1.0 within sRGB, so converting bright saturated HSP
+    /// colors back to sRGB may produce channels above 1.0.
+    ///
+    /// HSP colors are derived from gamma-encoded sRGB, and share its white point.
+    struct Hsp<S: YCbCrStandard = Bt601> : TripleChannel => HSP {
+        /// The hue of the color, in degrees. 0.0 is red, 120.0 is green and 240.0 is blue.
+        pub h: Deg,
+        /// The saturation of the color, from 0.0 for gray to 1.0 for fully saturated colors.
+        pub s,
+        /// The perceived brightness of the color, from 0.0 for black to 1.0 for white.
+        pub p,
+    }
+}
+
+/// HSP with the BT.601 luma coefficients, as originally proposed
+pub type Hsp601<C = f32, Wp = D65> = Hsp<C, Wp, Bt601>;
+
+/// HSP with the BT.709 luma coefficients
+pub type Hsp709<C = f32, Wp = D65> = Hsp<C, Wp, Bt709>;
+
+pub type Hspa<C, Wp, S> = Alpha<Hsp<C, Wp, S>>;
+
+use ::spaces::all::*;
+
+/// Computes the perceived brightness of gamma-encoded RGB channels with the luma weights of `S`.
+#[inline]
+fn brightness<T: Float, S: YCbCrStandard>([r, g, b]: Vector3<T>) -> T {
+    let (kr, kb): (T, T) = (math::cast(S::KR), math::cast(S::KB));
+    let kg = T::one() - kr - kb;
+
+    (kr * r * r + kg * g * g + kb * b * b).sqrt()
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> Default for Hsp<C, Wp, S>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Hsp<C, Wp, S> {
+        Hsp::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<Srgb<C, Wp>> for Hsp<C, Wp, S>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> Hsp<C, Wp, S> {
+        let rgb = rgb.into_float();
+
+        let (h, max, min) = rgb::hue_max_min(rgb.r, rgb.g, rgb.b);
+
+        let s = if max.is_normal() { (max - min) / max } else { Zero::zero() };
+        let p = brightness::<_, S>([rgb.r, rgb.g, rgb.b]);
+
+        Hsp::from_float(Hsp::raw(h, s, p))
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<Hsv<C, Wp>> for Hsp<C, Wp, S>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsv: Hsv<C, Wp>) -> Hsp<C, Wp, S> {
+        Srgb::from(hsv).into()
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<Xyz<C, Wp>> for Hsp<C, Wp, S>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Hsp<C, Wp, S> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<Yxy<C, Wp>> for Hsp<C, Wp, S>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Hsp<C, Wp, S> {
+        Srgb::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<Hsp<C, Wp, S>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsp: Hsp<C, Wp, S>) -> Srgb<C, Wp> {
+        let hsp = hsp.into_float();
+
+        let one = FloatChannel::<C>::one();
+
+        // The hue and saturation fix the ratios between the channels, and brightness scales linearly
+        // with them, so find the color with a largest channel of 1.0 and scale it to the brightness.
+        let unit = rgb::from_hue_chroma(hsp.h.degrees(), hsp.s, one - hsp.s);
+
+        let scale = hsp.p / brightness::<_, S>(unit);
+
+        Srgb::with_wp(Channel::from_float(unit[0] * scale),
+                      Channel::from_float(unit[1] * scale),
+                      Channel::from_float(unit[2] * scale))
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<Hsp<C, Wp, S>> for Hsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsp: Hsp<C, Wp, S>) -> Hsv<C, Wp> {
+        Srgb::from(hsp).into()
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<Hsp<C, Wp, S>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsp: Hsp<C, Wp, S>) -> Xyz<C, Wp> {
+        Srgb::from(hsp).into()
+    }
+}
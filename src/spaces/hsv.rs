@@ -0,0 +1,138 @@
+//! The HSV color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::rgb;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The HSV (hue, saturation, value) color space, also known as HSB
+    ///
+    /// HSV is a cylindrical rearrangement of the sRGB color cube, with black at the bottom of the value
+    /// axis and pure hues and white at the top. It models mixing paint, where saturation is the amount of
+    /// pigment and value is the amount of black added, which makes it popular for color pickers.
+    ///
+    /// HSV colors are derived from gamma-encoded sRGB, and share its white point.
+    struct Hsv : TripleChannel => HSV {
+        /// The hue of the color, in degrees. 0.0 is red, 120.0 is green and 240.0 is blue.
+        pub h: Deg,
+        /// The saturation of the color, from 0.0 for gray to 1.0 for fully saturated colors.
+        pub s,
+        /// The value (brightness) of the color, from 0.0 for black to 1.0 for fully bright colors.
+        pub v,
+    }
+}
+
+pub type Hsva<C, Wp> = Alpha<Hsv<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Hsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Hsv<C, Wp> {
+        Hsv::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for Hsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> Hsv<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let (h, max, min) = rgb::hue_max_min(rgb.r, rgb.g, rgb.b);
+
+        let s = if max.is_normal() { (max - min) / max } else { Zero::zero() };
+
+        Hsv::from_float(Hsv::raw(h, s, max))
+    }
+}
+
+impl<C: Channel, Wp> From<Hsl<C, Wp>> for Hsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsl: Hsl<C, Wp>) -> Hsv<C, Wp> {
+        let hsl = hsl.into_float();
+
+        let one = FloatChannel::<C>::one();
+
+        let v = hsl.l + hsl.s * hsl.l.min(one - hsl.l);
+
+        let s = if v.is_normal() { (one - hsl.l / v) * math::cast(2.0) } else { Zero::zero() };
+
+        Hsv::from_float(Hsv::raw(hsl.h.degrees(), s, v))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Hsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Hsv<C, Wp> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Hsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Hsv<C, Wp> {
+        Srgb::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Hsv<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsv: Hsv<C, Wp>) -> Srgb<C, Wp> {
+        let hsv = hsv.into_float();
+
+        let c = hsv.v * hsv.s;
+
+        let [r, g, b] = rgb::from_hue_chroma(hsv.h.degrees(), c, hsv.v - c);
+
+        Srgb::with_wp(Channel::from_float(r), Channel::from_float(g), Channel::from_float(b))
+    }
+}
+
+impl<C: Channel, Wp> From<Hsv<C, Wp>> for Hsl<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsv: Hsv<C, Wp>) -> Hsl<C, Wp> {
+        let hsv = hsv.into_float();
+
+        let one = FloatChannel::<C>::one();
+
+        let l = hsv.v * (one - hsv.s / math::cast(2.0));
+        let d = l.min(one - l);
+
+        let s = if d.is_normal() { (hsv.v - l) / d } else { Zero::zero() };
+
+        Hsl::from_float(Hsl::raw(hsv.h.degrees(), s, l))
+    }
+}
+
+impl<C: Channel, Wp> From<Hsv<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsv: Hsv<C, Wp>) -> Xyz<C, Wp> {
+        Srgb::from(hsv).into()
+    }
+}
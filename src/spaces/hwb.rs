@@ -0,0 +1,142 @@
+//! The HWB color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The HWB (hue, whiteness, blackness) color space
+    ///
+    /// HWB describes a color as a pure hue mixed with some amount of white and black,
+    /// which makes creating tints and shades very intuitive. It is a simple transformation of HSV,
+    /// and is part of CSS Color Level 4.
+    ///
+    /// When whiteness and blackness add up to more than 1.0 they are scaled down proportionally,
+    /// producing a shade of gray.
+    ///
+    /// HWB colors are derived from gamma-encoded sRGB, and share its white point.
+    struct Hwb : TripleChannel => HWB {
+        /// The hue of the color, in degrees. 0.0 is red, 120.0 is green and 240.0 is blue.
+        pub h: Deg,
+        /// The amount of white mixed into the hue, from 0.0 to 1.0.
+        pub w,
+        /// The amount of black mixed into the hue, from 0.0 to 1.0.
+        pub b,
+    }
+}
+
+pub type Hwba<C, Wp> = Alpha<Hwb<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Hwb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Hwb<C, Wp> {
+        Hwb::with_wp(C::zero(), C::zero(), C::from_float(One::one()))
+    }
+}
+
+impl<C: Channel, Wp> From<Hsv<C, Wp>> for Hwb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsv: Hsv<C, Wp>) -> Hwb<C, Wp> {
+        let hsv = hsv.into_float();
+
+        let one = FloatChannel::<C>::one();
+
+        Hwb::from_float(Hwb::raw(hsv.h.degrees(), (one - hsv.s) * hsv.v, one - hsv.v))
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for Hwb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> Hwb<C, Wp> {
+        Hsv::from(rgb).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Hwb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Hwb<C, Wp> {
+        Hsv::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Hwb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Hwb<C, Wp> {
+        Hsv::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Hwb<C, Wp>> for Hsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hwb: Hwb<C, Wp>) -> Hsv<C, Wp> {
+        let hwb = hwb.into_float();
+
+        let one = FloatChannel::<C>::one();
+
+        let (mut w, mut b) = (hwb.w, hwb.b);
+
+        let sum = w + b;
+
+        if sum > one {
+            w = w / sum;
+            b = b / sum;
+        }
+
+        let v = one - b;
+
+        let s = if v.is_normal() { one - w / v } else { Zero::zero() };
+
+        Hsv::from_float(Hsv::raw(hwb.h.degrees(), s, v))
+    }
+}
+
+impl<C: Channel, Wp> From<Hwb<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hwb: Hwb<C, Wp>) -> Srgb<C, Wp> {
+        Hsv::from(hwb).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Hwb<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hwb: Hwb<C, Wp>) -> Xyz<C, Wp> {
+        Hsv::from(hwb).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_default_is_black() {
+        let rgb = Srgb::from(Hwb::<u8>::default());
+
+        assert_eq!((rgb.r, rgb.g, rgb.b), (0, 0, 0));
+    }
+}
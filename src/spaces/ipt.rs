@@ -0,0 +1,109 @@
+//! The IPT color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::{WhitePoint, D65};
+use math::{self, Matrix3};
+
+use super::rgb;
+
+/// XYZ (D65) to Hunt-Pointer-Estevez cone responses normalized to D65
+const M1: Matrix3<f64> = [
+    [0.4002, 0.7075, -0.0807],
+    [-0.2280, 1.1500, 0.0612],
+    [0.0, 0.0, 0.9184],
+];
+
+/// Nonlinear cone responses to IPT
+const M2: Matrix3<f64> = [
+    [0.4000, 0.4000, 0.2000],
+    [4.4550, -4.8510, 0.3960],
+    [0.8056, 0.3572, -1.1628],
+];
+
+/// Exponent of the cone response nonlinearity
+const EXPONENT: f64 = 0.43;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The IPT color space
+    ///
+    /// IPT is an opponent color space by Ebner and Fairchild, designed for hue uniformity. Lines of
+    /// constant hue are close to straight, which makes it well suited to gamut mapping, where colors
+    /// are moved towards the neutral axis without visibly shifting their hue.
+    ///
+    /// IPT is defined relative to D65. Colors with any other white point are chromatically
+    /// adapted to D65 with the Bradford transform before conversion.
+    struct Ipt : TripleChannel => IPT {
+        /// I is the lightness of the color, from 0.0 for black to 1.0 for white.
+        pub i,
+        /// P is the protan, or red-green, opponent channel.
+        pub p,
+        /// T is the tritan, or yellow-blue, opponent channel.
+        pub t,
+    }
+}
+
+pub type Ipta<C, Wp> = Alpha<Ipt<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Ipt<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Ipt<C, Wp> {
+        Ipt::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Ipt<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Ipt<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let m1 = math::mul(&math::matrix(&M1), &rgb::adaptation_matrix::<C, Wp, D65>());
+        let [l, m, s] = math::mul_vec(&m1, [xyz.x, xyz.y, xyz.z]);
+
+        let f = |v: FloatChannel<C>| v.abs().powf(math::cast(EXPONENT)) * v.signum();
+
+        let [i, p, t] = math::mul_vec(&math::matrix(&M2), [f(l), f(m), f(s)]);
+
+        Ipt::from_float(Ipt::raw(i, p, t))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Ipt<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Ipt<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Ipt<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ipt: Ipt<C, Wp>) -> Xyz<C, Wp> {
+        let ipt = ipt.into_float();
+
+        let [l, m, s] = math::mul_vec(&math::invert(&math::matrix(&M2)), [ipt.i, ipt.p, ipt.t]);
+
+        let f_inv = |v: FloatChannel<C>| v.abs().powf(math::cast(1.0 / EXPONENT)) * v.signum();
+
+        let m1 = math::mul(&math::matrix(&M1), &rgb::adaptation_matrix::<C, Wp, D65>());
+        let [x, y, z] = math::mul_vec(&math::invert(&m1), [f_inv(l), f_inv(m), f_inv(s)]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
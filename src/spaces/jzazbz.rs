@@ -0,0 +1,171 @@
+//! The Jzazbz color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::{WhitePoint, D65};
+use math::{self, Matrix3};
+
+use super::rgb;
+
+/// Luminance of diffuse white in cd/m² assumed by the `From` conversions, as recommended by ITU-R BT.2408
+pub const REFERENCE_WHITE_LUMINANCE: f64 = 203.0;
+
+/// Modified XYZ to LMS cone responses
+const M: Matrix3<f64> = [
+    [0.41478972, 0.579999, 0.0146480],
+    [-0.2015100, 1.120649, 0.0531008],
+    [-0.0166008, 0.264800, 0.6684799],
+];
+
+/// Nonlinear cone responses to Izazbz
+const N: Matrix3<f64> = [
+    [0.5, 0.5, 0.0],
+    [3.524000, -4.066708, 0.542708],
+    [0.199076, 1.096799, -1.295875],
+];
+
+const B: f64 = 1.15;
+const G: f64 = 0.66;
+const D: f64 = -0.56;
+const D0: f64 = 1.6295499532821566e-11;
+
+const C1: f64 = 3424.0 / 4096.0;
+const C2: f64 = 2413.0 / 128.0;
+const C3: f64 = 2392.0 / 128.0;
+const PQ_N: f64 = 2610.0 / 16384.0;
+const PQ_P: f64 = 1.7 * 2523.0 / 32.0;
+
+/// The perceptual quantizer curve with the modified exponent used by Jzazbz, from cd/m².
+fn pq<T: Float>(x: T) -> T {
+    let xn = (x / math::cast(10000.0)).max(T::zero()).powf(math::cast(PQ_N));
+
+    ((math::cast::<T>(C1) + xn * math::cast(C2)) / (T::one() + xn * math::cast(C3))).powf(math::cast(PQ_P))
+}
+
+/// The inverse of the perceptual quantizer curve with the modified exponent used by Jzazbz, to cd/m².
+fn pq_inv<T: Float>(x: T) -> T {
+    let xp = x.powf(math::cast(1.0 / PQ_P));
+
+    ((math::cast::<T>(C1) - xp) / (xp * math::cast(C3) - math::cast(C2))).max(T::zero()).powf(math::cast(1.0 / PQ_N)) * math::cast(10000.0)
+}
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The Jzazbz color space.
+    ///
+    /// Jzazbz is a perceptually uniform color space by Safdar et al. designed for high dynamic range
+    /// and wide gamut content. It uses the perceptual quantizer curve of SMPTE ST 2084 to model
+    /// lightness over luminances from 0 to 10,000 cd/m², where L*a*b* breaks down.
+    ///
+    /// Jzazbz operates on absolute luminance. The `From` conversions assume the white point of the
+    /// color has a luminance of [`REFERENCE_WHITE_LUMINANCE`](constant.REFERENCE_WHITE_LUMINANCE.html), see
+    /// [`from_xyz_with_luminance`](#method.from_xyz_with_luminance) for other luminances.
+    ///
+    /// Jzazbz is defined relative to D65. Colors with any other white point are chromatically
+    /// adapted to D65 with the Bradford transform before conversion.
+    struct Jzazbz : TripleChannel => JZAZBZ {
+        /// Jz is the lightness of the color, from 0.0 for black to about 0.222 for diffuse white at 203 cd/m²,
+        /// and 1.0 at 10,000 cd/m².
+        pub jz,
+        /// az is how green or red the color is.
+        pub az,
+        /// bz is how blue or yellow the color is.
+        pub bz,
+    }
+}
+
+pub type Jzazbza<C, Wp> = Alpha<Jzazbz<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Jzazbz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Jzazbz<C, Wp> {
+        Jzazbz::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> Jzazbz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Converts from relative XYZ, where the white point has the given luminance in cd/m².
+    pub fn from_xyz_with_luminance(xyz: Xyz<C, Wp>, white_luminance: FloatChannel<C>) -> Jzazbz<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let [x, y, z] = math::mul_vec(&rgb::adaptation_matrix::<C, Wp, D65>(), [xyz.x, xyz.y, xyz.z]);
+        let [x, y, z] = [x * white_luminance, y * white_luminance, z * white_luminance];
+
+        let one = FloatChannel::<C>::one();
+        let (b, g): (FloatChannel<C>, FloatChannel<C>) = (math::cast(B), math::cast(G));
+
+        let xp = b * x - (b - one) * z;
+        let yp = g * y - (g - one) * x;
+
+        let [l, m, s] = math::mul_vec(&math::matrix(&M), [xp, yp, z]);
+        let [iz, az, bz] = math::mul_vec(&math::matrix(&N), [pq(l), pq(m), pq(s)]);
+
+        let d: FloatChannel<C> = math::cast(D);
+
+        let jz = (one + d) * iz / (one + d * iz) - math::cast(D0);
+
+        Jzazbz::from_float(Jzazbz::raw(jz, az, bz))
+    }
+
+    /// Converts to relative XYZ, where the white point has the given luminance in cd/m².
+    pub fn into_xyz_with_luminance(self, white_luminance: FloatChannel<C>) -> Xyz<C, Wp> {
+        let JZAZBZ { jz, az, bz } = *self.into_float();
+
+        let one = FloatChannel::<C>::one();
+        let (b, g, d): (FloatChannel<C>, FloatChannel<C>, FloatChannel<C>) = (math::cast(B), math::cast(G), math::cast(D));
+
+        let jz = jz + math::cast(D0);
+        let iz = jz / (one + d - d * jz);
+
+        let [l, m, s] = math::mul_vec(&math::invert(&math::matrix(&N)), [iz, az, bz]);
+        let [xp, yp, z] = math::mul_vec(&math::invert(&math::matrix(&M)), [pq_inv(l), pq_inv(m), pq_inv(s)]);
+
+        let x = (xp + (b - one) * z) / b;
+        let y = (yp + (g - one) * x) / g;
+
+        let [x, y, z] = [x / white_luminance, y / white_luminance, z / white_luminance];
+        let [x, y, z] = math::mul_vec(&math::invert(&rgb::adaptation_matrix::<C, Wp, D65>()), [x, y, z]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Jzazbz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Jzazbz<C, Wp> {
+        Jzazbz::from_xyz_with_luminance(xyz, math::cast(REFERENCE_WHITE_LUMINANCE))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Jzazbz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Jzazbz<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Jzazbz<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(jab: Jzazbz<C, Wp>) -> Xyz<C, Wp> {
+        jab.into_xyz_with_luminance(math::cast(REFERENCE_WHITE_LUMINANCE))
+    }
+}
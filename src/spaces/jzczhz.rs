@@ -0,0 +1,98 @@
+//! The Jzczhz color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The Jzczhz color space.
+    ///
+    /// Jzczhz is the cylindrical form of Jzazbz, replacing the az and bz axes with the chroma
+    /// and hue angle of the color. Like Jzazbz, it is suited to high dynamic range content
+    /// and defined relative to D65.
+    struct Jzczhz : TripleChannel => JZCZHZ {
+        /// Jz is the lightness of the color, identical to that of Jzazbz.
+        pub jz,
+        /// Cz is the chroma, or colorfulness, of the color. 0.0 gives gray.
+        pub cz,
+        /// hz is the hue angle of the color, in degrees.
+        pub hz: Deg,
+    }
+}
+
+pub type Jzczhza<C, Wp> = Alpha<Jzczhz<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Jzczhz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Jzczhz<C, Wp> {
+        Jzczhz::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Jzazbz<C, Wp>> for Jzczhz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(jab: Jzazbz<C, Wp>) -> Jzczhz<C, Wp> {
+        let jab = jab.into_float();
+
+        let c = jab.az.hypot(jab.bz);
+        let h = if c.is_normal() { math::normalize_hue(jab.bz.atan2(jab.az).to_degrees()) } else { Zero::zero() };
+
+        Jzczhz::from_float(Jzczhz::raw(jab.jz, c, h))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Jzczhz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Jzczhz<C, Wp> {
+        Jzazbz::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Jzczhz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Jzczhz<C, Wp> {
+        Jzazbz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Jzczhz<C, Wp>> for Jzazbz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(jch: Jzczhz<C, Wp>) -> Jzazbz<C, Wp> {
+        let jch = jch.into_float();
+
+        let (sin, cos) = jch.hz.degrees().to_radians().sin_cos();
+
+        Jzazbz::with_wp(Channel::from_float(jch.jz),
+                        Channel::from_float(jch.cz * cos),
+                        Channel::from_float(jch.cz * sin))
+    }
+}
+
+impl<C: Channel, Wp> From<Jzczhz<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(jch: Jzczhz<C, Wp>) -> Xyz<C, Wp> {
+        Jzazbz::from(jch).into()
+    }
+}
@@ -2,12 +2,13 @@ use std::ops::{Deref, DerefMut, Index, IndexMut};
 use std::marker::PhantomData;
 use std::fmt::{Debug, Formatter, Result as FmtResult};
 
-use num_traits::{One, Float};
+use num_traits::{Zero, One, Float};
 
 use color::*;
 use channels::*;
 use alpha::Alpha;
 use white_point::WhitePoint;
+use math::{self, CIE_EPSILON, CIE_KAPPA};
 
 
 declare_color_formats_with_components_plus_alpha_specialization! {
@@ -25,16 +26,18 @@ declare_color_formats_with_components_plus_alpha_specialization! {
     struct Lab : TripleChannel => LAB {
         /// L* is the lightness of the color. 0.0 gives absolute black and 100
         /// give the brightest white.
-        pub l,
+        pub l in 0.0..100.0,
         /// a* goes from red at -128 to green at 127.
-        pub a,
+        pub a in -128.0..127.0,
         /// b* goes from yellow at -128 to blue at 127.
-        pub b,
+        pub b in -128.0..127.0,
     }
 }
 
 pub type Laba<C, Wp> = Alpha<Lab<C, Wp>>;
 
+impl_color_arithmetic!(deltas: Lab);
+
 use ::spaces::all::*;
 
 impl<C: Channel, Wp> Default for Lab<C, Wp>
@@ -42,16 +45,60 @@ where
     Wp: WhitePoint<C>,
 {
     fn default() -> Lab<C, Wp> {
-        unimplemented!()
+        Lab::from_float(Lab::raw(Zero::zero(), Zero::zero(), Zero::zero()))
     }
 }
 
+/// Converts floating point XYZ into L\*a\*b\* relative to the XYZ of a white.
+pub(crate) fn xyz_to_lab<T: Float>([x, y, z]: [T; 3], [wx, wy, wz]: [T; 3]) -> [T; 3] {
+    fn f<T: Float>(t: T) -> T {
+        if t > math::cast(CIE_EPSILON) {
+            t.cbrt()
+        } else {
+            (t * math::cast(CIE_KAPPA) + math::cast(16.0)) / math::cast(116.0)
+        }
+    }
+
+    let fx = f(x / wx);
+    let fy = f(y / wy);
+    let fz = f(z / wz);
+
+    [fy * math::cast(116.0) - math::cast(16.0), (fx - fy) * math::cast(500.0), (fy - fz) * math::cast(200.0)]
+}
+
+/// Converts floating point L\*a\*b\* relative to the XYZ of a white into XYZ, the inverse of `xyz_to_lab`.
+pub(crate) fn lab_to_xyz<T: Float>([l, a, b]: [T; 3], [wx, wy, wz]: [T; 3]) -> [T; 3] {
+    let kappa: T = math::cast(CIE_KAPPA);
+
+    let fy = (l + math::cast(16.0)) / math::cast(116.0);
+    let fx = fy + a / math::cast(500.0);
+    let fz = fy - b / math::cast(200.0);
+
+    fn f_inv<T: Float>(t: T) -> T {
+        let t3 = t * t * t;
+
+        if t3 > math::cast(CIE_EPSILON) {
+            t3
+        } else {
+            (t * math::cast(116.0) - math::cast(16.0)) / math::cast(CIE_KAPPA)
+        }
+    }
+
+    let yr = if l > kappa * math::cast(CIE_EPSILON) { fy * fy * fy } else { l / kappa };
+
+    [f_inv(fx) * wx, yr * wy, f_inv(fz) * wz]
+}
+
 impl<C: Channel, Wp> From<Xyz<C, Wp>> for Lab<C, Wp>
 where
     Wp: WhitePoint<C>,
 {
     fn from(xyz: Xyz<C, Wp>) -> Lab<C, Wp> {
-        unimplemented!()
+        let xyz = xyz.into_float();
+
+        let [l, a, b] = xyz_to_lab([xyz.x, xyz.y, xyz.z], Wp::float_xyz());
+
+        Lab::from_float(Lab::raw(l, a, b))
     }
 }
 
@@ -60,6 +107,40 @@ where
     Wp: WhitePoint<C>,
 {
     fn from(yxy: Yxy<C, Wp>) -> Lab<C, Wp> {
-        unimplemented!()
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Lab<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(lab: Lab<C, Wp>) -> Xyz<C, Wp> {
+        let lab = lab.into_float();
+
+        let [x, y, z] = lab_to_xyz([lab.l, lab.a, lab.b], Wp::float_xyz());
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_deltas() {
+        let sum = Lab::<u8>::new(200, 200, 200) + Lab::<u8>::new(100, 100, 100);
+        let float = Lab::from_float(Lab::<u8>::new(200, 200, 200).into_float() + Lab::<u8>::new(100, 100, 100).into_float());
+
+        assert_eq!((sum.l, sum.a, sum.b), (255, float.a, float.b));
+
+        let xyz = Xyz::<u8>::new(200, 200, 200) + Xyz::<u8>::new(100, 100, 100);
+
+        assert_eq!((xyz.x, xyz.y, xyz.z), (255, 255, 255));
+
+        let half = Xyz::<u8>::new(200, 100, 50) / 2;
+
+        assert_eq!((half.x, half.y, half.z), (100, 50, 25));
+    }
+}
@@ -0,0 +1,126 @@
+//! The CIE L*C*h(ab) color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The CIE L*C*h(ab) color space.
+    ///
+    /// L*C*h is the cylindrical form of CIE L*a*b*, replacing the a* and b* axes with the chroma
+    /// and hue angle of the color. It shares the perceptual uniformity of L*a*b*, but adjusting
+    /// colorfulness or hue independently of lightness is much more natural.
+    ///
+    /// Conversions and operations on this color space depend on the white point.
+    struct Lch : TripleChannel => LCH {
+        /// L* is the lightness of the color. 0.0 gives absolute black and 100
+        /// give the brightest white.
+        pub l in 0.0..100.0,
+        /// C* is the chroma, or colorfulness, of the color. 0.0 gives gray, and it
+        /// reaches about 130 for the most saturated colors in common RGB spaces.
+        pub c in 0.0..150.0,
+        /// h is the hue angle of the color, in degrees.
+        pub h: Deg,
+    }
+}
+
+pub type Lcha<C, Wp> = Alpha<Lch<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Lch<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Lch<C, Wp> {
+        Lch::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Lab<C, Wp>> for Lch<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(lab: Lab<C, Wp>) -> Lch<C, Wp> {
+        let lab = lab.into_float();
+
+        let c = lab.a.hypot(lab.b);
+        let h = if c.is_normal() { math::normalize_hue(lab.b.atan2(lab.a).to_degrees()) } else { Zero::zero() };
+
+        Lch::from_float(Lch::raw(lab.l, c, h))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Lch<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Lch<C, Wp> {
+        Lab::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Lch<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Lch<C, Wp> {
+        Lab::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Lch<C, Wp>> for Lab<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(lch: Lch<C, Wp>) -> Lab<C, Wp> {
+        let lch = lch.into_float();
+
+        let (sin, cos) = lch.h.degrees().to_radians().sin_cos();
+
+        Lab::from_float(Lab::raw(lch.l,
+                                 lch.c * cos,
+                                 lch.c * sin))
+    }
+}
+
+impl<C: Channel, Wp> From<Lch<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(lch: Lch<C, Wp>) -> Xyz<C, Wp> {
+        Lab::from(lch).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use convert::ConvertFrom;
+
+    #[test]
+    fn integer_channels() {
+        let lab = Lab::<u8>::convert_from(Srgb::<u8>::new(255, 255, 255));
+        assert_eq!((lab.l, lab.a, lab.b), (255, 128, 128));
+
+        let rgb = Srgb::<u8>::new(200, 100, 50);
+
+        let lab = Lab::<u8>::convert_from(rgb).into_float();
+        let expected = Lab::<f32>::convert_from(rgb.into_float());
+        assert!((lab.l - expected.l).abs() < 0.5 && (lab.a - expected.a).abs() < 1.0 && (lab.b - expected.b).abs() < 1.0);
+
+        let lch = Lch::<u8>::convert_from(rgb).into_float();
+        let expected = Lch::<f32>::convert_from(rgb.into_float());
+        assert!((lch.c - expected.c).abs() < 0.5);
+        assert!(lch.h.distance(expected.h) < 1.0);
+    }
+}
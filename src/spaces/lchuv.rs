@@ -0,0 +1,101 @@
+//! The CIE L*C*h(uv) color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The CIE L*C*h(uv) color space, also known as HCL.
+    ///
+    /// L*C*h(uv) is the cylindrical form of CIE L*u*v*, replacing the u* and v* axes with the chroma
+    /// and hue angle of the color. It is the basis of many data visualization palettes, and of HSLuv.
+    ///
+    /// Conversions and operations on this color space depend on the white point.
+    struct Lchuv : TripleChannel => LCHUV {
+        /// L* is the lightness of the color. 0.0 gives absolute black and 100
+        /// give the brightest white.
+        pub l in 0.0..100.0,
+        /// C* is the chroma, or colorfulness, of the color. 0.0 gives gray, and it
+        /// reaches about 180 for the most saturated colors in sRGB.
+        pub c in 0.0..200.0,
+        /// h is the hue angle of the color, in degrees.
+        pub h: Deg,
+    }
+}
+
+pub type Lchuva<C, Wp> = Alpha<Lchuv<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Lchuv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Lchuv<C, Wp> {
+        Lchuv::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Luv<C, Wp>> for Lchuv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(luv: Luv<C, Wp>) -> Lchuv<C, Wp> {
+        let luv = luv.into_float();
+
+        let c = luv.u.hypot(luv.v);
+        let h = if c.is_normal() { math::normalize_hue(luv.v.atan2(luv.u).to_degrees()) } else { Zero::zero() };
+
+        Lchuv::from_float(Lchuv::raw(luv.l, c, h))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Lchuv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Lchuv<C, Wp> {
+        Luv::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Lchuv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Lchuv<C, Wp> {
+        Luv::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Lchuv<C, Wp>> for Luv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(lch: Lchuv<C, Wp>) -> Luv<C, Wp> {
+        let lch = lch.into_float();
+
+        let (sin, cos) = lch.h.degrees().to_radians().sin_cos();
+
+        Luv::from_float(Luv::raw(lch.l,
+                                 lch.c * cos,
+                                 lch.c * sin))
+    }
+}
+
+impl<C: Channel, Wp> From<Lchuv<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(lch: Lchuv<C, Wp>) -> Xyz<C, Wp> {
+        Luv::from(lch).into()
+    }
+}
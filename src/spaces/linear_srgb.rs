@@ -0,0 +1,108 @@
+//! The linear sRGB color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::rgb;
+use super::srgb::SRGB_PRIMARIES;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The linear sRGB color space
+    ///
+    /// Linear sRGB shares the primaries and white point of sRGB, but stores linear light
+    /// without the sRGB transfer function applied. Light mixing, blending and filtering
+    /// should be performed on linear values.
+    struct LinearSrgb : TripleChannel => RGB {
+        /// The linear amount of red light.
+        pub r,
+        /// The linear amount of green light.
+        pub g,
+        /// The linear amount of blue light.
+        pub b,
+    }
+}
+
+pub type LinearSrgba<C, Wp> = Alpha<LinearSrgb<C, Wp>>;
+
+impl_color_arithmetic!(LinearSrgb);
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for LinearSrgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> LinearSrgb<C, Wp> {
+        LinearSrgb::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for LinearSrgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> LinearSrgb<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let m = rgb::from_xyz_matrix::<C, Wp>(&SRGB_PRIMARIES);
+        let [r, g, b] = math::mul_vec(&m, [xyz.x, xyz.y, xyz.z]);
+
+        LinearSrgb::from_float(LinearSrgb::raw(r, g, b))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for LinearSrgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> LinearSrgb<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for LinearSrgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(srgb: Srgb<C, Wp>) -> LinearSrgb<C, Wp> {
+        let srgb = srgb.into_float();
+
+        LinearSrgb::from_float(LinearSrgb::raw(rgb::srgb_decode(srgb.r), rgb::srgb_decode(srgb.g), rgb::srgb_decode(srgb.b)))
+    }
+}
+
+impl<C: Channel, Wp> From<LinearSrgb<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(linear: LinearSrgb<C, Wp>) -> Srgb<C, Wp> {
+        let linear = linear.into_float();
+
+        Srgb::with_wp(Channel::from_float(rgb::srgb_encode(linear.r)),
+                      Channel::from_float(rgb::srgb_encode(linear.g)),
+                      Channel::from_float(rgb::srgb_encode(linear.b)))
+    }
+}
+
+impl<C: Channel, Wp> From<LinearSrgb<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: LinearSrgb<C, Wp>) -> Xyz<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let m = rgb::to_xyz_matrix::<C, Wp>(&SRGB_PRIMARIES);
+        let [x, y, z] = math::mul_vec(&m, [rgb.r, rgb.g, rgb.b]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
@@ -0,0 +1,135 @@
+//! The LMS cone response space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::{WhitePoint, D65};
+use math;
+
+/// A transform from XYZ to the responses of the long, medium and short wavelength cones.
+pub trait ConeResponse {
+    /// The XYZ to LMS matrix
+    const MATRIX: [[f64; 3]; 3];
+}
+
+/// The Hunt-Pointer-Estevez cone fundamentals, normalized to equal energy, as used by CIECAM97s and
+/// color vision deficiency simulation
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HuntPointerEstevez;
+
+/// The sharpened Bradford cone response, as used by the Bradford chromatic adaptation transform
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bradford;
+
+/// The sharpened cone response of CIECAM02
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cat02;
+
+/// The sharpened cone response of CAM16
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cat16;
+
+impl ConeResponse for HuntPointerEstevez {
+    const MATRIX: [[f64; 3]; 3] = [
+        [0.38971, 0.68898, -0.07868],
+        [-0.22981, 1.18340, 0.04641],
+        [0.0, 0.0, 1.0],
+    ];
+}
+
+impl ConeResponse for Bradford {
+    const MATRIX: [[f64; 3]; 3] = [
+        [0.8951, 0.2664, -0.1614],
+        [-0.7502, 1.7135, 0.0367],
+        [0.0389, -0.0685, 1.0296],
+    ];
+}
+
+impl ConeResponse for Cat02 {
+    const MATRIX: [[f64; 3]; 3] = [
+        [0.7328, 0.4296, -0.1624],
+        [-0.7036, 1.6975, 0.0061],
+        [0.0030, 0.0136, 0.9834],
+    ];
+}
+
+impl ConeResponse for Cat16 {
+    const MATRIX: [[f64; 3]; 3] = [
+        [0.401288, 0.650173, -0.051461],
+        [-0.250268, 1.204414, 0.045854],
+        [-0.002079, 0.048952, 0.953127],
+    ];
+}
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The LMS cone response space
+    ///
+    /// LMS describes a color by the stimulation of the long, medium and short wavelength cones of the eye,
+    /// as estimated by the cone response matrix `M`. It is the space in which chromatic adaptation transforms
+    /// scale colors and color vision deficiencies are simulated.
+    ///
+    /// The sharpened matrices of the chromatic adaptation transforms are not true cone fundamentals,
+    /// but are included as they are the spaces those transforms operate in.
+    struct Lms<M: ConeResponse = HuntPointerEstevez> : TripleChannel => LMS {
+        /// The response of the long wavelength cones.
+        pub l,
+        /// The response of the medium wavelength cones.
+        pub m,
+        /// The response of the short wavelength cones.
+        pub s,
+    }
+}
+
+pub type Lmsa<C, Wp, M> = Alpha<Lms<C, Wp, M>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp, M: ConeResponse> Default for Lms<C, Wp, M>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Lms<C, Wp, M> {
+        Lms::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp, M: ConeResponse> From<Xyz<C, Wp>> for Lms<C, Wp, M>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Lms<C, Wp, M> {
+        let xyz = xyz.into_float();
+
+        let [l, m, s] = math::mul_vec(&math::matrix(&M::MATRIX), [xyz.x, xyz.y, xyz.z]);
+
+        Lms::from_float(Lms::raw(l, m, s))
+    }
+}
+
+impl<C: Channel, Wp, M: ConeResponse> From<Yxy<C, Wp>> for Lms<C, Wp, M>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Lms<C, Wp, M> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp, M: ConeResponse> From<Lms<C, Wp, M>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(lms: Lms<C, Wp, M>) -> Xyz<C, Wp> {
+        let lms = lms.into_float();
+
+        let [x, y, z] = math::mul_vec(&math::invert(&math::matrix(&M::MATRIX)), [lms.l, lms.m, lms.s]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
@@ -0,0 +1,124 @@
+//! The CIE L*u*v* (CIELUV) color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math::{self, CIE_EPSILON, CIE_KAPPA};
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The CIE L*u*v* (CIELUV) color space.
+    ///
+    /// CIE L*u*v* is a device independent color space, like L*a*b*, which attempts perceptual
+    /// uniformity. It is derived from the CIE 1976 u′v′ chromaticity diagram, so additive mixtures
+    /// of lights fall on straight lines, which makes it popular for emissive displays and lighting.
+    ///
+    /// Conversions and operations on this color space depend on the white point.
+    struct Luv : TripleChannel => LUV {
+        /// L* is the lightness of the color. 0.0 gives absolute black and 100
+        /// give the brightest white.
+        pub l in 0.0..100.0,
+        /// u* goes from about -84 at green to 176 at red for colors within sRGB.
+        pub u in -134.0..220.0,
+        /// v* goes from about -135 at blue to 108 at yellow for colors within sRGB.
+        pub v in -140.0..122.0,
+    }
+}
+
+pub type Luva<C, Wp> = Alpha<Luv<C, Wp>>;
+
+use ::spaces::all::*;
+
+/// Computes the CIE 1976 u′v′ chromaticity coordinates of XYZ tristimulus values.
+#[inline]
+fn uv_prime<T: Float>(x: T, y: T, z: T) -> Option<(T, T)> {
+    let d = x + y * math::cast(15.0) + z * math::cast(3.0);
+
+    if d.is_normal() {
+        Some((x * math::cast(4.0) / d, y * math::cast(9.0) / d))
+    } else {
+        None
+    }
+}
+
+impl<C: Channel, Wp> Default for Luv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Luv<C, Wp> {
+        Luv::from_float(Luv::raw(Zero::zero(), Zero::zero(), Zero::zero()))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Luv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Luv<C, Wp> {
+        let xyz = xyz.into_float();
+        let [wx, wy, wz] = Wp::float_xyz();
+
+        let yr = xyz.y / wy;
+
+        let l = if yr > math::cast(CIE_EPSILON) {
+            yr.cbrt() * math::cast(116.0) - math::cast(16.0)
+        } else {
+            yr * math::cast(CIE_KAPPA)
+        };
+
+        let mut luv = Luv::raw(l, Zero::zero(), Zero::zero());
+
+        if let (Some((u, v)), Some((un, vn))) = (uv_prime(xyz.x, xyz.y, xyz.z), uv_prime(wx, wy, wz)) {
+            luv.u = l * math::cast(13.0) * (u - un);
+            luv.v = l * math::cast(13.0) * (v - vn);
+        }
+
+        Luv::from_float(luv)
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Luv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Luv<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Luv<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(luv: Luv<C, Wp>) -> Xyz<C, Wp> {
+        let luv = luv.into_float();
+        let [wx, wy, wz] = Wp::float_xyz();
+
+        let (un, vn) = match uv_prime(wx, wy, wz) {
+            Some(uv) if luv.l.is_normal() => uv,
+            _ => return Xyz::default(),
+        };
+
+        let kappa: FloatChannel<C> = math::cast(CIE_KAPPA);
+
+        let u = luv.u / (luv.l * math::cast(13.0)) + un;
+        let v = luv.v / (luv.l * math::cast(13.0)) + vn;
+
+        let y = if luv.l > kappa * math::cast(CIE_EPSILON) {
+            ((luv.l + math::cast(16.0)) / math::cast(116.0)).powi(3)
+        } else {
+            luv.l / kappa
+        } * wy;
+
+        let x = y * u * math::cast(9.0) / (v * math::cast(4.0));
+        let z = y * (math::cast::<FloatChannel<C>>(12.0) - u * math::cast(3.0) - v * math::cast(20.0)) / (v * math::cast(4.0));
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
@@ -1,10 +1,89 @@
-//pub mod rgb;
+pub(crate) mod rgb;
+pub mod primaries;
+mod ok_gamut;
+pub mod srgb;
+pub mod linear_srgb;
+pub mod scrgb;
+pub mod generic_rgb;
+pub mod display_p3;
+pub mod aces;
+pub mod aces_cg;
+pub mod hsl;
+pub mod hsv;
+pub mod hwb;
+pub mod hsi;
+pub mod hsp;
 pub mod xyz;
 pub mod yxy;
+pub mod uv1960;
+pub mod uv_prime;
 pub mod lab;
+pub mod lch;
+pub mod luv;
+pub mod lchuv;
+pub mod oklab;
+pub mod oklch;
+pub mod okhsl;
+pub mod okhsv;
+pub mod cam16;
+pub mod cam16_ucs;
+pub mod jzazbz;
+pub mod jzczhz;
+pub mod ycbcr;
+pub mod ypbpr;
+pub mod ydbdr;
+pub mod ycocg;
+pub mod ycocg_r;
+pub mod cmy;
+pub mod cmyk;
+pub mod lms;
+pub mod din99;
+pub mod din99d;
+pub mod xyb;
+pub mod ipt;
+pub mod osa_ucs;
+pub mod generic;
 
 pub mod all {
+    pub use super::srgb::Srgb;
+    pub use super::linear_srgb::LinearSrgb;
+    pub use super::scrgb::ScRgb;
+    pub use super::generic_rgb::Rgb;
+    pub use super::display_p3::DisplayP3;
+    pub use super::aces::Aces2065;
+    pub use super::aces_cg::AcesCg;
+    pub use super::hsl::Hsl;
+    pub use super::hsv::Hsv;
+    pub use super::hwb::Hwb;
+    pub use super::hsi::Hsi;
+    pub use super::hsp::Hsp;
     pub use super::xyz::Xyz;
     pub use super::yxy::Yxy;
-    //pub use super::lab::Lab;
-}
\ No newline at end of file
+    pub use super::uv1960::Uv1960;
+    pub use super::uv_prime::UvPrime;
+    pub use super::lab::Lab;
+    pub use super::lch::Lch;
+    pub use super::luv::Luv;
+    pub use super::lchuv::Lchuv;
+    pub use super::oklab::Oklab;
+    pub use super::oklch::Oklch;
+    pub use super::okhsl::Okhsl;
+    pub use super::okhsv::Okhsv;
+    pub use super::cam16_ucs::Cam16Ucs;
+    pub use super::jzazbz::Jzazbz;
+    pub use super::jzczhz::Jzczhz;
+    pub use super::ycbcr::YCbCr;
+    pub use super::ypbpr::YPbPr;
+    pub use super::ydbdr::YDbDr;
+    pub use super::ycocg::YCoCg;
+    pub use super::ycocg_r::YCoCgR;
+    pub use super::cmy::Cmy;
+    pub use super::cmyk::Cmyk;
+    pub use super::lms::Lms;
+    pub use super::din99::Din99;
+    pub use super::din99d::Din99d;
+    pub use super::xyb::Xyb;
+    pub use super::ipt::Ipt;
+    pub use super::osa_ucs::OsaUcs;
+    pub use super::generic::GenericColor;
+}
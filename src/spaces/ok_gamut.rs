@@ -0,0 +1,257 @@
+//! sRGB gamut estimation in Oklab, used by the Okhsl and Okhsv color spaces.
+//!
+//! This is a port of Björn Ottosson's reference implementation. The gamut boundary of linear sRGB
+//! for a given hue is approximated by a triangle between black, white and the cusp of maximum chroma,
+//! which is then refined with a single step of Halley's method.
+
+use num_traits::Float;
+
+use math::{self, Vector3};
+
+use super::rgb;
+
+/// Lightness and chroma of a point in Oklab
+#[derive(Clone, Copy)]
+pub struct LC<T> {
+    pub l: T,
+    pub c: T,
+}
+
+/// Saturation and "toe" saturation, `C/L` and `C/(1 - L)`
+#[derive(Clone, Copy)]
+pub struct ST<T> {
+    pub s: T,
+    pub t: T,
+}
+
+/// Converts Oklab to linear sRGB, with the matrices folded together as in the reference implementation.
+pub fn oklab_to_linear_srgb<T: Float>([l, a, b]: Vector3<T>) -> Vector3<T> {
+    let c = math::cast::<T>;
+
+    let l_ = l + c(0.3963377774) * a + c(0.2158037573) * b;
+    let m_ = l - c(0.1055613458) * a - c(0.0638541728) * b;
+    let s_ = l - c(0.0894841775) * a - c(1.2914855480) * b;
+
+    let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+    [
+        c(4.0767416621) * l - c(3.3077115913) * m + c(0.2309699292) * s,
+        c(-1.2684380046) * l + c(2.6097574011) * m - c(0.3413193965) * s,
+        c(-0.0041960863) * l - c(0.7034186147) * m + c(1.7076147010) * s,
+    ]
+}
+
+/// Converts linear sRGB to Oklab, with the matrices folded together as in the reference implementation.
+pub fn linear_srgb_to_oklab<T: Float>([r, g, b]: Vector3<T>) -> Vector3<T> {
+    let c = math::cast::<T>;
+
+    let l = (c(0.4122214708) * r + c(0.5363325363) * g + c(0.0514459929) * b).cbrt();
+    let m = (c(0.2119034982) * r + c(0.6806995451) * g + c(0.1073969566) * b).cbrt();
+    let s = (c(0.0883024619) * r + c(0.2817188376) * g + c(0.6299787005) * b).cbrt();
+
+    [
+        c(0.2104542553) * l + c(0.7936177850) * m - c(0.0040720468) * s,
+        c(1.9779984951) * l - c(2.4285922050) * m + c(0.4505937099) * s,
+        c(0.0259040371) * l + c(0.7827717662) * m - c(0.8086757660) * s,
+    ]
+}
+
+/// Converts gamma-encoded sRGB to Oklab.
+#[inline]
+pub fn srgb_to_oklab<T: Float>([r, g, b]: Vector3<T>) -> Vector3<T> {
+    linear_srgb_to_oklab([rgb::srgb_decode(r), rgb::srgb_decode(g), rgb::srgb_decode(b)])
+}
+
+/// Converts Oklab to gamma-encoded sRGB.
+#[inline]
+pub fn oklab_to_srgb<T: Float>(lab: Vector3<T>) -> Vector3<T> {
+    let [r, g, b] = oklab_to_linear_srgb(lab);
+
+    [rgb::srgb_encode(r), rgb::srgb_encode(g), rgb::srgb_encode(b)]
+}
+
+/// Finds the maximum saturation possible for a hue that fits in sRGB, where `a` and `b` are normalized.
+fn compute_max_saturation<T: Float>(a: T, b: T) -> T {
+    let c = math::cast::<T>;
+
+    // Select a polynomial fit and the sRGB channel that clips first, depending on the hue
+    let (k0, k1, k2, k3, k4, wl, wm, ws) = if c(-1.88170328) * a - c(0.80936493) * b > T::one() {
+        (1.19086277, 1.76576728, 0.59662641, 0.75515197, 0.56771245, 4.0767416621, -3.3077115913, 0.2309699292)
+    } else if c(1.81444104) * a - c(1.19445276) * b > T::one() {
+        (0.73956515, -0.45954404, 0.08285427, 0.12541070, 0.14503204, -1.2684380046, 2.6097574011, -0.3413193965)
+    } else {
+        (1.35733652, -0.00915799, -1.15130210, -0.50559606, 0.00692167, -0.0041960863, -0.7034186147, 1.7076147010)
+    };
+
+    let (wl, wm, ws) = (c(wl), c(wm), c(ws));
+
+    let s = c(k0) + c(k1) * a + c(k2) * b + c(k3) * a * a + c(k4) * a * b;
+
+    let k_l = c(0.3963377774) * a + c(0.2158037573) * b;
+    let k_m = c(-0.1055613458) * a - c(0.0638541728) * b;
+    let k_s = c(-0.0894841775) * a - c(1.2914855480) * b;
+
+    let l_ = T::one() + s * k_l;
+    let m_ = T::one() + s * k_m;
+    let s_ = T::one() + s * k_s;
+
+    let (l, m, s3) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+    let l_ds = c(3.0) * k_l * l_ * l_;
+    let m_ds = c(3.0) * k_m * m_ * m_;
+    let s_ds = c(3.0) * k_s * s_ * s_;
+
+    let l_ds2 = c(6.0) * k_l * k_l * l_;
+    let m_ds2 = c(6.0) * k_m * k_m * m_;
+    let s_ds2 = c(6.0) * k_s * k_s * s_;
+
+    let f = wl * l + wm * m + ws * s3;
+    let f1 = wl * l_ds + wm * m_ds + ws * s_ds;
+    let f2 = wl * l_ds2 + wm * m_ds2 + ws * s_ds2;
+
+    s - f * f1 / (f1 * f1 - c(0.5) * f * f2)
+}
+
+/// Finds the lightness and chroma of the cusp of the sRGB gamut for a normalized hue.
+pub fn find_cusp<T: Float>(a: T, b: T) -> LC<T> {
+    let s_cusp = compute_max_saturation(a, b);
+
+    let [r, g, b] = oklab_to_linear_srgb([T::one(), s_cusp * a, s_cusp * b]);
+
+    let l_cusp = (T::one() / r.max(g).max(b)).cbrt();
+
+    LC { l: l_cusp, c: l_cusp * s_cusp }
+}
+
+/// Finds the intersection of the line from `(L0, 0)` to `(L1, C1)` with the sRGB gamut boundary,
+/// as a fraction of the line.
+pub fn find_gamut_intersection<T: Float>(a: T, b: T, l1: T, c1: T, l0: T, cusp: LC<T>) -> T {
+    let c = math::cast::<T>;
+    let one = T::one();
+
+    if (l1 - l0) * cusp.c - (cusp.l - l0) * c1 <= T::zero() {
+        // Lower half, the intersection is with the line from black to the cusp
+        return cusp.c * l0 / (c1 * cusp.l + cusp.c * (l0 - l1));
+    }
+
+    // Upper half, intersect with the line from the cusp to white first
+    let t = cusp.c * (l0 - one) / (c1 * (cusp.l - one) + cusp.c * (l0 - l1));
+
+    // Then refine with one step of Halley's method for each channel
+    let d_l = l1 - l0;
+    let d_c = c1;
+
+    let k_l = c(0.3963377774) * a + c(0.2158037573) * b;
+    let k_m = c(-0.1055613458) * a - c(0.0638541728) * b;
+    let k_s = c(-0.0894841775) * a - c(1.2914855480) * b;
+
+    let l_dt = d_l + d_c * k_l;
+    let m_dt = d_l + d_c * k_m;
+    let s_dt = d_l + d_c * k_s;
+
+    let lightness = l0 * (one - t) + t * l1;
+    let chroma = t * c1;
+
+    let l_ = lightness + chroma * k_l;
+    let m_ = lightness + chroma * k_m;
+    let s_ = lightness + chroma * k_s;
+
+    let lms = [l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_];
+    let dt = [c(3.0) * l_dt * l_ * l_, c(3.0) * m_dt * m_ * m_, c(3.0) * s_dt * s_ * s_];
+    let dt2 = [c(6.0) * l_dt * l_dt * l_, c(6.0) * m_dt * m_dt * m_, c(6.0) * s_dt * s_dt * s_];
+
+    let channel = |w: [f64; 3]| {
+        let w = [c(w[0]), c(w[1]), c(w[2])];
+
+        let f = w[0] * lms[0] + w[1] * lms[1] + w[2] * lms[2] - one;
+        let f1 = w[0] * dt[0] + w[1] * dt[1] + w[2] * dt[2];
+        let f2 = w[0] * dt2[0] + w[1] * dt2[1] + w[2] * dt2[2];
+
+        let u = f1 / (f1 * f1 - c(0.5) * f * f2);
+
+        if u >= T::zero() { -f * u } else { T::max_value() }
+    };
+
+    let t_r = channel([4.0767416621, -3.3077115913, 0.2309699292]);
+    let t_g = channel([-1.2684380046, 2.6097574011, -0.3413193965]);
+    let t_b = channel([-0.0041960863, -0.7034186147, 1.7076147010]);
+
+    t + t_r.min(t_g).min(t_b)
+}
+
+const K1: f64 = 0.206;
+const K2: f64 = 0.03;
+const K3: f64 = (1.0 + K1) / (1.0 + K2);
+
+/// Remaps Oklab lightness to better match the lightness of CIE L*a*b* near black.
+pub fn toe<T: Float>(x: T) -> T {
+    let c = math::cast::<T>;
+
+    let y = c(K3) * x - c(K1);
+
+    (y + (y * y + c(4.0 * K2 * K3) * x).sqrt()) * c(0.5)
+}
+
+/// The inverse of [`toe`](fn.toe.html)
+pub fn toe_inv<T: Float>(x: T) -> T {
+    let c = math::cast::<T>;
+
+    (x * x + c(K1) * x) / (c(K3) * (x + c(K2)))
+}
+
+/// Converts the cusp of the gamut to saturation and toe saturation.
+#[inline]
+pub fn to_st<T: Float>(cusp: LC<T>) -> ST<T> {
+    ST { s: cusp.c / cusp.l, t: cusp.c / (T::one() - cusp.l) }
+}
+
+/// A smooth approximation of the location of the cusp, used for the middle of the Okhsl saturation scale.
+fn get_st_mid<T: Float>(a: T, b: T) -> ST<T> {
+    let c = math::cast::<T>;
+
+    let s = c(0.11516993) + T::one() / (
+        c(7.44778970) + c(4.15901240) * b
+            + a * (c(-2.19557347) + c(1.75198401) * b
+            + a * (c(-2.13704948) - c(10.02301043) * b
+            + a * (c(-4.24894561) + c(5.38770819) * b + c(4.69891013) * a))));
+
+    let t = c(0.11239642) + T::one() / (
+        c(1.61320320) - c(0.68124379) * b
+            + a * (c(0.40370612) + c(0.90148123) * b
+            + a * (c(-0.27087943) + c(0.61223990) * b
+            + a * (c(0.00299215) - c(0.45399568) * b - c(0.14661872) * a))));
+
+    ST { s, t }
+}
+
+/// Computes the chroma at zero, middle and maximum Okhsl saturation for a lightness and normalized hue.
+pub fn get_cs<T: Float>(l: T, a: T, b: T) -> (T, T, T) {
+    let c = math::cast::<T>;
+    let one = T::one();
+
+    let cusp = find_cusp(a, b);
+
+    let c_max = find_gamut_intersection(a, b, l, one, l, cusp);
+    let st_max = to_st(cusp);
+
+    // Scale factor to compensate for the curved part of the gamut shape
+    let k = c_max / (l * st_max.s).min((one - l) * st_max.t);
+
+    let c_mid = {
+        let st_mid = get_st_mid(a, b);
+
+        let c_a = l * st_mid.s;
+        let c_b = (one - l) * st_mid.t;
+
+        c(0.9) * k * (one / (one / c_a.powi(4) + one / c_b.powi(4))).sqrt().sqrt()
+    };
+
+    let c_0 = {
+        let c_a = l * c(0.4);
+        let c_b = (one - l) * c(0.8);
+
+        (one / (one / (c_a * c_a) + one / (c_b * c_b))).sqrt()
+    };
+
+    (c_0, c_mid, c_max)
+}
@@ -0,0 +1,162 @@
+//! The Okhsl color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::ok_gamut;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The Okhsl color space.
+    ///
+    /// Okhsl is a remapping of Oklab by Björn Ottosson into hue, saturation and lightness coordinates
+    /// bounded by the sRGB gamut, in the spirit of HSL. Unlike HSL, its lightness is perceptually
+    /// uniform and its hue matches Oklch, which makes it well suited for color pickers.
+    ///
+    /// Okhsl colors are derived from gamma-encoded sRGB, and share its white point.
+    struct Okhsl : TripleChannel => OKHSL {
+        /// The hue of the color, in degrees. Matches the hue of Oklch.
+        pub h: Deg,
+        /// The saturation of the color, from 0.0 for gray to 1.0 at the edge of the sRGB gamut.
+        pub s,
+        /// The perceived lightness of the color, from 0.0 for black to 1.0 for white.
+        pub l,
+    }
+}
+
+pub type Okhsla<C, Wp> = Alpha<Okhsl<C, Wp>>;
+
+use ::spaces::all::*;
+
+/// Saturation at which the middle of the chroma scale is reached
+const MID: f64 = 0.8;
+
+impl<C: Channel, Wp> Default for Okhsl<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Okhsl<C, Wp> {
+        Okhsl::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for Okhsl<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> Okhsl<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let [l, a, b] = ok_gamut::srgb_to_oklab([rgb.r, rgb.g, rgb.b]);
+
+        let chroma = a.hypot(b);
+
+        if !chroma.is_normal() || l <= Zero::zero() || l >= One::one() {
+            return Okhsl::from_float(Okhsl::raw(Zero::zero(), Zero::zero(), ok_gamut::toe(l)));
+        }
+
+        let (a_, b_) = (a / chroma, b / chroma);
+
+        let h = math::normalize_hue(b.atan2(a).to_degrees());
+
+        let (c_0, c_mid, c_max) = ok_gamut::get_cs(l, a_, b_);
+
+        let one = FloatChannel::<C>::one();
+        let mid: FloatChannel<C> = math::cast(MID);
+
+        let s = if chroma < c_mid {
+            let k_1 = mid * c_0;
+            let k_2 = one - k_1 / c_mid;
+
+            mid * chroma / (k_1 + k_2 * chroma)
+        } else {
+            let k_1 = (one - mid) * c_mid * c_mid / (mid * mid * c_0);
+            let k_2 = one - k_1 / (c_max - c_mid);
+
+            let t = (chroma - c_mid) / (k_1 + k_2 * (chroma - c_mid));
+
+            mid + (one - mid) * t
+        };
+
+        Okhsl::from_float(Okhsl::raw(h, s, ok_gamut::toe(l)))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Okhsl<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Okhsl<C, Wp> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Okhsl<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Okhsl<C, Wp> {
+        Srgb::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Okhsl<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsl: Okhsl<C, Wp>) -> Srgb<C, Wp> {
+        let OKHSL { h, s, l } = *hsl.into_float();
+
+        let one = FloatChannel::<C>::one();
+        let mid: FloatChannel<C> = math::cast(MID);
+
+        let [r, g, b] = if l >= one {
+            [one; 3]
+        } else if l <= Zero::zero() {
+            [Zero::zero(); 3]
+        } else {
+            let (b_, a_) = h.degrees().to_radians().sin_cos();
+
+            let lightness = ok_gamut::toe_inv(l);
+
+            let (c_0, c_mid, c_max) = ok_gamut::get_cs(lightness, a_, b_);
+
+            let chroma = if s < mid {
+                let t = s / mid;
+
+                let k_1 = mid * c_0;
+                let k_2 = one - k_1 / c_mid;
+
+                t * k_1 / (one - k_2 * t)
+            } else {
+                let t = (s - mid) / (one - mid);
+
+                let k_1 = (one - mid) * c_mid * c_mid / (mid * mid * c_0);
+                let k_2 = one - k_1 / (c_max - c_mid);
+
+                c_mid + t * k_1 / (one - k_2 * t)
+            };
+
+            ok_gamut::oklab_to_srgb([lightness, chroma * a_, chroma * b_])
+        };
+
+        Srgb::with_wp(Channel::from_float(r), Channel::from_float(g), Channel::from_float(b))
+    }
+}
+
+impl<C: Channel, Wp> From<Okhsl<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsl: Okhsl<C, Wp>) -> Xyz<C, Wp> {
+        Srgb::from(hsl).into()
+    }
+}
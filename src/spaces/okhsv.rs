@@ -0,0 +1,169 @@
+//! The Okhsv color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::ok_gamut;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The Okhsv color space.
+    ///
+    /// Okhsv is a remapping of Oklab by Björn Ottosson into hue, saturation and value coordinates
+    /// bounded by the sRGB gamut, in the spirit of HSV. Every combination of coordinates is a valid
+    /// sRGB color, which makes it well suited for color pickers.
+    ///
+    /// Okhsv colors are derived from gamma-encoded sRGB, and share its white point.
+    struct Okhsv : TripleChannel => OKHSV {
+        /// The hue of the color, in degrees. Matches the hue of Oklch.
+        pub h: Deg,
+        /// The saturation of the color, from 0.0 for gray to 1.0 at the edge of the sRGB gamut.
+        pub s,
+        /// The value of the color, from 0.0 for black to 1.0 for the brightest color of the hue.
+        pub v,
+    }
+}
+
+pub type Okhsva<C, Wp> = Alpha<Okhsv<C, Wp>>;
+
+use ::spaces::all::*;
+
+/// Saturation of the gamut at the midpoint of the value axis
+const S_0: f64 = 0.5;
+
+impl<C: Channel, Wp> Default for Okhsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Okhsv<C, Wp> {
+        Okhsv::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for Okhsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> Okhsv<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let [l, a, b] = ok_gamut::srgb_to_oklab([rgb.r, rgb.g, rgb.b]);
+
+        let chroma = a.hypot(b);
+
+        if !chroma.is_normal() || l <= Zero::zero() {
+            return Okhsv::from_float(Okhsv::raw(Zero::zero(), Zero::zero(), ok_gamut::toe(l)));
+        }
+
+        let (a_, b_) = (a / chroma, b / chroma);
+
+        let h = math::normalize_hue(b.atan2(a).to_degrees());
+
+        let one = FloatChannel::<C>::one();
+        let s_0: FloatChannel<C> = math::cast(S_0);
+
+        let st_max = ok_gamut::to_st(ok_gamut::find_cusp(a_, b_));
+        let k = one - s_0 / st_max.s;
+
+        // Find the triangle of constant value containing the color
+        let t = st_max.t / (chroma + l * st_max.t);
+        let l_v = t * l;
+        let c_v = t * chroma;
+
+        let l_vt = ok_gamut::toe_inv(l_v);
+        let c_vt = c_v * l_vt / l_v;
+
+        // Invert the scaling applied to stay within the gamut
+        let [r, g, b] = ok_gamut::oklab_to_linear_srgb([l_vt, a_ * c_vt, b_ * c_vt]);
+        let scale_l = (one / r.max(g).max(b).max(Zero::zero())).cbrt();
+
+        let l = l / scale_l;
+
+        let v = ok_gamut::toe(l) / l_v;
+        let s = (s_0 + st_max.t) * c_v / (st_max.t * s_0 + st_max.t * k * c_v);
+
+        Okhsv::from_float(Okhsv::raw(h, s, v))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Okhsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Okhsv<C, Wp> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Okhsv<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Okhsv<C, Wp> {
+        Srgb::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Okhsv<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsv: Okhsv<C, Wp>) -> Srgb<C, Wp> {
+        let OKHSV { h, s, v } = *hsv.into_float();
+
+        let one = FloatChannel::<C>::one();
+        let s_0: FloatChannel<C> = math::cast(S_0);
+
+        let [r, g, b] = if v <= Zero::zero() {
+            [Zero::zero(); 3]
+        } else {
+            let (b_, a_) = h.degrees().to_radians().sin_cos();
+
+            let st_max = ok_gamut::to_st(ok_gamut::find_cusp(a_, b_));
+            let k = one - s_0 / st_max.s;
+
+            let d = s_0 + st_max.t - st_max.t * k * s;
+
+            // Lightness and chroma on the edge of the triangle of constant value
+            let l_v = one - s * s_0 / d;
+            let c_v = s * st_max.t * s_0 / d;
+
+            let l = v * l_v;
+            let chroma = v * c_v;
+
+            let l_vt = ok_gamut::toe_inv(l_v);
+            let c_vt = c_v * l_vt / l_v;
+
+            let l_new = ok_gamut::toe_inv(l);
+            let chroma = chroma * l_new / l;
+
+            // Scale to compensate for the curvature of the gamut
+            let [r, g, b] = ok_gamut::oklab_to_linear_srgb([l_vt, a_ * c_vt, b_ * c_vt]);
+            let scale_l = (one / r.max(g).max(b).max(Zero::zero())).cbrt();
+
+            let l = l_new * scale_l;
+            let chroma = chroma * scale_l;
+
+            ok_gamut::oklab_to_srgb([l, chroma * a_, chroma * b_])
+        };
+
+        Srgb::with_wp(Channel::from_float(r), Channel::from_float(g), Channel::from_float(b))
+    }
+}
+
+impl<C: Channel, Wp> From<Okhsv<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(hsv: Okhsv<C, Wp>) -> Xyz<C, Wp> {
+        Srgb::from(hsv).into()
+    }
+}
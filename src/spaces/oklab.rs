@@ -0,0 +1,137 @@
+//! The Oklab color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::{WhitePoint, D65};
+use math::{self, Matrix3};
+
+use super::rgb;
+
+/// XYZ (D65) to approximate cone responses
+const M1: Matrix3<f64> = [
+    [0.8189330101, 0.3618667424, -0.1288597137],
+    [0.0329845436, 0.9293118715, 0.0361456387],
+    [0.0482003018, 0.2643662691, 0.6338517070],
+];
+
+/// Nonlinear cone responses to Lab
+const M2: Matrix3<f64> = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The Oklab color space.
+    ///
+    /// Oklab is a perceptual color space designed by Björn Ottosson for image processing. It predicts
+    /// lightness, chroma and hue better than CIE L*a*b*, while remaining a simple matrix and cube root
+    /// transformation of XYZ, which makes it well suited to interpolation and gamut mapping.
+    ///
+    /// Oklab is defined relative to D65. Colors with any other white point are chromatically
+    /// adapted to D65 with the Bradford transform before conversion.
+    struct Oklab : TripleChannel => OKLAB {
+        /// L is the perceived lightness of the color, from 0.0 for black to 1.0 for white.
+        pub l,
+        /// a is how green or red the color is, roughly from -0.4 to 0.4.
+        ///
+        /// Integer channels span -0.512 to 0.508, like the -128 to 127 of L\*a\*b\*, so that neutral colors
+        /// are exactly 128 with 8 bits.
+        pub a in -0.512..0.508,
+        /// b is how blue or yellow the color is, roughly from -0.4 to 0.4, stored like a.
+        pub b in -0.512..0.508,
+    }
+}
+
+pub type Oklaba<C, Wp> = Alpha<Oklab<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Oklab<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Oklab<C, Wp> {
+        Oklab::from_float(Oklab::raw(Zero::zero(), Zero::zero(), Zero::zero()))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Oklab<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Oklab<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let m1 = math::mul(&math::matrix(&M1), &rgb::adaptation_matrix::<C, Wp, D65>());
+        let [l, m, s] = math::mul_vec(&m1, [xyz.x, xyz.y, xyz.z]);
+
+        let [l, a, b] = math::mul_vec(&math::matrix(&M2), [l.cbrt(), m.cbrt(), s.cbrt()]);
+
+        Oklab::from_float(Oklab::raw(l, a, b))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Oklab<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Oklab<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<LinearSrgb<C, Wp>> for Oklab<C, Wp>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    fn from(rgb: LinearSrgb<C, Wp>) -> Oklab<C, Wp> {
+        Oklab::from_float(Xyz::from(rgb.into_float()).into())
+    }
+}
+
+impl<C: Channel, Wp> From<Oklab<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(oklab: Oklab<C, Wp>) -> Xyz<C, Wp> {
+        let oklab = oklab.into_float();
+
+        let [l, m, s] = math::mul_vec(&math::invert(&math::matrix(&M2)), [oklab.l, oklab.a, oklab.b]);
+
+        let m1 = math::mul(&math::matrix(&M1), &rgb::adaptation_matrix::<C, Wp, D65>());
+        let [x, y, z] = math::mul_vec(&math::invert(&m1), [l * l * l, m * m * m, s * s * s]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
+
+impl<C: Channel, Wp> From<Oklab<C, Wp>> for LinearSrgb<C, Wp>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    fn from(oklab: Oklab<C, Wp>) -> LinearSrgb<C, Wp> {
+        LinearSrgb::from_float(Xyz::from(oklab.into_float()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_linear_white() {
+        let oklab = Oklab::<u8>::from(LinearSrgb::<u8>::new(255, 255, 255));
+        let rgb = LinearSrgb::from(oklab);
+
+        assert_eq!((oklab.l, oklab.a, oklab.b), (255, 128, 128));
+        assert_eq!((rgb.r, rgb.g, rgb.b), (255, 255, 255));
+    }
+}
@@ -0,0 +1,101 @@
+//! The Oklch color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The Oklch color space.
+    ///
+    /// Oklch is the cylindrical form of Oklab, replacing the a and b axes with the chroma
+    /// and hue angle of the color. It is used by CSS Color Level 4 for perceptually uniform
+    /// adjustments of chroma and hue.
+    ///
+    /// Like Oklab, it is defined relative to D65.
+    struct Oklch : TripleChannel => OKLCH {
+        /// L is the perceived lightness of the color, from 0.0 for black to 1.0 for white.
+        pub l,
+        /// C is the chroma, or colorfulness, of the color. 0.0 gives gray, and it
+        /// reaches about 0.37 for the most saturated colors in common RGB spaces.
+        pub c in 0.0..0.5,
+        /// h is the hue angle of the color, in degrees.
+        pub h: Deg,
+    }
+}
+
+pub type Oklcha<C, Wp> = Alpha<Oklch<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Oklch<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Oklch<C, Wp> {
+        Oklch::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Oklab<C, Wp>> for Oklch<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(oklab: Oklab<C, Wp>) -> Oklch<C, Wp> {
+        let oklab = oklab.into_float();
+
+        let c = oklab.a.hypot(oklab.b);
+        let h = if c.is_normal() { math::normalize_hue(oklab.b.atan2(oklab.a).to_degrees()) } else { Zero::zero() };
+
+        Oklch::from_float(Oklch::raw(oklab.l, c, h))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Oklch<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Oklch<C, Wp> {
+        Oklab::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Oklch<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Oklch<C, Wp> {
+        Oklab::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Oklch<C, Wp>> for Oklab<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(lch: Oklch<C, Wp>) -> Oklab<C, Wp> {
+        let lch = lch.into_float();
+
+        let (sin, cos) = lch.h.degrees().to_radians().sin_cos();
+
+        Oklab::from_float(Oklab::raw(lch.l,
+                                     lch.c * cos,
+                                     lch.c * sin))
+    }
+}
+
+impl<C: Channel, Wp> From<Oklch<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(lch: Oklch<C, Wp>) -> Xyz<C, Wp> {
+        Oklab::from(lch).into()
+    }
+}
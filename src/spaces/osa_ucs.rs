@@ -0,0 +1,181 @@
+//! The OSA-UCS color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float, ToPrimitive};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math::{self, Matrix3, Vector3};
+
+/// XYZ to the cone-like responses of OSA-UCS
+const M: Matrix3<f64> = [
+    [0.7990, 0.4194, -0.1648],
+    [-0.4493, 1.3265, 0.0927],
+    [-0.1149, 0.3394, 0.7170],
+];
+
+/// Maximum number of Newton iterations of the inverse transform
+const MAX_ITERATIONS: usize = 64;
+
+/// Residual at which the inverse transform is considered converged
+const TOLERANCE: f64 = 1e-12;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The OSA-UCS color space
+    ///
+    /// OSA-UCS was developed by the Optical Society of America from a set of color samples arranged
+    /// so that every sample is equally distant from its twelve nearest neighbors. It is one of the
+    /// most uniform color spaces for large color differences.
+    ///
+    /// There is no closed form inverse of OSA-UCS, so conversion back to XYZ solves the forward
+    /// transform numerically with Newton's method, in double precision regardless of the channel type.
+    struct OsaUcs : TripleChannel => OSAUCS {
+        /// L is the lightness of the color, roughly from -10.0 for black to 8.0 for white, with 0.0 for a medium gray.
+        pub l,
+        /// j is the yellowness of the color, negative for blue colors.
+        pub j,
+        /// g is the greenness of the color, negative for red colors.
+        pub g,
+    }
+}
+
+pub type OsaUcsa<C, Wp> = Alpha<OsaUcs<C, Wp>>;
+
+use ::spaces::all::*;
+
+/// Computes OSA-UCS L, j and g from XYZ scaled so that white has a Y of 100.
+fn forward<T: Float>([x, y, z]: Vector3<T>, white: Vector3<T>) -> Vector3<T> {
+    let c = math::cast::<T>;
+
+    let sum = x + y + z;
+
+    // Black has no chromaticity, so use that of the white point
+    let (cx, cy) = if sum.is_normal() {
+        (x / sum, y / sum)
+    } else {
+        let sum = white[0] + white[1] + white[2];
+
+        (white[0] / sum, white[1] / sum)
+    };
+
+    let k = c(4.4934) * cx * cx + c(4.3034) * cy * cy - c(4.276) * cx * cy - c(1.3744) * cx - c(2.5643) * cy + c(1.8103);
+    let y0 = y * k;
+
+    let y0_cbrt = y0.cbrt() - c(2.0 / 3.0);
+
+    let l_prime = c(5.9) * (y0_cbrt + c(0.042) * (y0 - c(30.0)).cbrt());
+    let l = (l_prime - c(14.3993)) / c(2.0).sqrt();
+
+    let scale = l_prime / (c(5.9) * y0_cbrt);
+
+    let [r, g, b] = math::mul_vec(&math::matrix(&M), [x, y, z]);
+    let (r, g, b) = (r.cbrt(), g.cbrt(), b.cbrt());
+
+    let a = c(-13.7) * r + c(17.7) * g - c(4.0) * b;
+    let b = c(1.7) * r + c(8.0) * g - c(9.7) * b;
+
+    [l, scale * b, scale * a]
+}
+
+/// Solves the forward transform for XYZ scaled so that white has a Y of 100, using Newton's method.
+fn inverse(target: Vector3<f64>, white: Vector3<f64>) -> Vector3<f64> {
+    // Start from a neutral color with approximately the right lightness
+    let l_prime = target[0] * 2.0.sqrt() + 14.3993;
+    let y = (l_prime / 5.9 + 2.0 / 3.0).max(0.0).powi(3);
+
+    let mut xyz = [white[0] * y / white[1], y, white[2] * y / white[1]];
+
+    for _ in 0..MAX_ITERATIONS {
+        let current = forward(xyz, white);
+        let residual = [current[0] - target[0], current[1] - target[1], current[2] - target[2]];
+
+        if residual.iter().all(|r| r.abs() < TOLERANCE) {
+            break;
+        }
+
+        // Jacobian by central differences
+        let mut jacobian = [[0.0; 3]; 3];
+
+        for i in 0..3 {
+            let h = 1e-6 * xyz[i].abs().max(1.0);
+
+            let mut forwards = xyz;
+            let mut backwards = xyz;
+
+            forwards[i] += h;
+            backwards[i] -= h;
+
+            let (f, b) = (forward(forwards, white), forward(backwards, white));
+
+            for j in 0..3 {
+                jacobian[j][i] = (f[j] - b[j]) / (2.0 * h);
+            }
+        }
+
+        let step = math::mul_vec(&math::invert(&jacobian), residual);
+
+        for i in 0..3 {
+            xyz[i] -= step[i];
+        }
+    }
+
+    xyz
+}
+
+impl<C: Channel, Wp> Default for OsaUcs<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> OsaUcs<C, Wp> {
+        OsaUcs::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for OsaUcs<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> OsaUcs<C, Wp> {
+        let xyz = xyz.into_float();
+        let [wx, wy, wz] = Wp::float_xyz();
+
+        let hundred: FloatChannel<C> = math::cast(100.0);
+
+        let [l, j, g] = forward([xyz.x * hundred, xyz.y * hundred, xyz.z * hundred],
+                                [wx * hundred, wy * hundred, wz * hundred]);
+
+        OsaUcs::from_float(OsaUcs::raw(l, j, g))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for OsaUcs<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> OsaUcs<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<OsaUcs<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(osa: OsaUcs<C, Wp>) -> Xyz<C, Wp> {
+        let osa = osa.into_float();
+        let [wx, wy, wz] = Wp::tristimulus();
+
+        let f = |v: FloatChannel<C>| v.to_f64().unwrap_or(0.0);
+
+        let [x, y, z] = inverse([f(osa.l), f(osa.j), f(osa.g)], [wx * 100.0, wy * 100.0, wz * 100.0]);
+
+        let c = |v: f64| Channel::from_float(math::cast(v / 100.0));
+
+        Xyz::with_wp(c(x), c(y), c(z))
+    }
+}
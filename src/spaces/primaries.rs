@@ -0,0 +1,119 @@
+//! Chromaticities of the primaries of RGB color spaces.
+//!
+//! An RGB color space is defined by the `xy` chromaticities of its red, green and blue primaries and its
+//! native white. The conversion matrices between linear RGB and XYZ are derived from those four pairs,
+//! so defining the colorimetry of a new RGB space only requires implementing [`Primaries`](trait.Primaries.html).
+
+use num_traits::Float;
+
+use channels::*;
+use white_point::WhitePoint;
+use math::{self, Matrix3};
+
+use super::rgb;
+
+/// The chromaticities of the primaries and native white of an RGB color space
+pub trait Primaries {
+    /// The `xy` chromaticities of the red, green and blue primaries
+    const PRIMARIES: [[f64; 2]; 3];
+
+    /// The `xy` chromaticity of the native white
+    const WHITE: [f64; 2];
+
+    /// Returns the XYZ values of the native white, normalized to a luminance of 1.0.
+    fn white_xyz() -> [f64; 3] {
+        let [x, y] = Self::WHITE;
+
+        [x / y, 1.0, (1.0 - x - y) / y]
+    }
+
+    /// Computes the linear RGB to XYZ matrix, relative to the native white.
+    fn to_xyz_matrix<T: Float>() -> Matrix3<T> {
+        let [x, y, z] = Self::white_xyz();
+
+        rgb::primaries_matrix(&Self::PRIMARIES, [math::cast(x), math::cast(y), math::cast(z)])
+    }
+
+    /// Computes the XYZ to linear RGB matrix, relative to the native white.
+    fn from_xyz_matrix<T: Float>() -> Matrix3<T> {
+        math::invert(&Self::to_xyz_matrix())
+    }
+
+    /// Computes the linear RGB to XYZ matrix, adapted from the native white to the white point `Wp`
+    /// with the Bradford transform.
+    fn to_xyz_matrix_adapted<C: Channel, Wp>() -> Matrix3<FloatChannel<C>>
+    where
+        Wp: WhitePoint<C>,
+    {
+        rgb::to_xyz_matrix_adapted::<C, Wp>(&Self::PRIMARIES, &Self::white_xyz())
+    }
+
+    /// Computes the XYZ to linear RGB matrix, adapted from the white point `Wp` to the native white
+    /// with the Bradford transform.
+    fn from_xyz_matrix_adapted<C: Channel, Wp>() -> Matrix3<FloatChannel<C>>
+    where
+        Wp: WhitePoint<C>,
+    {
+        rgb::from_xyz_matrix_adapted::<C, Wp>(&Self::PRIMARIES, &Self::white_xyz())
+    }
+}
+
+/// The primaries of ITU-R BT.709 and sRGB, with a D65 white
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rec709;
+
+/// The primaries of Display P3, the DCI-P3 primaries with a D65 white
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct P3D65;
+
+/// The primaries of ITU-R BT.2020 and BT.2100, with a D65 white
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rec2020;
+
+/// The primaries of Adobe RGB (1998), with a D65 white
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AdobeRgb;
+
+/// The ACES AP0 primaries of ACES2065-1, with the ACES white
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ap0;
+
+/// The ACES AP1 primaries of ACEScg and ACEScct, with the ACES white
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ap1;
+
+/// The `xy` chromaticity of D65
+const D65_WHITE: [f64; 2] = [0.3127, 0.3290];
+
+/// The `xy` chromaticity of the ACES white
+const ACES_WHITE: [f64; 2] = [0.32168, 0.33767];
+
+impl Primaries for Rec709 {
+    const PRIMARIES: [[f64; 2]; 3] = [[0.64, 0.33], [0.30, 0.60], [0.15, 0.06]];
+    const WHITE: [f64; 2] = D65_WHITE;
+}
+
+impl Primaries for P3D65 {
+    const PRIMARIES: [[f64; 2]; 3] = [[0.680, 0.320], [0.265, 0.690], [0.150, 0.060]];
+    const WHITE: [f64; 2] = D65_WHITE;
+}
+
+impl Primaries for Rec2020 {
+    const PRIMARIES: [[f64; 2]; 3] = [[0.708, 0.292], [0.170, 0.797], [0.131, 0.046]];
+    const WHITE: [f64; 2] = D65_WHITE;
+}
+
+impl Primaries for AdobeRgb {
+    const PRIMARIES: [[f64; 2]; 3] = [[0.64, 0.33], [0.21, 0.71], [0.15, 0.06]];
+    const WHITE: [f64; 2] = D65_WHITE;
+}
+
+impl Primaries for Ap0 {
+    const PRIMARIES: [[f64; 2]; 3] = [[0.7347, 0.2653], [0.0, 1.0], [0.0001, -0.0770]];
+    const WHITE: [f64; 2] = ACES_WHITE;
+}
+
+impl Primaries for Ap1 {
+    const PRIMARIES: [[f64; 2]; 3] = [[0.713, 0.293], [0.165, 0.830], [0.128, 0.044]];
+    const WHITE: [f64; 2] = ACES_WHITE;
+}
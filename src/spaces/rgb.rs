@@ -0,0 +1,147 @@
+//! Shared machinery for the RGB color spaces.
+//!
+//! An RGB space is defined by the chromaticities of its three primaries and a transfer function.
+//! The conversion matrix to XYZ is derived from the primaries and the white point of the color.
+//!
+//! Spaces that are defined relative to a fixed native white point, such as the ACES encodings,
+//! derive their matrix from that white instead, and are then chromatically adapted to the white point
+//! of the color using the Bradford transform.
+
+use num_traits::{One, Float};
+
+use channels::*;
+use white_point::WhitePoint;
+use math::{self, Matrix3, Vector3};
+
+use adapt::{self, Bradford};
+
+pub use gamma::{srgb_encode, srgb_decode};
+
+/// Computes the linear RGB to XYZ matrix for the given `xy` primaries and XYZ white.
+pub fn primaries_matrix<T: Float>(primaries: &[[f64; 2]; 3], white: Vector3<T>) -> Matrix3<T> {
+    let mut m = [[T::one(); 3]; 3];
+
+    for (i, &[x, y]) in primaries.iter().enumerate() {
+        m[0][i] = math::cast(x / y);
+        m[2][i] = math::cast((1.0 - x - y) / y);
+    }
+
+    let s = math::mul_vec(&math::invert(&m), white);
+
+    for row in &mut m {
+        for (i, v) in row.iter_mut().enumerate() {
+            *v = *v * s[i];
+        }
+    }
+
+    m
+}
+
+/// Computes the Bradford chromatic adaptation matrix from one XYZ white to another.
+#[inline]
+pub fn bradford_matrix<T: Float>(from: Vector3<T>, to: Vector3<T>) -> Matrix3<T> {
+    adapt::matrix::<Bradford, T>(from, to)
+}
+
+/// Computes the Bradford chromatic adaptation matrix from the white point `Wp` to the white point `To`.
+#[inline]
+pub fn adaptation_matrix<C: Channel, Wp, To>() -> Matrix3<FloatChannel<C>>
+where
+    Wp: WhitePoint<C>,
+    To: WhitePoint<FloatChannel<C>>,
+{
+    let [x, y, z] = To::tristimulus();
+
+    bradford_matrix(Wp::float_xyz(), [math::cast(x), math::cast(y), math::cast(z)])
+}
+
+/// Computes the linear RGB to XYZ matrix for the given `xy` primaries,
+/// relative to the white point `Wp`.
+#[inline]
+pub fn to_xyz_matrix<C: Channel, Wp>(primaries: &[[f64; 2]; 3]) -> Matrix3<FloatChannel<C>>
+where
+    Wp: WhitePoint<C>,
+{
+    primaries_matrix(primaries, Wp::float_xyz())
+}
+
+/// Computes the XYZ to linear RGB matrix for the given `xy` primaries,
+/// relative to the white point `Wp`.
+#[inline]
+pub fn from_xyz_matrix<C: Channel, Wp>(primaries: &[[f64; 2]; 3]) -> Matrix3<FloatChannel<C>>
+where
+    Wp: WhitePoint<C>,
+{
+    math::invert(&to_xyz_matrix::<C, Wp>(primaries))
+}
+
+/// Computes the linear RGB to XYZ matrix for the given `xy` primaries and their native XYZ white,
+/// adapted to the white point `Wp`.
+pub fn to_xyz_matrix_adapted<C: Channel, Wp>(primaries: &[[f64; 2]; 3], native_white: &Vector3<f64>) -> Matrix3<FloatChannel<C>>
+where
+    Wp: WhitePoint<C>,
+{
+    let native_white = [math::cast(native_white[0]), math::cast(native_white[1]), math::cast(native_white[2])];
+
+    let m = primaries_matrix(primaries, native_white);
+
+    math::mul(&bradford_matrix(native_white, Wp::float_xyz()), &m)
+}
+
+/// Computes the XYZ to linear RGB matrix for the given `xy` primaries and their native XYZ white,
+/// adapted from the white point `Wp`.
+#[inline]
+pub fn from_xyz_matrix_adapted<C: Channel, Wp>(primaries: &[[f64; 2]; 3], native_white: &Vector3<f64>) -> Matrix3<FloatChannel<C>>
+where
+    Wp: WhitePoint<C>,
+{
+    math::invert(&to_xyz_matrix_adapted::<C, Wp>(primaries, native_white))
+}
+
+/// The offset of color difference channels, as the nearest value to one half representable by the channel type,
+/// such as 128 for `u8` channels.
+#[inline]
+pub fn chroma_offset<C: Channel>() -> FloatChannel<C> {
+    C::from_float(math::cast(0.5)).into_float()
+}
+
+/// Computes the hue of an RGB color in degrees, along with its largest and smallest channels.
+///
+/// Achromatic colors have a hue of zero.
+pub fn hue_max_min<T: Float>(r: T, g: T, b: T) -> (T, T, T) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+
+    let delta = max - min;
+
+    let hue = if delta <= T::zero() {
+        T::zero()
+    } else if max == r {
+        (g - b) / delta
+    } else if max == g {
+        (b - r) / delta + math::cast(2.0)
+    } else {
+        (r - g) / delta + math::cast(4.0)
+    };
+
+    (math::normalize_hue(hue * math::cast(60.0)), max, min)
+}
+
+/// Computes RGB channels from a hue in degrees, the chroma, and the value of the smallest channel.
+pub fn from_hue_chroma<T: Float>(hue: T, chroma: T, min: T) -> Vector3<T> {
+    let sector = math::normalize_hue(hue) / math::cast(60.0);
+
+    let x = chroma * (T::one() - (sector % math::cast(2.0) - T::one()).abs());
+    let z = T::zero();
+
+    let [r, g, b] = match sector.to_u8().unwrap_or(0) {
+        0 => [chroma, x, z],
+        1 => [x, chroma, z],
+        2 => [z, chroma, x],
+        3 => [z, x, chroma],
+        4 => [x, z, chroma],
+        _ => [chroma, z, x],
+    };
+
+    [r + min, g + min, b + min]
+}
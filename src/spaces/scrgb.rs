@@ -0,0 +1,156 @@
+//! The scRGB color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::rgb;
+use super::srgb::SRGB_PRIMARIES;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The scRGB color space (IEC 61966-2-2)
+    ///
+    /// scRGB stores linear light with the primaries and white point of sRGB, but allows channel values
+    /// outside of 0.0 to 1.0. Negative values describe colors outside of the sRGB gamut, and values above
+    /// 1.0 describe colors brighter than sRGB white at 80 cd/m², so wide gamut and high dynamic range
+    /// colors can be carried without clamping. It is the extended range format of Windows composition.
+    ///
+    /// Conversions from scRGB to the bounded RGB spaces clamp each channel to their range,
+    /// while conversions to XYZ and the other unbounded spaces preserve the full value.
+    struct ScRgb : TripleChannel => RGB {
+        /// The linear amount of red light, where 1.0 is the red of sRGB white.
+        pub r,
+        /// The linear amount of green light, where 1.0 is the green of sRGB white.
+        pub g,
+        /// The linear amount of blue light, where 1.0 is the blue of sRGB white.
+        pub b,
+    }
+}
+
+pub type ScRgba<C, Wp> = Alpha<ScRgb<C, Wp>>;
+
+impl_color_arithmetic!(ScRgb);
+
+use ::spaces::all::*;
+
+/// Clamps a channel to the bounded range of 0.0 to 1.0
+#[inline]
+fn clamp<T: Float>(value: T) -> T {
+    value.max(T::zero()).min(T::one())
+}
+
+impl<C: Channel, Wp> Default for ScRgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> ScRgb<C, Wp> {
+        ScRgb::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for ScRgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> ScRgb<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let m = rgb::from_xyz_matrix::<C, Wp>(&SRGB_PRIMARIES);
+        let [r, g, b] = math::mul_vec(&m, [xyz.x, xyz.y, xyz.z]);
+
+        ScRgb::from_float(ScRgb::raw(r, g, b))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for ScRgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> ScRgb<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<LinearSrgb<C, Wp>> for ScRgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(linear: LinearSrgb<C, Wp>) -> ScRgb<C, Wp> {
+        ScRgb::with_wp(linear.r, linear.g, linear.b)
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for ScRgb<C, Wp>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    fn from(srgb: Srgb<C, Wp>) -> ScRgb<C, Wp> {
+        ScRgb::from_float(LinearSrgb::from(srgb.into_float()).into())
+    }
+}
+
+impl<C: Channel, Wp> From<DisplayP3<C, Wp>> for ScRgb<C, Wp>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    fn from(p3: DisplayP3<C, Wp>) -> ScRgb<C, Wp> {
+        ScRgb::from_float(Xyz::from(p3.into_float()).into())
+    }
+}
+
+impl<C: Channel, Wp> From<ScRgb<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: ScRgb<C, Wp>) -> Xyz<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let m = rgb::to_xyz_matrix::<C, Wp>(&SRGB_PRIMARIES);
+        let [x, y, z] = math::mul_vec(&m, [rgb.r, rgb.g, rgb.b]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
+
+impl<C: Channel, Wp> From<ScRgb<C, Wp>> for LinearSrgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: ScRgb<C, Wp>) -> LinearSrgb<C, Wp> {
+        let rgb = rgb.into_float();
+
+        LinearSrgb::with_wp(Channel::from_float(clamp(rgb.r)),
+                            Channel::from_float(clamp(rgb.g)),
+                            Channel::from_float(clamp(rgb.b)))
+    }
+}
+
+impl<C: Channel, Wp> From<ScRgb<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    fn from(rgb: ScRgb<C, Wp>) -> Srgb<C, Wp> {
+        Srgb::from_float(LinearSrgb::from(rgb.into_float()).into())
+    }
+}
+
+impl<C: Channel, Wp> From<ScRgb<C, Wp>> for DisplayP3<C, Wp>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    fn from(rgb: ScRgb<C, Wp>) -> DisplayP3<C, Wp> {
+        let p3 = DisplayP3::from(Xyz::from(rgb.into_float()));
+
+        DisplayP3::with_wp(Channel::from_float(clamp(p3.r)),
+                           Channel::from_float(clamp(p3.g)),
+                           Channel::from_float(clamp(p3.b)))
+    }
+}
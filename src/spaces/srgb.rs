@@ -0,0 +1,136 @@
+//! The sRGB color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::rgb;
+use super::primaries::{Primaries, Rec709};
+
+/// Chromaticities of the sRGB (and Rec. 709) red, green and blue primaries
+pub const SRGB_PRIMARIES: [[f64; 2]; 3] = Rec709::PRIMARIES;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The sRGB color space (IEC 61966-2-1)
+    ///
+    /// sRGB is the standard RGB color space for monitors, printers and the internet.
+    /// Channels are stored gamma-encoded with the sRGB transfer function, and range from 0.0 to 1.0
+    /// for floating point channels, or over the full range of integer channels.
+    ///
+    /// sRGB is defined relative to D65. Using any other white point will derive the conversion
+    /// matrices relative to that white point instead.
+    struct Srgb : TripleChannel => RGB {
+        /// The gamma-encoded amount of red light.
+        pub r,
+        /// The gamma-encoded amount of green light.
+        pub g,
+        /// The gamma-encoded amount of blue light.
+        pub b,
+    }
+}
+
+pub type Srgba<C, Wp> = Alpha<Srgb<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Srgb<C, Wp> {
+        Srgb::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Srgb<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let m = rgb::from_xyz_matrix::<C, Wp>(&SRGB_PRIMARIES);
+        let [r, g, b] = math::mul_vec(&m, [xyz.x, xyz.y, xyz.z]);
+
+        Srgb::from_float(Srgb::raw(rgb::srgb_encode(r), rgb::srgb_encode(g), rgb::srgb_encode(b)))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Srgb<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<DisplayP3<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C> + WhitePoint<FloatChannel<C>>,
+{
+    fn from(p3: DisplayP3<C, Wp>) -> Srgb<C, Wp> {
+        Srgb::from_float(Xyz::from(p3.into_float()).into())
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> Xyz<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let m = rgb::to_xyz_matrix::<C, Wp>(&SRGB_PRIMARIES);
+        let [x, y, z] = math::mul_vec(&m, [rgb::srgb_decode(rgb.r), rgb::srgb_decode(rgb.g), rgb::srgb_decode(rgb.b)]);
+
+        Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spaces::srgb::Srgb;
+    use spaces::xyz::Xyz;
+    use spaces::display_p3::DisplayP3;
+
+    #[test]
+    fn u8_white_to_xyz() {
+        let xyz = Xyz::<u8>::from(Srgb::<u8>::new(255, 255, 255));
+
+        // The Z of D65 is above 1.0, so it saturates
+        assert_eq!((xyz.x, xyz.y, xyz.z), (242, 255, 255));
+    }
+
+    #[test]
+    fn u8_round_trip() {
+        for v in 128..241 {
+            let rgb = Srgb::<u8>::from(Xyz::<u8>::from(Srgb::<u8>::new(v, v, v)));
+
+            for &c in &[rgb.r, rgb.g, rgb.b] {
+                assert!((c as i32 - v as i32).abs() <= 2, "{} round trips to {:?}", v, rgb);
+            }
+        }
+
+        let rgb = Srgb::<u8>::from(Xyz::<u8>::from(Srgb::<u8>::new(188, 188, 188)));
+
+        assert_eq!((rgb.r, rgb.g, rgb.b), (188, 188, 188));
+    }
+
+    #[test]
+    fn u8_white_to_display_p3() {
+        let rgb = Srgb::<u8>::from(DisplayP3::<u8>::new(255, 255, 255));
+        let p3 = DisplayP3::<u8>::from(Srgb::<u8>::new(255, 255, 255));
+
+        assert_eq!((rgb.r, rgb.g, rgb.b), (255, 255, 255));
+        assert_eq!((p3.r, p3.g, p3.b), (255, 255, 255));
+    }
+}
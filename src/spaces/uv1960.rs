@@ -0,0 +1,108 @@
+//! The CIE 1960 UCS chromaticity coordinates.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The CIE 1960 UCS chromaticity coordinates
+    ///
+    /// The CIE 1960 uniform chromaticity scale is a projective transformation of the xy chromaticity
+    /// diagram where distances are more uniform. It is no longer recommended for color differences, but
+    /// is still the space in which correlated color temperature and Duv are defined.
+    ///
+    /// As only the chromaticity is stored, converting back to XYZ requires the luminance,
+    /// see [`into_yxy`](#method.into_yxy).
+    struct Uv1960 : DualChannel => UV1960 {
+        /// u is the horizontal chromaticity coordinate, 4X/(X + 15Y + 3Z).
+        pub u,
+        /// v is the vertical chromaticity coordinate, 6Y/(X + 15Y + 3Z).
+        pub v,
+    }
+}
+
+pub type Uv1960a<C, Wp> = Alpha<Uv1960<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Uv1960<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Uv1960<C, Wp> {
+        let [x, y, z] = Wp::float_xyz();
+
+        let d = x + y * math::cast(15.0) + z * math::cast(3.0);
+
+        Uv1960::from_float(Uv1960::raw(x * math::cast(4.0) / d, y * math::cast(6.0) / d))
+    }
+}
+
+impl<C: Channel, Wp> Uv1960<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Combines the chromaticity with a luminance.
+    pub fn into_yxy(self, luma: C) -> Yxy<C, Wp> {
+        let uv = self.into_float();
+
+        let d = uv.u * math::cast(2.0) - uv.v * math::cast(8.0) + math::cast(4.0);
+
+        if !d.is_normal() {
+            return Yxy::with_wp(C::zero(), C::zero(), luma);
+        }
+
+        Yxy::with_wp(Channel::from_float(uv.u * math::cast(3.0) / d),
+                     Channel::from_float(uv.v * math::cast(2.0) / d),
+                     luma)
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Uv1960<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Uv1960<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let d = xyz.x + xyz.y * math::cast(15.0) + xyz.z * math::cast(3.0);
+
+        if !d.is_normal() {
+            return Uv1960::with_wp(C::zero(), C::zero());
+        }
+
+        Uv1960::from_float(Uv1960::raw(xyz.x * math::cast(4.0) / d, xyz.y * math::cast(6.0) / d))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Uv1960<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Uv1960<C, Wp> {
+        let yxy = yxy.into_float();
+
+        let d = yxy.y * math::cast(12.0) - yxy.x * math::cast(2.0) + math::cast(3.0);
+
+        Uv1960::from_float(Uv1960::raw(yxy.x * math::cast(4.0) / d, yxy.y * math::cast(6.0) / d))
+    }
+}
+
+impl<C: Channel, Wp> From<UvPrime<C, Wp>> for Uv1960<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(uv: UvPrime<C, Wp>) -> Uv1960<C, Wp> {
+        let uv = uv.into_float();
+
+        Uv1960::from_float(Uv1960::raw(uv.u, uv.v * math::cast(2.0 / 3.0)))
+    }
+}
@@ -0,0 +1,108 @@
+//! The CIE 1976 UCS chromaticity coordinates.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The CIE 1976 UCS chromaticity coordinates
+    ///
+    /// The CIE 1976 uniform chromaticity scale, or u'v', stretches the v axis of the 1960 scale by
+    /// one and a half to improve its uniformity. It is the chromaticity diagram underlying CIE L*u*v*,
+    /// and the recommended diagram for plotting gamuts.
+    ///
+    /// As only the chromaticity is stored, converting back to XYZ requires the luminance,
+    /// see [`into_yxy`](#method.into_yxy).
+    struct UvPrime : DualChannel => UVPRIME {
+        /// u' is the horizontal chromaticity coordinate, 4X/(X + 15Y + 3Z).
+        pub u,
+        /// v' is the vertical chromaticity coordinate, 9Y/(X + 15Y + 3Z).
+        pub v,
+    }
+}
+
+pub type UvPrimea<C, Wp> = Alpha<UvPrime<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for UvPrime<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> UvPrime<C, Wp> {
+        let [x, y, z] = Wp::float_xyz();
+
+        let d = x + y * math::cast(15.0) + z * math::cast(3.0);
+
+        UvPrime::from_float(UvPrime::raw(x * math::cast(4.0) / d, y * math::cast(9.0) / d))
+    }
+}
+
+impl<C: Channel, Wp> UvPrime<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Combines the chromaticity with a luminance.
+    pub fn into_yxy(self, luma: C) -> Yxy<C, Wp> {
+        let uv = self.into_float();
+
+        let d = uv.u * math::cast(6.0) - uv.v * math::cast(16.0) + math::cast(12.0);
+
+        if !d.is_normal() {
+            return Yxy::with_wp(C::zero(), C::zero(), luma);
+        }
+
+        Yxy::with_wp(Channel::from_float(uv.u * math::cast(9.0) / d),
+                     Channel::from_float(uv.v * math::cast(4.0) / d),
+                     luma)
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for UvPrime<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> UvPrime<C, Wp> {
+        let xyz = xyz.into_float();
+
+        let d = xyz.x + xyz.y * math::cast(15.0) + xyz.z * math::cast(3.0);
+
+        if !d.is_normal() {
+            return UvPrime::with_wp(C::zero(), C::zero());
+        }
+
+        UvPrime::from_float(UvPrime::raw(xyz.x * math::cast(4.0) / d, xyz.y * math::cast(9.0) / d))
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for UvPrime<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> UvPrime<C, Wp> {
+        let yxy = yxy.into_float();
+
+        let d = yxy.y * math::cast(12.0) - yxy.x * math::cast(2.0) + math::cast(3.0);
+
+        UvPrime::from_float(UvPrime::raw(yxy.x * math::cast(4.0) / d, yxy.y * math::cast(9.0) / d))
+    }
+}
+
+impl<C: Channel, Wp> From<Uv1960<C, Wp>> for UvPrime<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(uv: Uv1960<C, Wp>) -> UvPrime<C, Wp> {
+        let uv = uv.into_float();
+
+        UvPrime::from_float(UvPrime::raw(uv.u, uv.v * math::cast(1.5)))
+    }
+}
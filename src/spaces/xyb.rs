@@ -0,0 +1,129 @@
+//! The XYB color space.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math::{self, Matrix3};
+
+/// Linear sRGB to the opsin absorbance of the cones
+const OPSIN_ABSORBANCE: Matrix3<f64> = [
+    [0.30, 0.622, 0.078],
+    [0.23, 0.692, 0.078],
+    [0.2434226892454782, 0.2047674442449682, 0.5518098665095536],
+];
+
+/// Bias added to the absorbance before the cube root, which keeps the slope finite near black
+const OPSIN_BIAS: f64 = 0.0037930732552754493;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The XYB color space
+    ///
+    /// XYB is the perceptual color space of the JPEG XL image format and the Butteraugli image metric.
+    /// It applies a biased cube root to approximate cone responses computed from linear sRGB, then
+    /// separates the long and medium wavelength responses into a red-green opponent channel and luminance.
+    ///
+    /// This is the unscaled XYB of the specification, before the quantization scaling applied by encoders.
+    struct Xyb : TripleChannel => XYB {
+        /// X is the red-green opponent channel, half the difference of the long and medium responses.
+        /// It is very close to zero, roughly from -0.015 to 0.03.
+        pub x,
+        /// Y is the luminance, half the sum of the long and medium responses, from 0.0 for black to
+        /// about 0.85 for white.
+        pub y,
+        /// B is the response of the short wavelength cones, from 0.0 for black to about 0.85 for white.
+        pub b,
+    }
+}
+
+pub type Xyba<C, Wp> = Alpha<Xyb<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for Xyb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> Xyb<C, Wp> {
+        Xyb::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<LinearSrgb<C, Wp>> for Xyb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: LinearSrgb<C, Wp>) -> Xyb<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let bias: FloatChannel<C> = math::cast(OPSIN_BIAS);
+        let bias_cbrt = bias.cbrt();
+
+        let [l, m, s] = math::mul_vec(&math::matrix(&OPSIN_ABSORBANCE), [rgb.r, rgb.g, rgb.b]);
+
+        let l = (l + bias).cbrt() - bias_cbrt;
+        let m = (m + bias).cbrt() - bias_cbrt;
+        let s = (s + bias).cbrt() - bias_cbrt;
+
+        let half: FloatChannel<C> = math::cast(0.5);
+
+        Xyb::from_float(Xyb::raw((l - m) * half, (l + m) * half, s))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for Xyb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> Xyb<C, Wp> {
+        LinearSrgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for Xyb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> Xyb<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Xyb<C, Wp>> for LinearSrgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyb: Xyb<C, Wp>) -> LinearSrgb<C, Wp> {
+        let xyb = xyb.into_float();
+
+        let bias: FloatChannel<C> = math::cast(OPSIN_BIAS);
+        let bias_cbrt = bias.cbrt();
+
+        let unbias = |v: FloatChannel<C>| {
+            let v = v + bias_cbrt;
+
+            v * v * v - bias
+        };
+
+        let lms = [unbias(xyb.y + xyb.x), unbias(xyb.y - xyb.x), unbias(xyb.b)];
+
+        let [r, g, b] = math::mul_vec(&math::invert(&math::matrix(&OPSIN_ABSORBANCE)), lms);
+
+        LinearSrgb::with_wp(Channel::from_float(r), Channel::from_float(g), Channel::from_float(b))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyb<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyb: Xyb<C, Wp>) -> Xyz<C, Wp> {
+        LinearSrgb::from(xyb).into()
+    }
+}
@@ -35,6 +35,8 @@ declare_color_formats_with_components_plus_alpha_specialization! {
 
 pub type Xyza<C, Wp> = Alpha<Xyz<C, Wp>>;
 
+impl_color_arithmetic!(Xyz);
+
 use ::spaces::all::*;
 
 impl<C: Channel, Wp> Default for Xyz<C, Wp>
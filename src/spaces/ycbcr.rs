@@ -0,0 +1,256 @@
+//! The Y'CbCr family of color encodings.
+//!
+//! Y'CbCr splits gamma-encoded RGB into a luma component and two color difference components.
+//! The weights used to compute luma differ between video standards, and video signals are usually quantized
+//! to a limited range of code values, so both the standard and the range are part of the type.
+//! Converting between standards must go through RGB, and converting between ranges must be done explicitly
+//! with [`into_range`](struct.YCbCr.html#method.into_range), which prevents decoding with the wrong parameters.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::{WhitePoint, D65};
+use math::{self, Vector3};
+
+use super::rgb;
+
+/// A Y'CbCr matrix standard, defined by the luma weights of the red and blue channels.
+pub trait YCbCrStandard {
+    /// The luma weight of red
+    const KR: f64;
+
+    /// The luma weight of blue
+    const KB: f64;
+}
+
+/// ITU-R BT.601, used for standard definition video and JPEG/JFIF
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bt601;
+
+/// ITU-R BT.709, used for high definition video
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bt709;
+
+/// ITU-R BT.2020 non-constant luminance, used for ultra high definition and HDR video
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bt2020;
+
+impl YCbCrStandard for Bt601 {
+    const KR: f64 = 0.299;
+    const KB: f64 = 0.114;
+}
+
+impl YCbCrStandard for Bt709 {
+    const KR: f64 = 0.2126;
+    const KB: f64 = 0.0722;
+}
+
+impl YCbCrStandard for Bt2020 {
+    const KR: f64 = 0.2627;
+    const KB: f64 = 0.0593;
+}
+
+/// The quantization range of Y'CbCr channels.
+pub trait YCbCrRange {
+    /// Quantizes luma from 0.0 to 1.0 and chroma from -0.5 to 0.5 into the stored channel values.
+    fn quantize<C: Channel>(y: FloatChannel<C>, cb: FloatChannel<C>, cr: FloatChannel<C>) -> Vector3<FloatChannel<C>>;
+
+    /// Recovers luma from 0.0 to 1.0 and chroma from -0.5 to 0.5 from the stored channel values.
+    fn dequantize<C: Channel>(y: FloatChannel<C>, cb: FloatChannel<C>, cr: FloatChannel<C>) -> Vector3<FloatChannel<C>>;
+}
+
+/// Full range quantization, as used by JPEG/JFIF, where every channel spans the entire range of the channel type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FullRange;
+
+/// Limited range quantization, as used by broadcast video, with luma codes from 16 to 235 and chroma codes from 16 to 240.
+///
+/// Codes are relative to 8-bit channels, so `u8` channels hold the exact codes while other channel types
+/// hold the same fractions of their range.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LimitedRange;
+
+impl YCbCrRange for FullRange {
+    #[inline]
+    fn quantize<C: Channel>(y: FloatChannel<C>, cb: FloatChannel<C>, cr: FloatChannel<C>) -> Vector3<FloatChannel<C>> {
+        let offset = rgb::chroma_offset::<C>();
+
+        [y, cb + offset, cr + offset]
+    }
+
+    #[inline]
+    fn dequantize<C: Channel>(y: FloatChannel<C>, cb: FloatChannel<C>, cr: FloatChannel<C>) -> Vector3<FloatChannel<C>> {
+        let offset = rgb::chroma_offset::<C>();
+
+        [y, cb - offset, cr - offset]
+    }
+}
+
+impl YCbCrRange for LimitedRange {
+    #[inline]
+    fn quantize<C: Channel>(y: FloatChannel<C>, cb: FloatChannel<C>, cr: FloatChannel<C>) -> Vector3<FloatChannel<C>> {
+        let c = math::cast::<FloatChannel<C>>;
+
+        [
+            (c(16.0) + c(219.0) * y) / c(255.0),
+            (c(128.0) + c(224.0) * cb) / c(255.0),
+            (c(128.0) + c(224.0) * cr) / c(255.0),
+        ]
+    }
+
+    #[inline]
+    fn dequantize<C: Channel>(y: FloatChannel<C>, cb: FloatChannel<C>, cr: FloatChannel<C>) -> Vector3<FloatChannel<C>> {
+        let c = math::cast::<FloatChannel<C>>;
+
+        [
+            (y * c(255.0) - c(16.0)) / c(219.0),
+            (cb * c(255.0) - c(128.0)) / c(224.0),
+            (cr * c(255.0) - c(128.0)) / c(224.0),
+        ]
+    }
+}
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// Y'CbCr color encoding
+    ///
+    /// Y'CbCr is the encoding used by digital video and JPEG images. Y' is a weighted sum of the
+    /// gamma-encoded RGB channels, while Cb and Cr are the scaled differences between blue and red and the luma.
+    /// Conversions are to and from sRGB values, as the RGB primaries of the standards are close enough that
+    /// they are usually treated as identical in practice.
+    ///
+    /// Like the integer encodings, the chroma components are offset so that every channel ranges from 0.0 to 1.0
+    /// for floating point channels, with neutral colors having chroma components of 0.5, or 128 for `u8` channels.
+    /// How the channels are quantized within that range is given by the range parameter `R`.
+    struct YCbCr<S: YCbCrStandard = Bt709, R: YCbCrRange = FullRange> : TripleChannel => YCBCR {
+        /// Y' is the luma of the color, the weighted sum of the gamma-encoded RGB channels.
+        pub y,
+        /// Cb is the difference between the blue channel and the luma, offset by one half.
+        pub cb,
+        /// Cr is the difference between the red channel and the luma, offset by one half.
+        pub cr,
+    }
+}
+
+/// Full range Y'CbCr with the BT.601 matrix
+pub type YCbCr601<C = f32, Wp = D65> = YCbCr<C, Wp, Bt601, FullRange>;
+
+/// Full range Y'CbCr with the BT.709 matrix
+pub type YCbCr709<C = f32, Wp = D65> = YCbCr<C, Wp, Bt709, FullRange>;
+
+/// Full range Y'CbCr with the BT.2020 non-constant luminance matrix
+pub type YCbCr2020<C = f32, Wp = D65> = YCbCr<C, Wp, Bt2020, FullRange>;
+
+/// Limited range Y'CbCr with the BT.601 matrix
+pub type YCbCr601Limited<C = f32, Wp = D65> = YCbCr<C, Wp, Bt601, LimitedRange>;
+
+/// Limited range Y'CbCr with the BT.709 matrix
+pub type YCbCr709Limited<C = f32, Wp = D65> = YCbCr<C, Wp, Bt709, LimitedRange>;
+
+/// Limited range Y'CbCr with the BT.2020 non-constant luminance matrix
+pub type YCbCr2020Limited<C = f32, Wp = D65> = YCbCr<C, Wp, Bt2020, LimitedRange>;
+
+pub type YCbCra<C, Wp, S, R> = Alpha<YCbCr<C, Wp, S, R>>;
+
+impl<C: Channel, Wp, S: YCbCrStandard, R: YCbCrRange> YCbCr<C, Wp, S, R>
+where
+    Wp: WhitePoint<C>,
+{
+    /// Requantizes the color into another range.
+    pub fn into_range<R2: YCbCrRange>(self) -> YCbCr<C, Wp, S, R2> {
+        let YCBCR { y, cb, cr } = *self.into_float();
+
+        let [y, cb, cr] = R::dequantize::<C>(y, cb, cr);
+        let [y, cb, cr] = R2::quantize::<C>(y, cb, cr);
+
+        YCbCr::from_float(YCbCr::raw(y, cb, cr))
+    }
+}
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp, S: YCbCrStandard, R: YCbCrRange> Default for YCbCr<C, Wp, S, R>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> YCbCr<C, Wp, S, R> {
+        let zero = Zero::zero();
+        let [y, cb, cr] = R::quantize::<C>(zero, zero, zero);
+
+        YCbCr::from_float(YCbCr::raw(y, cb, cr))
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard, R: YCbCrRange> From<Srgb<C, Wp>> for YCbCr<C, Wp, S, R>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> YCbCr<C, Wp, S, R> {
+        let rgb = rgb.into_float();
+
+        let (kr, kb): (FloatChannel<C>, FloatChannel<C>) = (math::cast(S::KR), math::cast(S::KB));
+        let kg = FloatChannel::<C>::one() - kr - kb;
+        let half: FloatChannel<C> = math::cast(0.5);
+
+        let y = kr * rgb.r + kg * rgb.g + kb * rgb.b;
+
+        let cb = half * (rgb.b - y) / (FloatChannel::<C>::one() - kb);
+        let cr = half * (rgb.r - y) / (FloatChannel::<C>::one() - kr);
+
+        let [y, cb, cr] = R::quantize::<C>(y, cb, cr);
+
+        YCbCr::from_float(YCbCr::raw(y, cb, cr))
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard, R: YCbCrRange> From<Xyz<C, Wp>> for YCbCr<C, Wp, S, R>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> YCbCr<C, Wp, S, R> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard, R: YCbCrRange> From<Yxy<C, Wp>> for YCbCr<C, Wp, S, R>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> YCbCr<C, Wp, S, R> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard, R: YCbCrRange> From<YCbCr<C, Wp, S, R>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ycc: YCbCr<C, Wp, S, R>) -> Srgb<C, Wp> {
+        let YCBCR { y, cb, cr } = *ycc.into_float();
+        let [y, cb, cr] = R::dequantize::<C>(y, cb, cr);
+
+        let (kr, kb): (FloatChannel<C>, FloatChannel<C>) = (math::cast(S::KR), math::cast(S::KB));
+        let kg = FloatChannel::<C>::one() - kr - kb;
+        let one = FloatChannel::<C>::one();
+
+        let r = y + cr * (one - kr) * math::cast(2.0);
+        let b = y + cb * (one - kb) * math::cast(2.0);
+        let g = (y - kr * r - kb * b) / kg;
+
+        Srgb::with_wp(Channel::from_float(r), Channel::from_float(g), Channel::from_float(b))
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard, R: YCbCrRange> From<YCbCr<C, Wp, S, R>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ycc: YCbCr<C, Wp, S, R>) -> Xyz<C, Wp> {
+        Srgb::from(ycc).into()
+    }
+}
@@ -0,0 +1,114 @@
+//! The YCoCg color encoding.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::rgb;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The YCoCg color encoding
+    ///
+    /// YCoCg splits gamma-encoded sRGB into luma and orange and green chroma components using only
+    /// additions and multiplications by powers of two, which makes it cheap to compute and
+    /// decorrelates the channels better than Y'CbCr for natural images. It is used by texture
+    /// compression formats and some video codecs.
+    ///
+    /// Like Y'CbCr, the chroma components are offset so that every channel ranges from 0.0 to 1.0
+    /// for floating point channels, with neutral colors having chroma components of 0.5, or 128 for `u8` channels.
+    struct YCoCg : TripleChannel => YCOCG {
+        /// Y is the luma of the color, a quarter of the red and blue channels plus half the green channel.
+        pub y,
+        /// Co is the orange chroma, half the difference between the red and blue channels, offset by one half.
+        pub co,
+        /// Cg is the green chroma, half the difference between the green channel and the average of
+        /// the red and blue channels, offset by one half.
+        pub cg,
+    }
+}
+
+pub type YCoCga<C, Wp> = Alpha<YCoCg<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for YCoCg<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> YCoCg<C, Wp> {
+        let offset = rgb::chroma_offset::<C>();
+
+        YCoCg::from_float(YCoCg::raw(Zero::zero(), offset, offset))
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for YCoCg<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> YCoCg<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let (half, quarter): (FloatChannel<C>, FloatChannel<C>) = (math::cast(0.5), math::cast(0.25));
+        let offset = rgb::chroma_offset::<C>();
+
+        let y = quarter * (rgb.r + rgb.b) + half * rgb.g;
+        let co = half * (rgb.r - rgb.b) + offset;
+        let cg = half * rgb.g - quarter * (rgb.r + rgb.b) + offset;
+
+        YCoCg::from_float(YCoCg::raw(y, co, cg))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for YCoCg<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> YCoCg<C, Wp> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for YCoCg<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> YCoCg<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<YCoCg<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ycocg: YCoCg<C, Wp>) -> Srgb<C, Wp> {
+        let ycocg = ycocg.into_float();
+
+        let offset = rgb::chroma_offset::<C>();
+
+        let (co, cg) = (ycocg.co - offset, ycocg.cg - offset);
+        let t = ycocg.y - cg;
+
+        Srgb::with_wp(Channel::from_float(t + co),
+                      Channel::from_float(ycocg.y + cg),
+                      Channel::from_float(t - co))
+    }
+}
+
+impl<C: Channel, Wp> From<YCoCg<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ycocg: YCoCg<C, Wp>) -> Xyz<C, Wp> {
+        Srgb::from(ycocg).into()
+    }
+}
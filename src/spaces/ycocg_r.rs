@@ -0,0 +1,162 @@
+//! The lossless YCoCg-R color encoding.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float, ToPrimitive, NumCast};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math;
+
+use super::rgb;
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The YCoCg-R color encoding
+    ///
+    /// YCoCg-R is the reversible variant of YCoCg, computed with integer lifting steps so that
+    /// integer channels round-trip exactly through sRGB. The chroma components are twice those of YCoCg,
+    /// and would need one extra bit of precision to be stored without loss. Instead, the lifting steps wrap around
+    /// the range of integer channels, so saturated colors do not have meaningful luma and chroma components.
+    /// This keeps the transform lossless and is harmless for compression, but integer YCoCg-R values should only
+    /// be interpreted after converting back to RGB.
+    ///
+    /// Exact round-tripping applies to unsigned integer channels of up to 32 bits. Other channel types
+    /// use the same transform without wrapping or rounding.
+    struct YCoCgR : TripleChannel => YCOCGR {
+        /// Y is the luma of the color, a quarter of the red and blue channels plus half the green channel.
+        pub y,
+        /// Co is the orange chroma, the difference between the red and blue channels, offset by one half.
+        pub co,
+        /// Cg is the green chroma, the difference between the green channel and the average of
+        /// the red and blue channels, offset by one half.
+        pub cg,
+    }
+}
+
+pub type YCoCgRa<C, Wp> = Alpha<YCoCgR<C, Wp>>;
+
+use ::spaces::all::*;
+
+/// The number of distinct codes of an integer channel type, or `None` for floating point channels.
+#[inline]
+fn code_count<C: Channel>() -> Option<i64> {
+    let max = C::from_float(One::one());
+
+    if max == C::one() { None } else { max.to_i64().and_then(|max| max.checked_add(1)) }
+}
+
+impl<C: Channel, Wp> Default for YCoCgR<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> YCoCgR<C, Wp> {
+        let offset = rgb::chroma_offset::<C>();
+
+        YCoCgR::from_float(YCoCgR::raw(Zero::zero(), offset, offset))
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for YCoCgR<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> YCoCgR<C, Wp> {
+        if let Some(n) = code_count::<C>() {
+            let half = n / 2;
+
+            // Wraps differences into the signed range centered on zero
+            let wrap = |x: i64| (x + half).rem_euclid(n) - half;
+
+            let code = |c: C| c.to_i64().unwrap_or(0);
+            let (r, g, b) = (code(rgb.r), code(rgb.g), code(rgb.b));
+
+            let co = wrap(r - b);
+            let t = (b + (co >> 1)).rem_euclid(n);
+            let cg = wrap(g - t);
+            let y = (t + (cg >> 1)).rem_euclid(n);
+
+            let channel = |x: i64| <C as NumCast>::from(x).unwrap();
+
+            return YCoCgR::raw(channel(y), channel(co + half), channel(cg + half));
+        }
+
+        let rgb = rgb.into_float();
+
+        let half: FloatChannel<C> = math::cast(0.5);
+        let offset = rgb::chroma_offset::<C>();
+
+        let co = rgb.r - rgb.b;
+        let t = rgb.b + co * half;
+        let cg = rgb.g - t;
+        let y = t + cg * half;
+
+        YCoCgR::from_float(YCoCgR::raw(y, co + offset, cg + offset))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for YCoCgR<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> YCoCgR<C, Wp> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for YCoCgR<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> YCoCgR<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<YCoCgR<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ycocg: YCoCgR<C, Wp>) -> Srgb<C, Wp> {
+        if let Some(n) = code_count::<C>() {
+            let half = n / 2;
+
+            let code = |c: C| c.to_i64().unwrap_or(0);
+            let (y, co, cg) = (code(ycocg.y), code(ycocg.co) - half, code(ycocg.cg) - half);
+
+            let t = (y - (cg >> 1)).rem_euclid(n);
+            let g = (cg + t).rem_euclid(n);
+            let b = (t - (co >> 1)).rem_euclid(n);
+            let r = (b + co).rem_euclid(n);
+
+            let channel = |x: i64| <C as NumCast>::from(x).unwrap();
+
+            return Srgb::with_wp(channel(r), channel(g), channel(b));
+        }
+
+        let ycocg = ycocg.into_float();
+
+        let half: FloatChannel<C> = math::cast(0.5);
+        let offset = rgb::chroma_offset::<C>();
+
+        let (co, cg) = (ycocg.co - offset, ycocg.cg - offset);
+
+        let t = ycocg.y - cg * half;
+        let g = cg + t;
+        let b = t - co * half;
+
+        Srgb::with_wp(Channel::from_float(b + co), Channel::from_float(g), Channel::from_float(b))
+    }
+}
+
+impl<C: Channel, Wp> From<YCoCgR<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ycocg: YCoCgR<C, Wp>) -> Xyz<C, Wp> {
+        Srgb::from(ycocg).into()
+    }
+}
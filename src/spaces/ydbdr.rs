@@ -0,0 +1,104 @@
+//! The YDbDr color encoding.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::WhitePoint;
+use math::{self, Matrix3};
+
+/// Gamma-encoded RGB to YDbDr
+const M: Matrix3<f64> = [
+    [0.299, 0.587, 0.114],
+    [-0.450, -0.883, 1.333],
+    [-1.333, 1.116, 0.217],
+];
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// The YDbDr color encoding
+    ///
+    /// YDbDr is the luma and color difference encoding of the SECAM analog television system.
+    /// It uses the same luma as BT.601, with color difference channels scaled for the frequency
+    /// modulation of SECAM, and is otherwise equivalent to YUV with the opposite sign for Dr.
+    ///
+    /// The color difference channels are signed, so YDbDr should be used with floating point channels.
+    struct YDbDr : TripleChannel => YDBDR {
+        /// Y' is the luma of the color, the weighted sum of the gamma-encoded RGB channels.
+        pub y,
+        /// Db is the scaled difference between the blue channel and the luma, from -1.333 to 1.333.
+        pub db,
+        /// Dr is the scaled difference between the luma and the red channel, from -1.333 to 1.333.
+        pub dr,
+    }
+}
+
+pub type YDbDra<C, Wp> = Alpha<YDbDr<C, Wp>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp> Default for YDbDr<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> YDbDr<C, Wp> {
+        YDbDr::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp> From<Srgb<C, Wp>> for YDbDr<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> YDbDr<C, Wp> {
+        let rgb = rgb.into_float();
+
+        let [y, db, dr] = math::mul_vec(&math::matrix(&M), [rgb.r, rgb.g, rgb.b]);
+
+        YDbDr::from_float(YDbDr::raw(y, db, dr))
+    }
+}
+
+impl<C: Channel, Wp> From<Xyz<C, Wp>> for YDbDr<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> YDbDr<C, Wp> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp> From<Yxy<C, Wp>> for YDbDr<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> YDbDr<C, Wp> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp> From<YDbDr<C, Wp>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ydd: YDbDr<C, Wp>) -> Srgb<C, Wp> {
+        let ydd = ydd.into_float();
+
+        let [r, g, b] = math::mul_vec(&math::invert(&math::matrix(&M)), [ydd.y, ydd.db, ydd.dr]);
+
+        Srgb::with_wp(Channel::from_float(r), Channel::from_float(g), Channel::from_float(b))
+    }
+}
+
+impl<C: Channel, Wp> From<YDbDr<C, Wp>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ydd: YDbDr<C, Wp>) -> Xyz<C, Wp> {
+        Srgb::from(ydd).into()
+    }
+}
@@ -0,0 +1,147 @@
+//! The YPbPr analog component encoding.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use num_traits::{Zero, One, Float};
+
+use color::*;
+use channels::*;
+use alpha::Alpha;
+use white_point::{WhitePoint, D65};
+use math;
+
+use super::ycbcr::{YCbCrStandard, YCbCrRange, Bt601, Bt709};
+
+declare_color_formats_with_components_plus_alpha_specialization! {
+    /// YPbPr analog component video
+    ///
+    /// YPbPr is the analog counterpart of [`YCbCr`](../ycbcr/struct.YCbCr.html), carried over three cables
+    /// in component video connections. It uses the same luma weights, given by the standard `S`,
+    /// but the color difference channels are signed and unquantized.
+    ///
+    /// Y' ranges from 0.0 to 1.0, and Pb and Pr range from -0.5 to 0.5, with neutral colors having
+    /// zero color difference. As the color difference channels are signed, YPbPr should be used with
+    /// floating point channels. Digitizing YPbPr into Y'CbCr only requires quantization, see the `From`
+    /// conversions between them.
+    struct YPbPr<S: YCbCrStandard = Bt709> : TripleChannel => YPBPR {
+        /// Y' is the luma of the color, the weighted sum of the gamma-encoded RGB channels.
+        pub y,
+        /// Pb is the scaled difference between the blue channel and the luma.
+        pub pb,
+        /// Pr is the scaled difference between the red channel and the luma.
+        pub pr,
+    }
+}
+
+/// YPbPr with the BT.601 coefficients
+pub type YPbPr601<C = f32, Wp = D65> = YPbPr<C, Wp, Bt601>;
+
+/// YPbPr with the BT.709 coefficients
+pub type YPbPr709<C = f32, Wp = D65> = YPbPr<C, Wp, Bt709>;
+
+pub type YPbPra<C, Wp, S> = Alpha<YPbPr<C, Wp, S>>;
+
+use ::spaces::all::*;
+
+impl<C: Channel, Wp, S: YCbCrStandard> Default for YPbPr<C, Wp, S>
+where
+    Wp: WhitePoint<C>,
+{
+    fn default() -> YPbPr<C, Wp, S> {
+        YPbPr::with_wp(C::zero(), C::zero(), C::zero())
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<Srgb<C, Wp>> for YPbPr<C, Wp, S>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(rgb: Srgb<C, Wp>) -> YPbPr<C, Wp, S> {
+        let rgb = rgb.into_float();
+
+        let (kr, kb): (FloatChannel<C>, FloatChannel<C>) = (math::cast(S::KR), math::cast(S::KB));
+        let kg = FloatChannel::<C>::one() - kr - kb;
+        let half: FloatChannel<C> = math::cast(0.5);
+
+        let y = kr * rgb.r + kg * rgb.g + kb * rgb.b;
+
+        let pb = half * (rgb.b - y) / (FloatChannel::<C>::one() - kb);
+        let pr = half * (rgb.r - y) / (FloatChannel::<C>::one() - kr);
+
+        YPbPr::from_float(YPbPr::raw(y, pb, pr))
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<Xyz<C, Wp>> for YPbPr<C, Wp, S>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(xyz: Xyz<C, Wp>) -> YPbPr<C, Wp, S> {
+        Srgb::from(xyz).into()
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<Yxy<C, Wp>> for YPbPr<C, Wp, S>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(yxy: Yxy<C, Wp>) -> YPbPr<C, Wp, S> {
+        Xyz::from(yxy).into()
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard, R: YCbCrRange> From<YCbCr<C, Wp, S, R>> for YPbPr<C, Wp, S>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ycc: YCbCr<C, Wp, S, R>) -> YPbPr<C, Wp, S> {
+        let ycc = ycc.into_float();
+
+        let [y, pb, pr] = R::dequantize::<C>(ycc.y, ycc.cb, ycc.cr);
+
+        YPbPr::from_float(YPbPr::raw(y, pb, pr))
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard, R: YCbCrRange> From<YPbPr<C, Wp, S>> for YCbCr<C, Wp, S, R>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ypp: YPbPr<C, Wp, S>) -> YCbCr<C, Wp, S, R> {
+        let ypp = ypp.into_float();
+
+        let [y, cb, cr] = R::quantize::<C>(ypp.y, ypp.pb, ypp.pr);
+
+        YCbCr::with_wp(Channel::from_float(y), Channel::from_float(cb), Channel::from_float(cr))
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<YPbPr<C, Wp, S>> for Srgb<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ypp: YPbPr<C, Wp, S>) -> Srgb<C, Wp> {
+        let YPBPR { y, pb, pr } = *ypp.into_float();
+
+        let (kr, kb): (FloatChannel<C>, FloatChannel<C>) = (math::cast(S::KR), math::cast(S::KB));
+        let kg = FloatChannel::<C>::one() - kr - kb;
+        let one = FloatChannel::<C>::one();
+
+        let r = y + pr * (one - kr) * math::cast(2.0);
+        let b = y + pb * (one - kb) * math::cast(2.0);
+        let g = (y - kr * r - kb * b) / kg;
+
+        Srgb::with_wp(Channel::from_float(r), Channel::from_float(g), Channel::from_float(b))
+    }
+}
+
+impl<C: Channel, Wp, S: YCbCrStandard> From<YPbPr<C, Wp, S>> for Xyz<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    fn from(ypp: YPbPr<C, Wp, S>) -> Xyz<C, Wp> {
+        Srgb::from(ypp).into()
+    }
+}
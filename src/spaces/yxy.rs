@@ -42,9 +42,11 @@ where
     Wp: WhitePoint<C>,
 {
     fn default() -> Yxy<C, Wp> {
-        let YXY { y, x, .. } = *Yxy::from(Wp::get_xyz());
+        let [x, y, z] = Wp::float_xyz();
 
-        Yxy::with_wp(x, y, C::zero())
+        let sum = x + y + z;
+
+        Yxy::from_float(Yxy::raw(x / sum, y / sum, Zero::zero()))
     }
 }
 
@@ -0,0 +1,307 @@
+//! Correlated color temperature of light sources.
+//!
+//! The chromaticity of many light sources lies close to a curve in the chromaticity diagram, such as the
+//! daylight locus of the CIE D series illuminants, and can be described by a single temperature in Kelvin.
+//!
+//! The correlated color temperature of a chromaticity is the temperature of the nearest point on the
+//! Planckian locus in the CIE 1960 UCS diagram, and Duv is the signed distance to that point, positive
+//! for chromaticities above the locus (greener) and negative for those below it (pinker).
+//! See [`Uv1960::cct_duv`](../spaces/uv1960/struct.Uv1960.html#method.cct_duv).
+
+use num_traits::{ToPrimitive, Zero, One, Float};
+
+use channels::{Channel, FloatChannel};
+use white_point::WhitePoint;
+use math;
+
+use spaces::xyz::Xyz;
+use spaces::uv1960::Uv1960;
+use spaces::srgb::{Srgb, SRGB_PRIMARIES};
+use spaces::rgb;
+
+/// Computes the `xy` chromaticity of CIE daylight with a correlated color temperature in Kelvin,
+/// or `None` if the temperature is outside the range of 4000K to 25000K the formula is defined for.
+///
+/// Note that the standard D series illuminants were defined before a revision of the radiation constant,
+/// so their nominal temperatures need to be scaled by 1.4388 / 1.4380, such as 6504K for D65.
+pub fn daylight_chromaticity(cct: f64) -> Option<[f64; 2]> {
+    if !(4000.0..=25000.0).contains(&cct) {
+        return None;
+    }
+
+    let t = cct;
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let x = if t <= 7000.0 {
+        -4.6070e9 / t3 + 2.9678e6 / t2 + 0.09911e3 / t + 0.244063
+    } else {
+        -2.0064e9 / t3 + 1.9018e6 / t2 + 0.24748e3 / t + 0.237040
+    };
+
+    let y = -3.000 * x * x + 2.870 * x - 0.275;
+
+    Some([x, y])
+}
+
+/// Computes the XYZ values of CIE daylight with a correlated color temperature in Kelvin, normalized to
+/// a luminance of 1.0, or `None` if the temperature is outside the range of 4000K to 25000K.
+///
+/// The result can be used as a runtime white point with
+/// [`RuntimeWp::set_xyz`](../white_point/struct.RuntimeWp.html#method.set_xyz).
+pub fn daylight_xyz<C: Channel, Wp>(cct: f64) -> Option<Xyz<C, Wp>>
+where
+    Wp: WhitePoint<C>,
+{
+    daylight_chromaticity(cct).map(|[x, y]| {
+        Xyz::with_wp(C::from_float(math::cast(x / y)),
+                     C::from_float(math::cast(1.0)),
+                     C::from_float(math::cast((1.0 - x - y) / y)))
+    })
+}
+
+/// Computes the `xy` chromaticity of a Planckian (blackbody) radiator with a temperature in Kelvin,
+/// using the cubic spline approximation of Kim et al., or `None` if the temperature is outside
+/// the range of 1667K to 25000K the approximation is defined for.
+pub fn planckian_chromaticity(cct: f64) -> Option<[f64; 2]> {
+    if !(1667.0..=25000.0).contains(&cct) {
+        return None;
+    }
+
+    let t = cct;
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let x = if t <= 4000.0 {
+        -0.2661239e9 / t3 - 0.2343589e6 / t2 + 0.8776956e3 / t + 0.179910
+    } else {
+        -3.0258469e9 / t3 + 2.1070379e6 / t2 + 0.2226347e3 / t + 0.240390
+    };
+
+    let x2 = x * x;
+    let x3 = x2 * x;
+
+    let y = if t <= 2222.0 {
+        -1.1063814 * x3 - 1.34811020 * x2 + 2.18555832 * x - 0.20219683
+    } else if t <= 4000.0 {
+        -0.9549476 * x3 - 1.37418593 * x2 + 2.09137015 * x - 0.16748867
+    } else {
+        3.0817580 * x3 - 5.87338670 * x2 + 3.75112997 * x - 0.37001483
+    };
+
+    Some([x, y])
+}
+
+/// Computes the XYZ values of a Planckian (blackbody) radiator with a temperature in Kelvin, normalized to
+/// a luminance of 1.0, or `None` if the temperature is outside the range of 1667K to 25000K.
+pub fn planckian_xyz<C: Channel, Wp>(cct: f64) -> Option<Xyz<C, Wp>>
+where
+    Wp: WhitePoint<C>,
+{
+    planckian_chromaticity(cct).map(|[x, y]| {
+        Xyz::with_wp(C::from_float(math::cast(x / y)),
+                     C::from_float(math::cast(1.0)),
+                     C::from_float(math::cast((1.0 - x - y) / y)))
+    })
+}
+
+/// Computes a displayable sRGB color for a Planckian (blackbody) radiator with a temperature in Kelvin,
+/// such as for rendering stars or a color temperature slider, or `None` if the temperature is outside
+/// the range of 1667K to 25000K.
+///
+/// The color is as seen by a viewer adapted to the white point `Wp`. Blackbody colors below about 2000K
+/// are outside of the sRGB gamut, so negative channels are clipped, and the color is scaled to
+/// the brightest color of its chromaticity.
+pub fn blackbody_srgb<C: Channel, Wp>(cct: f64) -> Option<Srgb<C, Wp>>
+where
+    Wp: WhitePoint<C>,
+{
+    planckian_chromaticity(cct).map(|[x, y]| {
+        let xyz = [math::cast(x / y), FloatChannel::<C>::one(), math::cast((1.0 - x - y) / y)];
+
+        let [r, g, b] = math::mul_vec(&rgb::from_xyz_matrix::<C, Wp>(&SRGB_PRIMARIES), xyz);
+
+        let zero = FloatChannel::<C>::zero();
+
+        let (r, g, b) = (r.max(zero), g.max(zero), b.max(zero));
+
+        let max = r.max(g).max(b);
+
+        Srgb::with_wp(C::from_float(rgb::srgb_encode(r / max)),
+                      C::from_float(rgb::srgb_encode(g / max)),
+                      C::from_float(rgb::srgb_encode(b / max)))
+    })
+}
+
+/// Isotemperature lines of Robertson's method, as reciprocal megakelvin, `u`, `v` and slope
+const ROBERTSON: [[f64; 4]; 31] = [
+    [0.0, 0.18006, 0.26352, -0.24341],
+    [10.0, 0.18066, 0.26589, -0.25479],
+    [20.0, 0.18133, 0.26846, -0.26876],
+    [30.0, 0.18208, 0.27119, -0.28539],
+    [40.0, 0.18293, 0.27407, -0.30470],
+    [50.0, 0.18388, 0.27709, -0.32675],
+    [60.0, 0.18494, 0.28021, -0.35156],
+    [70.0, 0.18611, 0.28342, -0.37915],
+    [80.0, 0.18740, 0.28668, -0.40955],
+    [90.0, 0.18880, 0.28997, -0.44278],
+    [100.0, 0.19032, 0.29326, -0.47888],
+    [125.0, 0.19462, 0.30141, -0.58204],
+    [150.0, 0.19962, 0.30921, -0.70471],
+    [175.0, 0.20525, 0.31647, -0.84901],
+    [200.0, 0.21142, 0.32312, -1.0182],
+    [225.0, 0.21807, 0.32909, -1.2168],
+    [250.0, 0.22511, 0.33439, -1.4512],
+    [275.0, 0.23247, 0.33904, -1.7298],
+    [300.0, 0.24010, 0.34308, -2.0637],
+    [325.0, 0.24792, 0.34655, -2.4681],
+    [350.0, 0.25591, 0.34951, -2.9641],
+    [375.0, 0.26400, 0.35200, -3.5814],
+    [400.0, 0.27218, 0.35407, -4.3633],
+    [425.0, 0.28039, 0.35577, -5.3762],
+    [450.0, 0.28863, 0.35714, -6.7262],
+    [475.0, 0.29685, 0.35823, -8.5955],
+    [500.0, 0.30505, 0.35907, -11.324],
+    [525.0, 0.31320, 0.35968, -15.628],
+    [550.0, 0.32129, 0.36011, -23.325],
+    [575.0, 0.32931, 0.36038, -40.770],
+    [600.0, 0.33724, 0.36051, -116.45],
+];
+
+/// Approximates the correlated color temperature of an `xy` chromaticity with McCamy's cubic formula.
+///
+/// The approximation is within a few Kelvin of the exact temperature between 2856K and 6504K,
+/// but degrades quickly outside of that range and away from the Planckian locus.
+pub fn mccamy_cct([x, y]: [f64; 2]) -> f64 {
+    let n = (x - 0.3320) / (0.1858 - y);
+
+    449.0 * n * n * n + 3525.0 * n * n + 6823.3 * n + 5520.33
+}
+
+/// Computes the correlated color temperature of a CIE 1960 `uv` chromaticity with Robertson's method,
+/// by interpolating between isotemperature lines.
+///
+/// Returns `None` if the chromaticity is outside of the range of 1667K to infinity the lines cover.
+pub fn robertson_cct([u, v]: [f64; 2]) -> Option<f64> {
+    let distance = |line: &[f64; 4]| (v - line[2]) - line[3] * (u - line[1]);
+
+    let mut previous = distance(&ROBERTSON[0]);
+
+    for i in 1..ROBERTSON.len() {
+        let current = distance(&ROBERTSON[i]);
+
+        if (current < 0.0) != (previous < 0.0) {
+            let dm = previous / (1.0 + ROBERTSON[i - 1][3] * ROBERTSON[i - 1][3]).sqrt();
+            let di = current / (1.0 + ROBERTSON[i][3] * ROBERTSON[i][3]).sqrt();
+
+            let p = dm / (dm - di);
+
+            let mired = ROBERTSON[i - 1][0] + p * (ROBERTSON[i][0] - ROBERTSON[i - 1][0]);
+
+            return Some(1.0e6 / mired);
+        }
+
+        previous = current;
+    }
+
+    None
+}
+
+/// Approximates the CIE 1960 `uv` chromaticity of a Planckian radiator with Krystek's rational
+/// approximation, accurate to within 1e-5 between 1000K and 15000K.
+fn planckian_uv(cct: f64) -> [f64; 2] {
+    let t = cct;
+    let t2 = t * t;
+
+    let u = (0.860117757 + 1.54118254e-4 * t + 1.28641212e-7 * t2) / (1.0 + 8.42420235e-4 * t + 7.08145163e-7 * t2);
+    let v = (0.317398726 + 4.22806245e-5 * t + 4.20481691e-8 * t2) / (1.0 - 2.89741816e-5 * t + 1.61456053e-7 * t2);
+
+    [u, v]
+}
+
+/// Computes the CIE 1960 `uv` chromaticity at the signed distance Duv from the Planckian locus at the given
+/// correlated color temperature, along the normal of the locus, where positive Duv is above the locus.
+pub fn planckian_uv_duv(cct: f64, duv: f64) -> [f64; 2] {
+    let [u, v] = planckian_uv(cct);
+
+    let [u0, v0] = planckian_uv(cct - 1.0);
+    let [u1, v1] = planckian_uv(cct + 1.0);
+
+    let (du, dv) = (u1 - u0, v1 - v0);
+    let len = du.hypot(dv);
+
+    // The normal of the tangent, pointing towards increasing v
+    let (nu, nv) = if du < 0.0 { (dv / len, -du / len) } else { (-dv / len, du / len) };
+
+    [u + nu * duv, v + nv * duv]
+}
+
+/// Computes the signed distance Duv of a CIE 1960 `uv` chromaticity from the Planckian locus
+/// at the given correlated color temperature.
+pub fn duv([u, v]: [f64; 2], cct: f64) -> f64 {
+    let [up, vp] = planckian_uv(cct);
+
+    let d = (u - up).hypot(v - vp);
+
+    if v < vp { -d } else { d }
+}
+
+impl<C: Channel, Wp> Uv1960<C, Wp>
+where
+    Wp: WhitePoint<C>,
+{
+    #[inline]
+    fn to_f64(self) -> [f64; 2] {
+        let uv = self.into_float();
+
+        [uv.u.to_f64().unwrap(), uv.v.to_f64().unwrap()]
+    }
+
+    /// Computes the correlated color temperature of the chromaticity in Kelvin, with Robertson's method.
+    ///
+    /// Returns `None` if the temperature would be below 1667K.
+    pub fn cct(self) -> Option<f64> {
+        robertson_cct(self.to_f64())
+    }
+
+    /// Approximates the correlated color temperature of the chromaticity in Kelvin, with McCamy's formula.
+    pub fn cct_mccamy(self) -> f64 {
+        let [u, v] = self.to_f64();
+
+        let d = 2.0 * u - 8.0 * v + 4.0;
+
+        mccamy_cct([3.0 * u / d, 2.0 * v / d])
+    }
+
+    /// Computes the correlated color temperature of the chromaticity in Kelvin and its distance Duv
+    /// from the Planckian locus.
+    ///
+    /// Returns `None` if the temperature would be below 1667K.
+    pub fn cct_duv(self) -> Option<(f64, f64)> {
+        let uv = self.to_f64();
+
+        robertson_cct(uv).map(|cct| (cct, duv(uv, cct)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use white_point::D65;
+
+    #[test]
+    fn integer_daylight() {
+        let xyz = daylight_xyz::<u8, D65>(6504.0).unwrap();
+        let float = daylight_xyz::<f32, D65>(6504.0).unwrap();
+
+        assert_eq!((xyz.x, xyz.y, xyz.z), ((float.x * 255.0).round() as u8, 255, 255));
+    }
+
+    #[test]
+    fn integer_planckian() {
+        let xyz = planckian_xyz::<u16, D65>(3000.0).unwrap();
+        let float = planckian_xyz::<f64, D65>(3000.0).unwrap();
+
+        assert_eq!((xyz.x, xyz.y, xyz.z), (65535, 65535, (float.z * 65535.0).round() as u16));
+    }
+}
@@ -0,0 +1,216 @@
+//! White balance correction.
+//!
+//! A photo taken under one illuminant can be corrected to look as if it were taken under another by a
+//! von Kries-style chromatic adaptation, which scales the cone responses of each color by the ratio between
+//! the cone responses of the two illuminants. The source illuminant can be given as a color temperature
+//! and tint, as with the white balance controls of raw photo editors.
+//!
+//! The illuminant can also be estimated from the colors of an image with an [`Estimator`](enum.Estimator.html).
+//! Estimators work on linear sRGB channels relative to the white point of the colors, and return the XYZ of
+//! the illuminant, which can be passed to [`WhiteBalance::new`](struct.WhiteBalance.html#method.new) or set as a
+//! runtime white point with [`RuntimeWp::set_xyz`](../white_point/struct.RuntimeWp.html#method.set_xyz).
+
+use num_traits::Float;
+
+use channels::*;
+use color::{Color, FromColor, ColorChannel, ColorWhitePoint};
+use white_point::WhitePoint;
+use temperature;
+use adapt;
+use math::{self, Matrix3, Vector3};
+
+use spaces::xyz::Xyz;
+use spaces::lms::{ConeResponse, Bradford};
+use spaces::srgb::SRGB_PRIMARIES;
+use spaces::rgb;
+
+/// A white balance correction from a source illuminant to a target white, applied in CIE XYZ
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WhiteBalance<T> {
+    matrix: Matrix3<T>,
+}
+
+impl<T: Channel + Float> WhiteBalance<T> {
+    /// Creates a correction from the XYZ of the source illuminant to the XYZ of the target white,
+    /// scaling the cone response `M`.
+    pub fn new<M: ConeResponse>(source: Vector3<T>, target: Vector3<T>) -> WhiteBalance<T> {
+        WhiteBalance { matrix: adapt::matrix::<M, T>(source, target) }
+    }
+
+    /// Creates a correction with the Bradford cone response from a source illuminant with a correlated
+    /// color temperature in Kelvin and a tint, to the white point `Wp` of the corrected colors.
+    ///
+    /// The tint is the signed distance Duv from the Planckian locus, where positive values correct
+    /// a green cast and negative values a magenta cast. Raising the temperature makes the result warmer.
+    ///
+    /// Returns `None` if the temperature is outside the range of 1000K to 15000K.
+    #[inline]
+    pub fn from_temperature<C, Wp>(cct: f64, tint: f64) -> Option<WhiteBalance<T>>
+    where
+        C: Channel<FloatChannel = T>,
+        Wp: WhitePoint<C>,
+    {
+        WhiteBalance::from_temperature_with::<Bradford, C, Wp>(cct, tint)
+    }
+
+    /// Creates a correction with the cone response `M` from a source illuminant with a correlated
+    /// color temperature in Kelvin and a tint, to the white point `Wp` of the corrected colors.
+    ///
+    /// Returns `None` if the temperature is outside the range of 1000K to 15000K.
+    pub fn from_temperature_with<M, C, Wp>(cct: f64, tint: f64) -> Option<WhiteBalance<T>>
+    where
+        M: ConeResponse,
+        C: Channel<FloatChannel = T>,
+        Wp: WhitePoint<C>,
+    {
+        if !(1000.0..=15000.0).contains(&cct) {
+            return None;
+        }
+
+        let [u, v] = temperature::planckian_uv_duv(cct, tint);
+
+        let d = 2.0 * u - 8.0 * v + 4.0;
+        let (x, y) = (3.0 * u / d, 2.0 * v / d);
+
+        let source = [math::cast(x / y), T::one(), math::cast((1.0 - x - y) / y)];
+
+        Some(WhiteBalance::new::<M>(source, Wp::float_xyz()))
+    }
+
+    /// Creates a correction with the Bradford cone response from the illuminant estimated from `colors`,
+    /// to the white point of the colors.
+    ///
+    /// Returns `None` if no illuminant could be estimated.
+    pub fn auto<C>(colors: &[C], estimator: Estimator<T>) -> Option<WhiteBalance<T>>
+    where
+        T: Channel<FloatChannel = T>,
+        C: Color<Channel = T> + Copy,
+        Xyz<T, ColorWhitePoint<C>>: From<C>,
+    {
+        let source = estimator.estimate(colors)?;
+        Some(WhiteBalance::new::<Bradford>([source.x, source.y, source.z], ColorWhitePoint::<C>::float_xyz()))
+    }
+
+    /// The XYZ matrix of the correction
+    #[inline]
+    pub fn matrix(&self) -> &Matrix3<T> {
+        &self.matrix
+    }
+
+    /// Corrects the white balance of a color, by way of CIE XYZ.
+    ///
+    /// The intermediate XYZ color uses the channel type of the color, so colors with integer channels lose precision.
+    pub fn apply<C>(&self, color: C) -> C
+    where
+        C: Color + FromColor<ColorChannel<C>, ColorWhitePoint<C>>,
+        ColorChannel<C>: Channel<FloatChannel = T>,
+        Xyz<ColorChannel<C>, ColorWhitePoint<C>>: From<C>,
+    {
+        let xyz = Xyz::from(color).into_float();
+
+        let [x, y, z] = math::mul_vec(&self.matrix, [xyz.x, xyz.y, xyz.z]);
+
+        C::from_xyz(Xyz::with_wp(Channel::from_float(x), Channel::from_float(y), Channel::from_float(z)))
+    }
+
+    /// Corrects the white balance of every color in a buffer.
+    pub fn apply_slice<C>(&self, colors: &mut [C])
+    where
+        C: Color + FromColor<ColorChannel<C>, ColorWhitePoint<C>> + Copy,
+        ColorChannel<C>: Channel<FloatChannel = T>,
+        Xyz<ColorChannel<C>, ColorWhitePoint<C>>: From<C>,
+    {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+/// A method of estimating the illuminant of an image from its colors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Estimator<T> {
+    /// Assumes the average color of the image is gray.
+    GrayWorld,
+    /// Assumes the brightest value of each channel is a reflection of the illuminant.
+    WhitePatch,
+    /// Assumes the Minkowski p-norm of the colors is gray, between gray-world at 1 and white-patch at infinity.
+    /// A norm of 6 works well for many images.
+    ShadesOfGray(T),
+}
+
+impl<T: Channel<FloatChannel = T> + Float> Estimator<T> {
+    /// Estimates the linear RGB of the illuminant from linear RGB channels, normalized to a maximum of 1.0.
+    ///
+    /// Negative channels are ignored. Returns `None` if there are no colors or a channel of the estimate is zero.
+    pub fn estimate_rgb<I>(&self, colors: I) -> Option<[T; 3]>
+    where
+        I: IntoIterator<Item = [T; 3]>,
+    {
+        let p = match *self {
+            Estimator::GrayWorld => T::one(),
+            Estimator::WhitePatch => T::infinity(),
+            Estimator::ShadesOfGray(p) => p.max(T::one()),
+        };
+
+        let mut acc = [T::zero(); 3];
+        let mut n = 0usize;
+
+        for rgb in colors {
+            for (a, &v) in acc.iter_mut().zip(&rgb) {
+                let v = v.max(T::zero());
+
+                *a = if p.is_infinite() { a.max(v) } else { *a + v.powf(p) };
+            }
+
+            n += 1;
+        }
+
+        if n == 0 {
+            return None;
+        }
+
+        if p.is_finite() {
+            let n: T = math::cast(n as f64);
+
+            for a in &mut acc {
+                *a = (*a / n).powf(p.recip());
+            }
+        }
+
+        let max = acc[0].max(acc[1]).max(acc[2]);
+
+        if !(acc[0] > T::zero() && acc[1] > T::zero() && acc[2] > T::zero()) {
+            return None;
+        }
+
+        Some([acc[0] / max, acc[1] / max, acc[2] / max])
+    }
+
+    /// Estimates the illuminant of the colors, as XYZ normalized to a luminance of 1.0.
+    ///
+    /// Returns `None` if there are no colors or a channel of the estimate is zero.
+    pub fn estimate<C>(&self, colors: &[C]) -> Option<Xyz<T, ColorWhitePoint<C>>>
+    where
+        C: Color<Channel = T> + Copy,
+        Xyz<T, ColorWhitePoint<C>>: From<C>,
+    {
+        let to_rgb = rgb::from_xyz_matrix::<T, ColorWhitePoint<C>>(&SRGB_PRIMARIES);
+        let to_xyz = rgb::to_xyz_matrix::<T, ColorWhitePoint<C>>(&SRGB_PRIMARIES);
+
+        let rgb = self.estimate_rgb(colors.iter().map(|&color| {
+            let xyz = Xyz::from(color);
+
+            math::mul_vec(&to_rgb, [xyz.x, xyz.y, xyz.z])
+        }))?;
+
+        let [x, y, z] = math::mul_vec(&to_xyz, rgb);
+
+        Some(Xyz::with_wp(x / y, T::one(), z / y))
+    }
+}
+
+/// Computes the gains of each linear RGB channel that turn an estimated illuminant into gray,
+/// normalized so the green channel is unchanged.
+pub fn rgb_gains<T: Float>([r, g, b]: [T; 3]) -> [T; 3] {
+    [g / r, T::one(), g / b]
+}
@@ -5,17 +5,104 @@
 //! which are relatively orange compared to daylight. Defining "white" as daylight will give
 //! unacceptable results when attempting to color-correct a photograph taken with incandescent lighting.
 
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::marker::PhantomData;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::any::TypeId;
 
-use channels::Channel;
+use num_traits::ToPrimitive;
+
+use channels::{Channel, FloatChannel};
+use observer::{Observer, TwoDegree, TenDegree};
 use spaces::xyz::Xyz;
+use math;
 
 pub trait WhitePoint<C: Channel>: Sized {
-    fn get_xyz() -> Xyz<C, Self>;
+    /// The standard observer the white point is defined for
+    type Observer: Observer;
+
+    /// The XYZ values of the white point, normalized to a luminance of 1.0
+    fn tristimulus() -> [f64; 3];
+
+    /// The white point as an XYZ color with channel type `C`.
+    ///
+    /// Integer channels are normalized like any other color, so values above 1.0 saturate. Use
+    /// [`float_xyz`](#method.float_xyz) for computations with the white point.
+    fn get_xyz() -> Xyz<C, Self> {
+        let [x, y, z] = Self::float_xyz();
+
+        Xyz::with_wp(C::from_float(x), C::from_float(y), C::from_float(z))
+    }
+
+    /// The XYZ values of the white point in the floating point type of `C`, which are exact for any channel type
+    #[inline]
+    fn float_xyz() -> [FloatChannel<C>; 3] {
+        let [x, y, z] = Self::tristimulus();
+
+        [math::cast(x), math::cast(y), math::cast(z)]
+    }
+}
+
+/// Convenience type alias for the observer of a white point
+pub type WhitePointObserver<Wp, C> = <Wp as WhitePoint<C>>::Observer;
+
+/// A white point with tristimulus values known at compile time
+///
+/// Implementing `ConstWhitePoint` implements [`WhitePoint`](trait.WhitePoint.html) for all channel types,
+/// which allows defining custom illuminants, such as the measured white of a monitor, outside of this crate.
+/// See also the [`declare_whitepoint!`](../macro.declare_whitepoint.html) macro.
+pub trait ConstWhitePoint {
+    /// The standard observer the white point is defined for
+    type Observer: Observer;
+
+    /// The XYZ values of the white point, normalized to a luminance of 1.0
+    const XYZ: [f64; 3];
+}
+
+impl<C: Channel, Wp: ConstWhitePoint> WhitePoint<C> for Wp {
+    type Observer = Wp::Observer;
+
+    #[inline]
+    fn tristimulus() -> [f64; 3] {
+        Wp::XYZ
+    }
+}
+
+/// Declares a custom white point type from its XYZ values and observer.
+///
+/// ```ignore
+/// declare_whitepoint! {
+///     /// The measured white of my monitor
+///     pub struct MonitorWhite: TwoDegree { 0.9512, 1.0, 1.0721 }
+/// }
+/// ```
+#[macro_export]
+macro_rules! declare_whitepoint {
+    (
+        $(#[$attrs:meta])*
+        $vis:vis struct $name:ident : $observer:ty { $x:expr, $y:expr, $z:expr }
+    ) => {
+        $(#[$attrs])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        $vis struct $name;
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "{} ({}, {}, {})", stringify!($name), $x, $y, $z)
+            }
+        }
+
+        impl $crate::white_point::ConstWhitePoint for $name {
+            type Observer = $observer;
+
+            const XYZ: [f64; 3] = [$x, $y, $z];
+        }
+    }
 }
 
 macro_rules! declare_whitepoints {
-    ($(
+    (observer = $observer:ty; $(
         $(#[$($attrs:tt)*])*
         struct $name:ident { $x:expr, $y:expr, $z:expr },
     )*) => {
@@ -30,18 +117,18 @@ macro_rules! declare_whitepoints {
                 }
             }
 
-            impl<C: Channel> WhitePoint<C> for $name {
-                fn get_xyz() -> Xyz<C, Self> {
-                    Xyz::with_wp(C::from($x).unwrap(),
-                                 C::from($y).unwrap(),
-                                 C::from($z).unwrap())
-                }
+            impl ConstWhitePoint for $name {
+                type Observer = $observer;
+
+                const XYZ: [f64; 3] = [$x, $y, $z];
             }
         )*
     }
 }
 
 declare_whitepoints! {
+    observer = TwoDegree;
+
     /// CIE standard illuminant A
     ///
     /// CIE standard illuminant A is intended to represent typical, domestic, tungsten-filament lighting.
@@ -73,6 +160,12 @@ declare_whitepoints! {
     /// for 2° Standard Observer.
     struct D55 { 0.95682, 1.0, 0.92149 },
 
+    /// ACES white point
+    ///
+    /// The white point of the Academy Color Encoding System, with chromaticity (0.32168, 0.33767).
+    /// It is close to, but not exactly, the D series illuminant for a color temperature of 6000K.
+    struct Aces { 0.95265, 1.0, 1.00883 },
+
     /// CIE D series standard illuminant - D65
     ///
     /// D65 White Point is the natural daylight with a color temperature of 6500K
@@ -91,27 +184,136 @@ declare_whitepoints! {
     /// Uses the CIE 1932 2° Standard Observer
     struct E { 1.0, 1.0, 1.0 },
 
+    /// CIE fluorescent illuminant series - F1
+    ///
+    /// F1 represents a standard halophosphate fluorescent lamp for 2° Standard Observer.
+    struct F1 { 0.92834, 1.0, 1.03665 },
+
     /// CIE fluorescent illuminant series - F2
     ///
     /// F2 represents a semi-broadband fluorescent lamp for 2° Standard Observer.
     struct F2 { 0.99186, 1.0, 0.67393},
 
+    /// CIE fluorescent illuminant series - F3
+    ///
+    /// F3 represents a standard halophosphate fluorescent lamp for 2° Standard Observer.
+    struct F3 { 1.03753, 1.0, 0.49861 },
+
+    /// CIE fluorescent illuminant series - F4
+    ///
+    /// F4 represents a standard halophosphate fluorescent lamp for 2° Standard Observer.
+    struct F4 { 1.09147, 1.0, 0.38813 },
+
+    /// CIE fluorescent illuminant series - F5
+    ///
+    /// F5 represents a standard halophosphate fluorescent lamp for 2° Standard Observer.
+    struct F5 { 0.90872, 1.0, 0.98723 },
+
+    /// CIE fluorescent illuminant series - F6
+    ///
+    /// F6 represents a standard halophosphate fluorescent lamp for 2° Standard Observer.
+    struct F6 { 0.97309, 1.0, 0.60191 },
+
     /// CIE fluorescent illuminant series - F7
     ///
     /// F7 represents a broadband fluorescent lamp for 2° Standard Observer.
     struct F7 { 0.95041, 1.0, 1.08747 },
 
+    /// CIE fluorescent illuminant series - F8
+    ///
+    /// F8 represents a broadband fluorescent lamp for 2° Standard Observer.
+    struct F8 { 0.96413, 1.0, 0.82333 },
+
+    /// CIE fluorescent illuminant series - F9
+    ///
+    /// F9 represents a broadband fluorescent lamp for 2° Standard Observer.
+    struct F9 { 1.00365, 1.0, 0.67868 },
+
+    /// CIE fluorescent illuminant series - F10
+    ///
+    /// F10 represents a narrowband fluorescent lamp for 2° Standard Observer.
+    struct F10 { 0.96174, 1.0, 0.81712 },
+
     ///CIE fluorescent illuminant series - F11
     ///
     ///F11 represents a narrowband fluorescent lamp for 2° Standard Observer.
     struct F11 { 1.00962, 1.0, 0.64350 },
+
+    /// CIE fluorescent illuminant series - F12
+    ///
+    /// F12 represents a narrowband fluorescent lamp for 2° Standard Observer.
+    struct F12 { 1.08046, 1.0, 0.39228 },
+
+    /// CIE D series illuminant - D93
+    ///
+    /// D93 is natural daylight with a color temperature of around 9300K, the white point of
+    /// many older CRT monitors, for 2° Standard Observer.
+    struct D93 { 0.95300, 1.0, 1.41319 },
+
+    /// CIE indoor daylight illuminant - ID50
+    ///
+    /// ID50 represents daylight of around 5000K filtered through window glass, for 2° Standard Observer.
+    struct ID50 { 0.95400, 1.0, 0.82618 },
+
+    /// CIE indoor daylight illuminant - ID65
+    ///
+    /// ID65 represents daylight of around 6500K filtered through window glass, for 2° Standard Observer.
+    struct ID65 { 0.93602, 1.0, 1.07703 },
+
+    /// CIE LED illuminant series - LED-B1
+    ///
+    /// LED-B1 represents a phosphor-converted blue LED of around 2700K for 2° Standard Observer.
+    struct LedB1 { 1.11820, 1.0, 0.33399 },
+
+    /// CIE LED illuminant series - LED-B2
+    ///
+    /// LED-B2 represents a phosphor-converted blue LED of around 3000K for 2° Standard Observer.
+    struct LedB2 { 1.08599, 1.0, 0.40653 },
+
+    /// CIE LED illuminant series - LED-B3
+    ///
+    /// LED-B3 represents a phosphor-converted blue LED of around 4100K for 2° Standard Observer.
+    struct LedB3 { 1.00886, 1.0, 0.67714 },
+
+    /// CIE LED illuminant series - LED-B4
+    ///
+    /// LED-B4 represents a phosphor-converted blue LED of around 5100K for 2° Standard Observer.
+    struct LedB4 { 0.97716, 1.0, 0.87836 },
+
+    /// CIE LED illuminant series - LED-B5
+    ///
+    /// LED-B5 represents a phosphor-converted blue LED of around 6600K for 2° Standard Observer.
+    struct LedB5 { 0.96354, 1.0, 1.12670 },
+
+    /// CIE LED illuminant series - LED-BH1
+    ///
+    /// LED-BH1 represents a hybrid of phosphor-converted blue and red LEDs of around 2850K
+    /// for 2° Standard Observer.
+    struct LedBH1 { 1.10034, 1.0, 0.35908 },
+
+    /// CIE LED illuminant series - LED-RGB1
+    ///
+    /// LED-RGB1 represents a mixture of red, green and blue LEDs of around 2840K for 2° Standard Observer.
+    struct LedRGB1 { 1.08217, 1.0, 0.29257 },
+
+    /// CIE LED illuminant series - LED-V1
+    ///
+    /// LED-V1 represents a phosphor-converted violet LED of around 2700K for 2° Standard Observer.
+    struct LedV1 { 1.00264, 1.0, 0.19613 },
+
+    /// CIE LED illuminant series - LED-V2
+    ///
+    /// LED-V2 represents a phosphor-converted violet LED of around 4100K for 2° Standard Observer.
+    struct LedV2 { 1.00159, 1.0, 0.64742 },
 }
 
-/// Alternative D-series illuminants for 10° Standard Observer.
+/// Alternative illuminants for 10° Standard Observer.
 pub mod degree10 {
     use super::*;
 
     declare_whitepoints! {
+        observer = TenDegree;
+
         ///CIE D series standard illuminant - D50
         ///
         ///D50 White Point is the natural daylight with a color temperature of around 5000K
@@ -135,5 +337,313 @@ pub mod degree10 {
         ///D75 White Point is the natural daylight with a color temperature of around 7500K
         ///for 10° Standard Observer.
         struct D75Degree10 { 0.94416, 1.0, 1.2064 },
+
+        ///CIE standard illuminant A
+        ///
+        ///CIE standard illuminant A represents typical, domestic, tungsten-filament lighting
+        ///for 10° Standard Observer.
+        struct ADegree10 { 1.11144, 1.0, 0.35200 },
+
+        ///CIE standard illuminant C
+        ///
+        ///CIE standard illuminant C represents the average day light with a CCT of 6774 K
+        ///for 10° Standard Observer.
+        struct CDegree10 { 0.97285, 1.0, 1.16145 },
+
+        ///CIE standard illuminant E
+        ///
+        ///CIE standard illuminant E represents the equal energy radiator
+        ///for 10° Standard Observer.
+        struct EDegree10 { 1.0, 1.0, 1.0 },
+
+        ///CIE fluorescent illuminant series - F1
+        ///
+        ///F1 represents a standard halophosphate fluorescent lamp for 10° Standard Observer.
+        struct F1Degree10 { 0.94791, 1.0, 1.03191 },
+
+        ///CIE fluorescent illuminant series - F2
+        ///
+        ///F2 represents a semi-broadband fluorescent lamp for 10° Standard Observer.
+        struct F2Degree10 { 1.03279, 1.0, 0.69027 },
+
+        ///CIE fluorescent illuminant series - F3
+        ///
+        ///F3 represents a standard halophosphate fluorescent lamp for 10° Standard Observer.
+        struct F3Degree10 { 1.08968, 1.0, 0.51965 },
+
+        ///CIE fluorescent illuminant series - F4
+        ///
+        ///F4 represents a standard halophosphate fluorescent lamp for 10° Standard Observer.
+        struct F4Degree10 { 1.14961, 1.0, 0.40963 },
+
+        ///CIE fluorescent illuminant series - F5
+        ///
+        ///F5 represents a standard halophosphate fluorescent lamp for 10° Standard Observer.
+        struct F5Degree10 { 0.93369, 1.0, 0.98636 },
+
+        ///CIE fluorescent illuminant series - F6
+        ///
+        ///F6 represents a standard halophosphate fluorescent lamp for 10° Standard Observer.
+        struct F6Degree10 { 1.02148, 1.0, 0.62074 },
+
+        ///CIE fluorescent illuminant series - F7
+        ///
+        ///F7 represents a broadband fluorescent lamp for 10° Standard Observer.
+        struct F7Degree10 { 0.95792, 1.0, 1.07686 },
+
+        ///CIE fluorescent illuminant series - F8
+        ///
+        ///F8 represents a broadband fluorescent lamp for 10° Standard Observer.
+        struct F8Degree10 { 0.97115, 1.0, 0.81135 },
+
+        ///CIE fluorescent illuminant series - F9
+        ///
+        ///F9 represents a broadband fluorescent lamp for 10° Standard Observer.
+        struct F9Degree10 { 1.02116, 1.0, 0.67826 },
+
+        ///CIE fluorescent illuminant series - F10
+        ///
+        ///F10 represents a narrowband fluorescent lamp for 10° Standard Observer.
+        struct F10Degree10 { 0.99001, 1.0, 0.83134 },
+
+        ///CIE fluorescent illuminant series - F11
+        ///
+        ///F11 represents a narrowband fluorescent lamp for 10° Standard Observer.
+        struct F11Degree10 { 1.03863, 1.0, 0.65607 },
+
+        ///CIE fluorescent illuminant series - F12
+        ///
+        ///F12 represents a narrowband fluorescent lamp for 10° Standard Observer.
+        struct F12Degree10 { 1.11428, 1.0, 0.40353 },
+
+        ///CIE D series illuminant - D93
+        ///
+        ///D93 is natural daylight with a color temperature of around 9300K, the white point of
+        ///many older CRT monitors, for 10° Standard Observer.
+        struct D93Degree10 { 0.9434, 1.0, 1.3866 },
+
+        ///CIE indoor daylight illuminant - ID50
+        ///
+        ///ID50 represents daylight of around 5000K filtered through window glass, for 10° Standard Observer.
+        struct ID50Degree10 { 0.9570, 1.0, 0.8154 },
+
+        ///CIE indoor daylight illuminant - ID65
+        ///
+        ///ID65 represents daylight of around 6500K filtered through window glass, for 10° Standard Observer.
+        struct ID65Degree10 { 0.9338, 1.0, 1.0611 },
+
+        ///CIE LED illuminant series - LED-B1
+        ///
+        ///LED-B1 represents a phosphor-converted blue LED of around 2700K for 10° Standard Observer.
+        struct LedB1Degree10 { 1.14754, 1.0, 0.33360 },
+
+        ///CIE LED illuminant series - LED-B2
+        ///
+        ///LED-B2 represents a phosphor-converted blue LED of around 3000K for 10° Standard Observer.
+        struct LedB2Degree10 { 1.11468, 1.0, 0.40654 },
+
+        ///CIE LED illuminant series - LED-B3
+        ///
+        ///LED-B3 represents a phosphor-converted blue LED of around 4100K for 10° Standard Observer.
+        struct LedB3Degree10 { 1.03347, 1.0, 0.68010 },
+
+        ///CIE LED illuminant series - LED-B4
+        ///
+        ///LED-B4 represents a phosphor-converted blue LED of around 5100K for 10° Standard Observer.
+        struct LedB4Degree10 { 1.00958, 1.0, 0.88842 },
+
+        ///CIE LED illuminant series - LED-B5
+        ///
+        ///LED-B5 represents a phosphor-converted blue LED of around 6600K for 10° Standard Observer.
+        struct LedB5Degree10 { 0.98403, 1.0, 1.12098 },
+
+        ///CIE LED illuminant series - LED-BH1
+        ///
+        ///LED-BH1 represents a hybrid of phosphor-converted blue and red LEDs of around 2850K
+        ///for 10° Standard Observer.
+        struct LedBH1Degree10 { 1.13184, 1.0, 0.36796 },
+
+        ///CIE LED illuminant series - LED-RGB1
+        ///
+        ///LED-RGB1 represents a mixture of red, green and blue LEDs of around 2840K for 10° Standard Observer.
+        struct LedRGB1Degree10 { 1.07442, 1.0, 0.27642 },
+
+        ///CIE LED illuminant series - LED-V1
+        ///
+        ///LED-V1 represents a phosphor-converted violet LED of around 2700K for 10° Standard Observer.
+        struct LedV1Degree10 { 1.13913, 1.0, 0.37217 },
+
+        ///CIE LED illuminant series - LED-V2
+        ///
+        ///LED-V2 represents a phosphor-converted violet LED of around 4100K for 10° Standard Observer.
+        struct LedV2Degree10 { 1.00859, 1.0, 0.66155 },
+    }
+}
+
+thread_local! {
+    static RUNTIME_WHITE_POINTS: RefCell<HashMap<TypeId, [f64; 3]>> = RefCell::new(HashMap::new());
+}
+
+/// A white point whose tristimulus values are only known at runtime
+///
+/// White points are types, so that colors of different white points cannot be mixed by accident.
+/// `RuntimeWp` is a white point type for illuminants that are not known at compile time, such as
+/// those read from image metadata or measured by a spectrometer. Its XYZ values are stored per thread,
+/// and are used by every color with the `RuntimeWp<K>` white point on that thread, so conversions and
+/// chromatic adaptation work with it like any other white point.
+///
+/// The marker type `K` selects an independent white point, which allows using several runtime
+/// white points at once, such as when adapting from a measured source white to a measured target white.
+/// Until it is set, a runtime white point is D65.
+///
+/// The observer `O` of the white point defaults to the 2° standard observer.
+///
+/// # Threads
+///
+/// The white point is not stored in the colors themselves, so a color sent to another thread, or processed
+/// by a thread pool, is interpreted with whatever white point that thread has set, which is D65 unless it
+/// sets its own. Set the white point on every thread that works with such colors, or convert them to a
+/// compile-time white point before sharing them.
+pub struct RuntimeWp<K = (), O = TwoDegree>(PhantomData<(K, O)>);
+
+/// Restores the previous white point of a `RuntimeWp` when dropped, including during a panic.
+struct RestoreRuntimeWp<K: 'static, O: 'static> {
+    previous: Option<[f64; 3]>,
+    marker: PhantomData<(K, O)>,
+}
+
+impl<K: 'static, O: 'static> Drop for RestoreRuntimeWp<K, O> {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+
+        RUNTIME_WHITE_POINTS.with(|wps| {
+            let mut wps = wps.borrow_mut();
+
+            match previous {
+                Some(xyz) => wps.insert(TypeId::of::<(K, O)>(), xyz),
+                None => wps.remove(&TypeId::of::<(K, O)>()),
+            };
+        });
+    }
+}
+
+impl<K: 'static, O: 'static> RuntimeWp<K, O> {
+    /// Returns the XYZ values of the white point on the current thread.
+    pub fn get() -> [f64; 3] {
+        RUNTIME_WHITE_POINTS.with(|wps| {
+            wps.borrow().get(&TypeId::of::<(K, O)>()).cloned().unwrap_or([0.95047, 1.0, 1.08883])
+        })
+    }
+
+    /// Sets the XYZ values of the white point on the current thread, returning the previous values.
+    ///
+    /// Other threads are not affected, see the [type documentation](struct.RuntimeWp.html#threads).
+    pub fn set(x: f64, y: f64, z: f64) -> [f64; 3] {
+        let previous = Self::get();
+
+        RUNTIME_WHITE_POINTS.with(|wps| wps.borrow_mut().insert(TypeId::of::<(K, O)>(), [x, y, z]));
+
+        previous
+    }
+
+    /// Sets the white point on the current thread to the white point of an XYZ color, such as the white
+    /// of a compile-time white point, returning the previous values.
+    pub fn set_xyz<C: Channel, Wp>(xyz: Xyz<C, Wp>) -> [f64; 3]
+    where
+        Wp: WhitePoint<C>,
+    {
+        let xyz = xyz.into_float();
+
+        Self::set(xyz.x.to_f64().unwrap(), xyz.y.to_f64().unwrap(), xyz.z.to_f64().unwrap())
+    }
+
+    /// Sets the white point on the current thread for the duration of `f`, then restores the previous values,
+    /// even if `f` panics.
+    pub fn scoped<F, R>(x: f64, y: f64, z: f64, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let previous = RUNTIME_WHITE_POINTS.with(|wps| {
+            wps.borrow_mut().insert(TypeId::of::<(K, O)>(), [x, y, z])
+        });
+
+        let _restore = RestoreRuntimeWp::<K, O> { previous, marker: PhantomData };
+
+        f()
+    }
+}
+
+impl<K, O> Debug for RuntimeWp<K, O> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str("RuntimeWp")
+    }
+}
+
+impl<K, O> Clone for RuntimeWp<K, O> {
+    fn clone(&self) -> RuntimeWp<K, O> {
+        *self
+    }
+}
+
+impl<K, O> Copy for RuntimeWp<K, O> {}
+
+impl<K, O> Default for RuntimeWp<K, O> {
+    fn default() -> RuntimeWp<K, O> {
+        RuntimeWp(PhantomData)
+    }
+}
+
+impl<K: 'static, O: 'static> Display for RuntimeWp<K, O> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let [x, y, z] = Self::get();
+
+        write!(f, "Runtime White Point ({}, {}, {})", x, y, z)
+    }
+}
+
+impl<C: Channel, K: 'static, O: Observer + 'static> WhitePoint<C> for RuntimeWp<K, O> {
+    type Observer = O;
+
+    #[inline]
+    fn tristimulus() -> [f64; 3] {
+        Self::get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+    use std::thread;
+
+    use super::*;
+
+    const D65: [f64; 3] = [0.95047, 1.0, 1.08883];
+
+    #[test]
+    fn scoped_restores_on_panic() {
+        struct Marker;
+
+        let res = panic::catch_unwind(|| {
+            RuntimeWp::<Marker>::scoped(0.9, 1.0, 0.8, || panic!("inside scope"))
+        });
+
+        assert!(res.is_err());
+        assert_eq!(RuntimeWp::<Marker>::get(), D65);
+
+        RuntimeWp::<Marker>::set(0.9, 1.0, 0.8);
+
+        let _ = panic::catch_unwind(|| RuntimeWp::<Marker>::scoped(1.0, 1.0, 1.0, || panic!("inside scope")));
+
+        assert_eq!(RuntimeWp::<Marker>::get(), [0.9, 1.0, 0.8]);
+    }
+
+    #[test]
+    fn per_thread() {
+        struct Marker;
+
+        RuntimeWp::<Marker>::set(0.9, 1.0, 0.8);
+
+        assert_eq!(thread::spawn(RuntimeWp::<Marker>::get).join().unwrap(), D65);
+        assert_eq!(RuntimeWp::<Marker>::get(), [0.9, 1.0, 0.8]);
     }
 }